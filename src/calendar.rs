@@ -0,0 +1,399 @@
+//! Banking calendars deciding which days the banks process payments on.
+//!
+//! Direct debit collections, bank transfers and payouts settle on business days only,
+//! so the dates computed for them must skip weekends and bank holidays of the payment scheme:
+//!
+//! ```skip
+//! // SEPA collections and payouts in euro
+//! let collection = Target2.next_business_day(billing_date).ok_or(NoBusinessDay)?;
+//! let arrival = Target2.add_business_days(payout_date, 2).ok_or(NoBusinessDay)?;
+//!
+//! // ACH effective dates
+//! let effective = FederalReserve.add_business_days(today, 1).ok_or(NoBusinessDay)?;
+//!
+//! // Local bank holidays on top of the scheme calendar
+//! let calendar = Holidays::new(Target2).load(&std::fs::read_to_string("holidays.txt")?)?;
+//! ```
+
+use std::collections::BTreeSet;
+
+use crate::types::Date;
+use crate::{Error, Weekday};
+
+/// Calendar of the business days of a payment scheme.
+pub trait BankingCalendar: Send + Sync {
+    /// Whether banks process payments on the date.
+    fn is_business_day(&self, date: Date) -> bool;
+
+    /// The date itself if it's a business day, or the first business day after it
+    /// (the "following" rolling convention).
+    ///
+    /// Returns None if there's no business day up to the last supported date (2199-12-31).
+    fn next_business_day(&self, date: Date) -> Option<Date> {
+        let mut date = date;
+        while !self.is_business_day(date) {
+            date = date.checked_add_days(1)?;
+        }
+        Some(date)
+    }
+
+    /// The date shifted by the number of business days (backwards for negative numbers).
+    ///
+    /// The date itself is not counted, so that `add_business_days(friday, 1)`
+    /// is the next Monday (unless it's a holiday), and zero days roll the date
+    /// to the next business day.
+    ///
+    /// Returns None if the business days run out of the supported dates
+    /// (1970-01-01 to 2199-12-31).
+    fn add_business_days(&self, date: Date, days: i64) -> Option<Date> {
+        if days == 0 {
+            return self.next_business_day(date);
+        }
+        let step = days.signum();
+        let mut date = date;
+        for _ in 0..days.unsigned_abs() {
+            date = date.checked_add_days(step)?;
+            while !self.is_business_day(date) {
+                date = date.checked_add_days(step)?;
+            }
+        }
+        Some(date)
+    }
+}
+
+/// Calendar closed on weekends only (Monday to Friday are business days).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Weekdays;
+
+impl BankingCalendar for Weekdays {
+    #[inline]
+    fn is_business_day(&self, date: Date) -> bool {
+        !date.weekday().is_weekend()
+    }
+}
+
+/// Calendar of the TARGET2 (T2) system settling euro payments, including SEPA.
+///
+/// Closed on weekends, New Year's Day, Good Friday, Easter Monday,
+/// Labour Day (May 1), Christmas Day and December 26.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Target2;
+
+impl BankingCalendar for Target2 {
+    fn is_business_day(&self, date: Date) -> bool {
+        if date.weekday().is_weekend() {
+            return false;
+        }
+        let easter = easter_sunday(date.year());
+        !matches!(
+            (date.month(), date.day()),
+            (1, 1) | (5, 1) | (12, 25) | (12, 26)
+        ) && date != easter.add_days(-2)
+            && date != easter.add_days(1)
+    }
+}
+
+/// Calendar of the US Federal Reserve settling ACH and Fedwire payments.
+///
+/// Closed on weekends and the federal holidays. Holidays falling on Sunday
+/// are observed on Monday, while the ones falling on Saturday are not moved
+/// (the Federal Reserve Banks are open on the preceding Friday).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FederalReserve;
+
+impl BankingCalendar for FederalReserve {
+    fn is_business_day(&self, date: Date) -> bool {
+        !date.weekday().is_weekend() && !federal_holidays(date.year()).contains(&date)
+    }
+}
+
+/// Calendar with the custom holidays on top of the base one
+/// (like local bank holidays or unscheduled closing days).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Holidays<C: BankingCalendar = Weekdays> {
+    base: C,
+    dates: BTreeSet<Date>,
+}
+
+impl<C: BankingCalendar> Holidays<C> {
+    /// The calendar without custom holidays.
+    pub fn new(base: C) -> Self {
+        Self {
+            base,
+            dates: BTreeSet::new(),
+        }
+    }
+
+    /// Add the holidays.
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = Date>) -> Self {
+        self.dates.extend(dates);
+        self
+    }
+
+    /// Add the holidays listed in the text, one ISO 8601 date (`YYYY-MM-DD`) per line.
+    ///
+    /// The date can be followed by the name of the holiday. Empty lines
+    /// and comments starting with `#` are ignored:
+    ///
+    /// ```text
+    /// # Bank holidays in Germany
+    /// 2025-10-03 German Unity Day
+    /// 2025-12-24 Christmas Eve
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if any line doesn't start with a valid date.
+    pub fn load(mut self, text: &str) -> Result<Self, Error> {
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let date = line
+                .split_whitespace()
+                .next()
+                .and_then(parse_date)
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!("Invalid holiday at line {}", index + 1))
+                })?;
+            self.dates.insert(date);
+        }
+        Ok(self)
+    }
+
+    /// The base calendar.
+    #[inline]
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// The custom holidays (in chronological order).
+    pub fn holidays(&self) -> impl Iterator<Item = Date> + '_ {
+        self.dates.iter().copied()
+    }
+}
+
+impl<C: BankingCalendar> BankingCalendar for Holidays<C> {
+    fn is_business_day(&self, date: Date) -> bool {
+        !self.dates.contains(&date) && self.base.is_business_day(date)
+    }
+}
+
+/// The date of the ISO 8601 string (`YYYY-MM-DD`).
+fn parse_date(value: &str) -> Option<Date> {
+    let mut parts = value.splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    if (year.len(), month.len(), day.len()) != (4, 2, 2) {
+        return None;
+    }
+    crate::Date {
+        year: year.parse().ok()?,
+        month: month.parse().ok()?,
+        day: day.parse().ok()?,
+    }
+    .try_into()
+    .ok()
+}
+
+/// Easter Sunday of the Gregorian calendar (the anonymous Gregorian algorithm).
+fn easter_sunday(year: u16) -> Date {
+    let a = year % 19;
+    let (b, c) = (year / 100, year % 100);
+    let (d, e) = (b / 4, b % 4);
+    let g = (8 * b + 13) / 25;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l + 114 - 7 * m) / 31;
+    let day = (h + l + 114 - 7 * m) % 31 + 1;
+    Date::of(year, month as u8, day as u8)
+}
+
+/// The observed federal holidays of the year.
+fn federal_holidays(year: u16) -> Vec<Date> {
+    let mut holidays = vec![
+        Date::of(year, 1, 1),                        // New Year's Day
+        nth_weekday(year, 1, Weekday::Monday, 3),    // Martin Luther King Jr. Day
+        nth_weekday(year, 2, Weekday::Monday, 3),    // Washington's Birthday
+        last_weekday(year, 5, Weekday::Monday),      // Memorial Day
+        Date::of(year, 7, 4),                        // Independence Day
+        nth_weekday(year, 9, Weekday::Monday, 1),    // Labor Day
+        nth_weekday(year, 10, Weekday::Monday, 2),   // Columbus Day
+        Date::of(year, 11, 11),                      // Veterans Day
+        nth_weekday(year, 11, Weekday::Thursday, 4), // Thanksgiving Day
+        Date::of(year, 12, 25),                      // Christmas Day
+    ];
+    if year >= 2022 {
+        holidays.push(Date::of(year, 6, 19)); // Juneteenth National Independence Day
+    }
+    for date in &mut holidays {
+        if date.weekday() == Weekday::Sunday {
+            *date = date.add_days(1);
+        }
+    }
+    holidays
+}
+
+/// The n-th weekday of the month (starting from 1).
+fn nth_weekday(year: u16, month: u8, weekday: Weekday, n: i64) -> Date {
+    let mut date = Date::of(year, month, 1);
+    while date.weekday() != weekday {
+        date = date.add_days(1);
+    }
+    date.add_days(7 * (n - 1))
+}
+
+/// The last weekday of the month.
+fn last_weekday(year: u16, month: u8, weekday: Weekday) -> Date {
+    let mut date = Date::last_day_of(year, month);
+    while date.weekday() != weekday {
+        date = date.add_days(-1);
+    }
+    date
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::date;
+
+    #[test]
+    fn computes_easter_sundays() {
+        assert_eq!(easter_sunday(2024), date(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), date(2025, 4, 20));
+        assert_eq!(easter_sunday(2026), date(2026, 4, 5));
+        assert_eq!(easter_sunday(2038), date(2038, 4, 25));
+    }
+
+    #[test]
+    fn skips_weekends_and_target2_holidays() {
+        assert!(Target2.is_business_day(date(2025, 4, 17)));
+        assert!(!Target2.is_business_day(date(2025, 4, 18)));
+        assert!(!Target2.is_business_day(date(2025, 4, 21)));
+        assert!(!Target2.is_business_day(date(2025, 5, 1)));
+        assert!(Target2.is_business_day(date(2025, 12, 24)));
+
+        assert_eq!(
+            Target2.add_business_days(date(2025, 4, 17), 1),
+            Some(date(2025, 4, 22))
+        );
+        assert_eq!(
+            Target2.next_business_day(date(2025, 12, 25)),
+            Some(date(2025, 12, 29))
+        );
+        assert_eq!(
+            Target2.next_business_day(date(2026, 1, 1)),
+            Some(date(2026, 1, 2))
+        );
+    }
+
+    #[test]
+    fn observes_federal_holidays() {
+        let holidays = [
+            date(2025, 1, 1),
+            date(2025, 1, 20),
+            date(2025, 2, 17),
+            date(2025, 5, 26),
+            date(2025, 6, 19),
+            date(2025, 7, 4),
+            date(2025, 9, 1),
+            date(2025, 10, 13),
+            date(2025, 11, 11),
+            date(2025, 11, 27),
+            date(2025, 12, 25),
+        ];
+        for holiday in holidays {
+            assert!(!FederalReserve.is_business_day(holiday), "{holiday:?}");
+        }
+
+        // Sunday holidays are observed on Monday
+        assert!(!FederalReserve.is_business_day(date(2022, 6, 20)));
+        assert!(!FederalReserve.is_business_day(date(2023, 1, 2)));
+        // Saturday holidays are not moved
+        assert!(FederalReserve.is_business_day(date(2026, 7, 3)));
+        assert!(FederalReserve.is_business_day(date(2025, 12, 24)));
+    }
+
+    #[test]
+    fn adds_business_days_in_both_directions() {
+        assert_eq!(
+            FederalReserve.add_business_days(date(2025, 5, 23), 1),
+            Some(date(2025, 5, 27))
+        );
+        assert_eq!(
+            FederalReserve.add_business_days(date(2025, 5, 27), -1),
+            Some(date(2025, 5, 23))
+        );
+        assert_eq!(
+            Weekdays.add_business_days(date(2025, 3, 7), 5),
+            Some(date(2025, 3, 14))
+        );
+        assert_eq!(
+            Weekdays.add_business_days(date(2025, 3, 1), 0),
+            Some(date(2025, 3, 3))
+        );
+    }
+
+    #[test]
+    fn stops_at_supported_dates() {
+        assert_eq!(Target2.add_business_days(date(1970, 1, 2), -1), None);
+        assert_eq!(
+            Weekdays.add_business_days(date(1970, 1, 5), -1),
+            Some(date(1970, 1, 2))
+        );
+
+        let calendar = Holidays::new(Weekdays).with_holidays([date(2199, 12, 31)]);
+        assert_eq!(calendar.next_business_day(date(2199, 12, 31)), None);
+        assert_eq!(calendar.add_business_days(date(2199, 12, 30), 1), None);
+        assert_eq!(
+            calendar.next_business_day(date(2199, 12, 30)),
+            Some(date(2199, 12, 30))
+        );
+    }
+
+    #[test]
+    fn finds_no_business_days_in_closed_calendar() {
+        struct Closed;
+
+        impl BankingCalendar for Closed {
+            fn is_business_day(&self, _date: Date) -> bool {
+                false
+            }
+        }
+
+        assert_eq!(Closed.next_business_day(date(2025, 1, 1)), None);
+        assert_eq!(Closed.add_business_days(date(2025, 1, 1), -1), None);
+    }
+
+    #[test]
+    fn loads_custom_holidays() {
+        let calendar = Holidays::new(Target2)
+            .load("# Bank holidays in Germany\n\n2025-10-03 German Unity Day\n  2025-12-24\n")
+            .unwrap();
+
+        assert_eq!(
+            calendar.holidays().collect::<Vec<_>>(),
+            [date(2025, 10, 3), date(2025, 12, 24)]
+        );
+        assert!(!calendar.is_business_day(date(2025, 10, 3)));
+        assert!(!calendar.is_business_day(date(2025, 12, 25)));
+        assert_eq!(
+            calendar.next_business_day(date(2025, 12, 24)),
+            Some(date(2025, 12, 29))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_holidays() {
+        for text in ["2025-02-30", "2025-1-05", "25-01-05", "holiday"] {
+            assert!(
+                matches!(
+                    Holidays::new(Weekdays).load(&format!("2025-01-01\n{text}")),
+                    Err(Error::InvalidInput(message)) if message.contains("line 2")
+                ),
+                "{text}"
+            );
+        }
+    }
+}
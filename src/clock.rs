@@ -0,0 +1,107 @@
+//! Source of the current time for time-dependent checks.
+//!
+//! Checks like card expiry depend on the current date. To keep them testable,
+//! they take the [`Clock`] provided by the application instead of reading
+//! the system time directly.
+//!
+//! Calendar computations (like billing dates) are made in the customer's time zone.
+//! The crate doesn't bundle the time zone database, so the offsets are provided
+//! by the application's [`TimeZone`] (backed by `chrono-tz` or similar), or fixed
+//! via [`FixedOffset`].
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::types::Date;
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// The current moment (Unix timestamp).
+    fn now(&self) -> i64;
+
+    /// The current date (in UTC).
+    fn today(&self) -> Date {
+        Date::from_timestamp(self.now())
+    }
+}
+
+/// The clock backed by the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() as i64)
+    }
+}
+
+/// The clock stopped at the given moment (Unix timestamp), for tests.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub i64);
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now(&self) -> i64 {
+        self.0
+    }
+}
+
+/// Source of the local time offsets from UTC.
+pub trait TimeZone: Send + Sync {
+    /// The offset of the local time from UTC (in seconds) at the moment (Unix timestamp).
+    fn utc_offset(&self, timestamp: i64) -> i32;
+
+    /// The local date at the moment (Unix timestamp).
+    fn date_at(&self, timestamp: i64) -> Date {
+        Date::from_timestamp(timestamp + i64::from(self.utc_offset(timestamp)))
+    }
+
+    /// The moment (Unix timestamp) of the local midnight starting the date.
+    fn midnight(&self, date: Date) -> i64 {
+        let local = date.timestamp();
+        let guess = local - i64::from(self.utc_offset(local));
+        local - i64::from(self.utc_offset(guess))
+    }
+}
+
+/// The time zone with the constant offset from UTC (in seconds), like `FixedOffset(0)` for UTC.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FixedOffset(pub i32);
+
+impl TimeZone for FixedOffset {
+    #[inline]
+    fn utc_offset(&self, _timestamp: i64) -> i32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_local_dates_and_midnights() {
+        // 2024-03-01 00:00:00 UTC
+        let timestamp = 1_709_251_200;
+        let tokyo = FixedOffset(9 * 3600);
+        let new_york = FixedOffset(-5 * 3600);
+
+        assert_eq!(
+            tokyo.date_at(timestamp - 1),
+            Date::from_timestamp(timestamp)
+        );
+        assert_eq!(
+            new_york.date_at(timestamp),
+            Date::from_timestamp(timestamp - 1)
+        );
+        assert_eq!(
+            tokyo.midnight(Date::from_timestamp(timestamp)),
+            timestamp - 9 * 3600
+        );
+        assert_eq!(
+            new_york.midnight(Date::from_timestamp(timestamp)),
+            timestamp + 5 * 3600
+        );
+    }
+}
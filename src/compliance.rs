@@ -0,0 +1,141 @@
+//! Card network rules of merchant initiated transactions (MIT) and stored credentials.
+//!
+//! Visa and Mastercard reject (or downgrade) merchant initiated payments which are not
+//! linked to the initial agreement of the cardholder. The [`validate_stored_credential`]
+//! checks the combination of the [`MerchantInitiatedType`] and the [`StoredCredentialUsage`]
+//! before the payment reaches the gateway:
+//!
+//! ```skip
+//! // The network transaction ID of the initial customer initiated payment
+//! let usage = initial_transaction.subsequent_usage()?;
+//! let mit = Some(MerchantInitiatedType::Recurring);
+//!
+//! validate_stored_credential(PaymentFlow::Immediate, mit, Some(&usage))?;
+//! gateway.charge(payment, NoInstallments, mit, Some(usage)).await?;
+//! ```
+//!
+//! The [`Compliant`](crate::decorators::Compliant) decorator runs the validation
+//! before every `charge` and `authorize` call of the wrapped gateway.
+
+use crate::types::StoredCredentialUsage;
+use crate::{Error, MerchantInitiatedType};
+
+/// The flow the payment is sent by.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaymentFlow {
+    /// The payment is captured immediately (`ImmediatePayments::charge`).
+    Immediate,
+    /// The payment is authorized to be captured later (`DeferredPayments::authorize`).
+    Deferred,
+}
+
+/// Check the merchant initiated type against the stored credential usage.
+///
+/// # Validation
+/// * the initial use of stored credentials is customer initiated (comes without MIT),
+/// * recurring, installment and unscheduled MIT use the stored credentials
+///   of the initial agreement (`StoredCredentialUsage::Subsequent`),
+/// * incremental authorizations and reauthorizations are applicable to deferred payments only.
+///
+/// The subsequent usage always refers to the [`NetworkTransactionId`](crate::types::NetworkTransactionId)
+/// of the initial agreement, so it cannot be built without one.
+///
+/// # Errors
+/// Returns `Error::InvalidInput` describing the first violated rule.
+pub fn validate_stored_credential(
+    flow: PaymentFlow,
+    merchant_initiated_type: Option<MerchantInitiatedType>,
+    stored_credential_usage: Option<&StoredCredentialUsage>,
+) -> Result<(), Error> {
+    use MerchantInitiatedType::*;
+
+    let Some(mit) = merchant_initiated_type else {
+        return Ok(());
+    };
+    match (mit, stored_credential_usage) {
+        (_, Some(StoredCredentialUsage::Initial)) => Err(Error::InvalidInput(format!(
+            "{mit} transaction cannot be the initial use of stored credentials"
+        ))),
+        (Recurring | Installment | UnscheduledCardOnFile, None) => Err(Error::InvalidInput(
+            format!("{mit} transaction requires stored credentials of the initial agreement"),
+        )),
+        (Incremental | Reauthorization, _) if flow != PaymentFlow::Deferred => {
+            Err(Error::InvalidInput(format!(
+                "{mit} transaction is applicable to deferred payments only"
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subsequent() -> StoredCredentialUsage {
+        StoredCredentialUsage::Subsequent("583112345678901".try_into().unwrap())
+    }
+
+    #[test]
+    fn accepts_customer_initiated_payments() {
+        for usage in [
+            None,
+            Some(StoredCredentialUsage::Initial),
+            Some(subsequent()),
+        ] {
+            let result = validate_stored_credential(PaymentFlow::Immediate, None, usage.as_ref());
+            assert!(result.is_ok(), "{usage:?} failed validation");
+        }
+    }
+
+    #[test]
+    fn accepts_merchant_initiated_payments_on_stored_credentials() {
+        let usage = subsequent();
+        for mit in [
+            MerchantInitiatedType::Recurring,
+            MerchantInitiatedType::Installment,
+            MerchantInitiatedType::UnscheduledCardOnFile,
+            MerchantInitiatedType::NoShow,
+        ] {
+            let result =
+                validate_stored_credential(PaymentFlow::Immediate, Some(mit), Some(&usage));
+            assert!(result.is_ok(), "{mit} failed validation");
+        }
+    }
+
+    #[test]
+    fn rejects_initial_usage_by_merchant() {
+        let result = validate_stored_credential(
+            PaymentFlow::Immediate,
+            Some(MerchantInitiatedType::Recurring),
+            Some(&StoredCredentialUsage::Initial),
+        );
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_recurring_payment_without_stored_credentials() {
+        for mit in [
+            MerchantInitiatedType::Recurring,
+            MerchantInitiatedType::Installment,
+            MerchantInitiatedType::UnscheduledCardOnFile,
+        ] {
+            let result = validate_stored_credential(PaymentFlow::Deferred, Some(mit), None);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{mit}");
+        }
+    }
+
+    #[test]
+    fn restricts_incremental_authorizations_to_deferred_payments() {
+        for mit in [
+            MerchantInitiatedType::Incremental,
+            MerchantInitiatedType::Reauthorization,
+        ] {
+            let immediate = validate_stored_credential(PaymentFlow::Immediate, Some(mit), None);
+            assert!(matches!(immediate, Err(Error::InvalidInput(_))), "{mit}");
+
+            let deferred = validate_stored_credential(PaymentFlow::Deferred, Some(mit), None);
+            assert!(deferred.is_ok(), "{mit}");
+        }
+    }
+}
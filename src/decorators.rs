@@ -0,0 +1,19 @@
+//! Decorators wrapping payment gateway adapters with cross-cutting concerns.
+//!
+//! A decorator implements the same flow traits as the gateway it wraps
+//! and delegates every call to it, so it can be used in place of the original
+//! adapter without changing client code.
+//!
+//! * [`Compliant`] rejects merchant initiated payments breaking the card network rules,
+//! * [`Metered`] reports outcomes of flow calls to the [`Metrics`](crate::metrics::Metrics) hooks,
+//! * [`Traced`] emits a `tracing` span per flow call (requires the `tracing` feature).
+
+mod compliant;
+mod metered;
+#[cfg(feature = "tracing")]
+mod traced;
+
+pub use compliant::Compliant;
+pub use metered::Metered;
+#[cfg(feature = "tracing")]
+pub use traced::Traced;
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+
+use crate::compliance::{PaymentFlow, validate_stored_credential};
+use crate::flows::{
+    CancelPayments, CheckTransaction, DeferredPayments, ExternalPayments, ImmediatePayments,
+    RefundPayments, ReversePayment, StoreCredentials,
+};
+use crate::gateway::{authorize, secure};
+use crate::types::payments::PaymentMarker;
+use crate::types::{
+    ExternalPayment, ExternalPaymentData, ExternalPaymentMethod, InternalPaymentMethod,
+    ReversalReason, StoredCredentialUsage, Transaction, TransactionId, VaultPaymentMethod,
+};
+use crate::{Error, Gateway, MerchantInitiatedType};
+
+/// Gateway decorator rejecting merchant initiated payments which break the card network rules.
+///
+/// Before every `charge` and `authorize` call the decorator checks the merchant initiated type
+/// against the stored credential usage (see [`validate_stored_credential`]),
+/// and fails with `Error::InvalidInput` without calling the wrapped gateway.
+/// Other calls are delegated as is.
+///
+/// ```skip
+/// let gateway = Compliant::new(AdyenGateway::new(config));
+/// let usage = initial_transaction.subsequent_usage()?;
+/// let mit = Some(MerchantInitiatedType::Recurring);
+/// let transaction = gateway.charge(payment, NoInstallments, mit, Some(usage)).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Compliant<G> {
+    inner: G,
+}
+
+impl<G: Gateway> Compliant<G> {
+    /// Wraps the gateway adapter.
+    #[inline]
+    pub fn new(inner: G) -> Self {
+        Self { inner }
+    }
+
+    /// The wrapped gateway adapter.
+    #[inline]
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// Unwraps the gateway adapter.
+    #[inline]
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<G: Gateway> Gateway for Compliant<G> {
+    type Payment = G::Payment;
+    type Installments = G::Installments;
+    type PaymentMethod = G::PaymentMethod;
+    type AuthorizedPaymentMethod = G::AuthorizedPaymentMethod;
+    type SecuredPaymentMethod = G::SecuredPaymentMethod;
+
+    async fn authorize(
+        &self,
+        request: authorize::Request<Self::PaymentMethod>,
+    ) -> Result<authorize::Response<Self::AuthorizedPaymentMethod>, Error> {
+        self.inner.authorize(request).await
+    }
+
+    async fn secure(
+        &self,
+        request: secure::Request<Self::AuthorizedPaymentMethod>,
+    ) -> Result<secure::Response<Self::SecuredPaymentMethod>, Error> {
+        self.inner.secure(request).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ImmediatePayments for Compliant<G>
+where
+    G: ImmediatePayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn charge(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        validate_stored_credential(
+            PaymentFlow::Immediate,
+            merchant_initiated_type,
+            stored_credential_usage.as_ref(),
+        )?;
+        self.inner
+            .charge(
+                payment,
+                installments,
+                merchant_initiated_type,
+                stored_credential_usage,
+            )
+            .await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> DeferredPayments for Compliant<G>
+where
+    G: DeferredPayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    type AuthorizationChanges = G::AuthorizationChanges;
+    type CapturedAmount = G::CapturedAmount;
+    type CapturedDistribution = G::CapturedDistribution;
+
+    #[allow(private_interfaces)]
+    async fn authorize(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        validate_stored_credential(
+            PaymentFlow::Deferred,
+            merchant_initiated_type,
+            stored_credential_usage.as_ref(),
+        )?;
+        DeferredPayments::authorize(
+            &self.inner,
+            payment,
+            installments,
+            merchant_initiated_type,
+            stored_credential_usage,
+        )
+        .await
+    }
+
+    async fn capture(
+        &self,
+        transaction_id: TransactionId,
+        captured_amount: Self::CapturedAmount,
+        captured_distribution: Self::CapturedDistribution,
+    ) -> Result<Transaction, Error> {
+        self.inner
+            .capture(transaction_id, captured_amount, captured_distribution)
+            .await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ExternalPayments for Compliant<G>
+where
+    G: ExternalPayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: ExternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn initiate(&self, payment: Self::Payment) -> Result<ExternalPayment, Error> {
+        self.inner.initiate(payment).await
+    }
+
+    async fn payment_data(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<ExternalPaymentData, Error> {
+        self.inner.payment_data(transaction_id).await
+    }
+}
+
+#[async_trait]
+impl<G: CheckTransaction> CheckTransaction for Compliant<G> {
+    async fn status(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        self.inner.status(transaction_id).await
+    }
+}
+
+#[async_trait]
+impl<G: CancelPayments> CancelPayments for Compliant<G> {
+    async fn void(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        self.inner.void(transaction_id).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G: RefundPayments> RefundPayments for Compliant<G> {
+    type RefundAmount = G::RefundAmount;
+    type RefundDistribution = G::RefundDistribution;
+
+    async fn refund(
+        &self,
+        transaction_id: TransactionId,
+        refund_amount: Self::RefundAmount,
+        refund_distribution: Self::RefundDistribution,
+    ) -> Result<Transaction, Error> {
+        self.inner
+            .refund(transaction_id, refund_amount, refund_distribution)
+            .await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ReversePayment for Compliant<G>
+where
+    G: ReversePayment,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    async fn reverse(
+        &self,
+        transaction_id: TransactionId,
+        reason: Option<ReversalReason>,
+    ) -> Result<Transaction, Error> {
+        self.inner.reverse(transaction_id, reason).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds, private_interfaces)]
+impl<G> StoreCredentials for Compliant<G>
+where
+    G: StoreCredentials,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: VaultPaymentMethod,
+{
+    type StoredPaymentMethod = G::StoredPaymentMethod;
+
+    async fn store(
+        &self,
+        payment_method: Self::StoredPaymentMethod,
+    ) -> Result<<<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod, Error> {
+        self.inner.store(payment_method).await
+    }
+
+    async fn unstore(
+        &self,
+        token: <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod,
+    ) -> Result<(), Error> {
+        self.inner.unstore(token).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionStatus;
+    use crate::test_support::{self, MockGateway};
+    use crate::types::NoInstallments;
+    use futures::executor::block_on;
+
+    fn gateway() -> Compliant<MockGateway> {
+        Compliant::new(MockGateway {
+            status: Some(TransactionStatus::Captured),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn charges_merchant_initiated_payment_on_stored_credentials() {
+        let usage = StoredCredentialUsage::Subsequent("583112345678901".try_into().unwrap());
+        let mit = Some(MerchantInitiatedType::Recurring);
+
+        let transaction =
+            block_on(gateway().charge(test_support::payment(), NoInstallments, mit, Some(usage)))
+                .unwrap();
+        assert_eq!(*transaction.status(), TransactionStatus::Captured);
+    }
+
+    #[test]
+    fn rejects_invalid_combination_before_calling_gateway() {
+        // The mock would fail with `Error::NotSupported` if called
+        let gateway = Compliant::new(MockGateway::default());
+        let mit = Some(MerchantInitiatedType::Recurring);
+
+        let result = block_on(gateway.charge(test_support::payment(), NoInstallments, mit, None));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_incremental_charge() {
+        let mit = Some(MerchantInitiatedType::Incremental);
+
+        let result = block_on(gateway().charge(test_support::payment(), NoInstallments, mit, None));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
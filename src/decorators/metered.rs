@@ -0,0 +1,568 @@
+use async_trait::async_trait;
+use iso_currency::Currency;
+use std::time::Instant;
+
+use crate::flows::{
+    CancelPayments, CheckTransaction, DeferredPayments, ExternalPayments, ImmediatePayments,
+    RefundPayments, ReversePayment,
+};
+use crate::gateway::{authorize, secure};
+use crate::metrics::{Labels, Metrics, Outcome};
+use crate::types::payments::PaymentMarker;
+use crate::types::{
+    ExternalPayment, ExternalPaymentData, ExternalPaymentMethod, InternalPaymentMethod,
+    PaymentMethod, ReversalReason, StoredCredentialUsage, Transaction, TransactionId,
+};
+use crate::{Error, Gateway, MerchantInitiatedType};
+
+/// Gateway decorator reporting the outcome of every flow call to [`Metrics`].
+///
+/// For every call the decorator reports an attempt (before calling the wrapped gateway),
+/// its latency, and either an approval, a decline (with the canonical reason), a pending result,
+/// a failed transaction, or an error (by its kind).
+/// Measurements are labeled by the gateway name, the flow, the payment method kind
+/// (the method of the payment, or the method being authorized for the authorization step)
+/// and the currency of the payment. Flows without a payment (like capture, void, refund
+/// and status) are labeled without the currency, so that all measurements of a call
+/// share the same labels.
+///
+/// ```skip
+/// let metrics = Arc::new(PrometheusMetrics::new(registry));
+/// let gateway = Metered::new("adyen", AdyenGateway::new(config), metrics);
+/// let transaction = gateway.charge(payment, NoInstallments, None, None).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Metered<G, M> {
+    name: &'static str,
+    inner: G,
+    metrics: M,
+}
+
+impl<G: Gateway, M: Metrics> Metered<G, M> {
+    /// Wraps the gateway adapter under the given name.
+    #[inline]
+    pub fn new(name: &'static str, inner: G, metrics: M) -> Self {
+        Self {
+            name,
+            inner,
+            metrics,
+        }
+    }
+
+    /// The name of the gateway used to label measurements.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The wrapped gateway adapter.
+    #[inline]
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// The receiver of measurements.
+    #[inline]
+    pub fn metrics(&self) -> &M {
+        &self.metrics
+    }
+
+    /// Unwraps the gateway adapter.
+    #[inline]
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+
+    /// The labels of payment flows (by the method of the payment).
+    fn labels(&self, flow: &'static str, currency: Option<Currency>) -> Labels {
+        Labels {
+            gateway: self.name,
+            flow,
+            payment_method: <<G::Payment as PaymentMarker>::PaymentMethod as PaymentMethod>::KIND,
+            currency,
+        }
+    }
+
+    /// The labels of the authorization steps (by the method being authorized).
+    fn authorization_labels(&self, flow: &'static str) -> Labels {
+        Labels {
+            payment_method: <G::PaymentMethod as PaymentMethod>::KIND,
+            ..self.labels(flow, None)
+        }
+    }
+
+    /// Reports the start of a call.
+    fn start(&self, labels: Labels) -> Instant {
+        self.metrics.attempt(&labels);
+        Instant::now()
+    }
+
+    /// Reports a call that does not return a transaction.
+    fn report<T>(&self, labels: Labels, started: Instant, result: &Result<T, Error>) {
+        self.metrics.latency(&labels, started.elapsed());
+        if let Err(error) = result {
+            self.metrics.error(&labels, error.kind());
+        }
+    }
+
+    /// Reports the outcome of the returned transaction.
+    fn report_status(&self, labels: &Labels, transaction: &Transaction) {
+        match Outcome::from(*transaction.status()) {
+            Outcome::Approved => self.metrics.approval(labels),
+            Outcome::Declined => self.metrics.decline(labels, transaction.decline_reason()),
+            Outcome::Failed => self.metrics.failure(labels),
+            Outcome::Pending => self.metrics.pending(labels),
+        }
+    }
+
+    /// Reports a call returning a transaction (with the labels of its attempt).
+    fn report_transaction(
+        &self,
+        labels: Labels,
+        started: Instant,
+        result: &Result<Transaction, Error>,
+    ) {
+        self.report(labels, started, result);
+        if let Ok(transaction) = result {
+            self.report_status(&labels, transaction);
+        }
+    }
+}
+
+#[async_trait]
+impl<G: Gateway, M: Metrics> Gateway for Metered<G, M> {
+    type Payment = G::Payment;
+    type Installments = G::Installments;
+    type PaymentMethod = G::PaymentMethod;
+    type AuthorizedPaymentMethod = G::AuthorizedPaymentMethod;
+    type SecuredPaymentMethod = G::SecuredPaymentMethod;
+
+    async fn authorize(
+        &self,
+        request: authorize::Request<Self::PaymentMethod>,
+    ) -> Result<authorize::Response<Self::AuthorizedPaymentMethod>, Error> {
+        let labels = self.authorization_labels("authorize_payment_method");
+        let started = self.start(labels);
+        let result = self.inner.authorize(request).await;
+        self.report(labels, started, &result);
+        match result {
+            Ok(authorize::Response::Authorized { .. }) => self.metrics.approval(&labels),
+            Ok(authorize::Response::RequiresAction(_)) => self.metrics.pending(&labels),
+            Err(_) => {}
+        }
+        result
+    }
+
+    async fn secure(
+        &self,
+        request: secure::Request<Self::AuthorizedPaymentMethod>,
+    ) -> Result<secure::Response<Self::SecuredPaymentMethod>, Error> {
+        let labels = self.authorization_labels("secure_payment_method");
+        let started = self.start(labels);
+        let result = self.inner.secure(request).await;
+        self.report(labels, started, &result);
+        match result {
+            Ok(secure::Response::Secured(_)) => self.metrics.approval(&labels),
+            Ok(secure::Response::RequiresAction(_)) => self.metrics.pending(&labels),
+            Ok(
+                secure::Response::ExemptionRejected(reason) | secure::Response::Declined(reason),
+            ) => self.metrics.decline(&labels, Some(reason)),
+            Err(_) => {}
+        }
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, M> ImmediatePayments for Metered<G, M>
+where
+    G: ImmediatePayments,
+    M: Metrics,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn charge(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        let currency = payment.currency();
+        let labels = self.labels("charge", Some(currency));
+        let started = self.start(labels);
+        let result = self
+            .inner
+            .charge(
+                payment,
+                installments,
+                merchant_initiated_type,
+                stored_credential_usage,
+            )
+            .await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, M> DeferredPayments for Metered<G, M>
+where
+    G: DeferredPayments,
+    M: Metrics,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    type AuthorizationChanges = G::AuthorizationChanges;
+    type CapturedAmount = G::CapturedAmount;
+    type CapturedDistribution = G::CapturedDistribution;
+
+    #[allow(private_interfaces)]
+    async fn authorize(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        let currency = payment.currency();
+        let labels = self.labels("authorize", Some(currency));
+        let started = self.start(labels);
+        let result = DeferredPayments::authorize(
+            &self.inner,
+            payment,
+            installments,
+            merchant_initiated_type,
+            stored_credential_usage,
+        )
+        .await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+
+    async fn capture(
+        &self,
+        transaction_id: TransactionId,
+        captured_amount: Self::CapturedAmount,
+        captured_distribution: Self::CapturedDistribution,
+    ) -> Result<Transaction, Error> {
+        let labels = self.labels("capture", None);
+        let started = self.start(labels);
+        let result = self
+            .inner
+            .capture(transaction_id, captured_amount, captured_distribution)
+            .await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, M> ExternalPayments for Metered<G, M>
+where
+    G: ExternalPayments,
+    M: Metrics,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: ExternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn initiate(&self, payment: Self::Payment) -> Result<ExternalPayment, Error> {
+        let labels = self.labels("initiate", Some(payment.currency()));
+        let started = self.start(labels);
+        let result = self.inner.initiate(payment).await;
+        self.report(labels, started, &result);
+        if let Ok(external_payment) = &result {
+            self.report_status(&labels, external_payment.transaction());
+        }
+        result
+    }
+
+    async fn payment_data(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<ExternalPaymentData, Error> {
+        let labels = self.labels("payment_data", None);
+        let started = self.start(labels);
+        let result = self.inner.payment_data(transaction_id).await;
+        self.report(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+impl<G: CheckTransaction, M: Metrics> CheckTransaction for Metered<G, M> {
+    async fn status(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        let labels = self.labels("status", None);
+        let started = self.start(labels);
+        let result = self.inner.status(transaction_id).await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+impl<G: CancelPayments, M: Metrics> CancelPayments for Metered<G, M> {
+    async fn void(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        let labels = self.labels("void", None);
+        let started = self.start(labels);
+        let result = self.inner.void(transaction_id).await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G: RefundPayments, M: Metrics> RefundPayments for Metered<G, M> {
+    type RefundAmount = G::RefundAmount;
+    type RefundDistribution = G::RefundDistribution;
+
+    async fn refund(
+        &self,
+        transaction_id: TransactionId,
+        refund_amount: Self::RefundAmount,
+        refund_distribution: Self::RefundDistribution,
+    ) -> Result<Transaction, Error> {
+        let labels = self.labels("refund", None);
+        let started = self.start(labels);
+        let result = self
+            .inner
+            .refund(transaction_id, refund_amount, refund_distribution)
+            .await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, M> ReversePayment for Metered<G, M>
+where
+    G: ReversePayment,
+    M: Metrics,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    async fn reverse(
+        &self,
+        transaction_id: TransactionId,
+        reason: Option<ReversalReason>,
+    ) -> Result<Transaction, Error> {
+        let labels = self.labels("reverse", None);
+        let started = self.start(labels);
+        let result = self.inner.reverse(transaction_id, reason).await;
+        self.report_transaction(labels, started, &result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::InMemoryMetrics;
+    use crate::test_support::{self, MockGateway};
+    use crate::types::{CreditCard, NoInstallments, Payment, Vault};
+    use crate::{DeclineReason, TransactionStatus, inputs};
+    use futures::executor::block_on;
+    use rust_decimal::Decimal;
+    use std::sync::{Arc, Mutex};
+
+    const LABELS: Labels = Labels {
+        gateway: "mock",
+        flow: "charge",
+        payment_method: "credit_card",
+        currency: Some(Currency::EUR),
+    };
+
+    fn gateway(
+        status: Option<TransactionStatus>,
+        decline_reason: Option<DeclineReason>,
+    ) -> Metered<MockGateway, InMemoryMetrics> {
+        Metered::new(
+            "mock",
+            MockGateway {
+                status,
+                decline_reason,
+                ..Default::default()
+            },
+            InMemoryMetrics::default(),
+        )
+    }
+
+    fn charge(gateway: &Metered<MockGateway, InMemoryMetrics>) -> Result<Transaction, Error> {
+        block_on(gateway.charge(test_support::payment(), NoInstallments, None, None))
+    }
+
+    #[test]
+    fn reports_approval() {
+        let gateway = gateway(Some(TransactionStatus::Captured), None);
+        charge(&gateway).unwrap();
+
+        let metrics = gateway.metrics();
+        assert_eq!(metrics.attempts(&LABELS), 1);
+        assert_eq!(metrics.approvals(&LABELS), 1);
+        assert_eq!(metrics.latencies(&LABELS).len(), 1);
+    }
+
+    #[test]
+    fn reports_decline_by_reason() {
+        let reason = Some(DeclineReason::InsufficientFunds);
+        let gateway = gateway(Some(TransactionStatus::Declined), reason);
+        charge(&gateway).unwrap();
+
+        let metrics = gateway.metrics();
+        assert_eq!(metrics.attempts(&LABELS), 1);
+        assert_eq!(metrics.approvals(&LABELS), 0);
+        assert_eq!(metrics.declines(&LABELS, reason), 1);
+        assert_eq!(metrics.approval_rate(&LABELS), Some(0.0));
+    }
+
+    #[test]
+    fn reports_error_by_kind() {
+        let gateway = gateway(None, None);
+        charge(&gateway).unwrap_err();
+
+        let metrics = gateway.metrics();
+        assert_eq!(metrics.attempts(&LABELS), 1);
+        assert_eq!(metrics.errors(&LABELS, "NotSupported"), 1);
+        assert_eq!(metrics.approval_rate(&LABELS), None);
+    }
+
+    #[test]
+    fn reports_failure_apart_from_declines() {
+        let gateway = gateway(Some(TransactionStatus::Failed), None);
+        charge(&gateway).unwrap();
+
+        let metrics = gateway.metrics();
+        assert_eq!(metrics.attempts(&LABELS), 1);
+        assert_eq!(metrics.failures(&LABELS), 1);
+        assert_eq!(metrics.declines(&LABELS, None), 0);
+        assert_eq!(metrics.approval_rate(&LABELS), None);
+    }
+
+    /// Log of the calls to the gateway and the metrics.
+    #[derive(Default)]
+    struct Log(Mutex<Vec<&'static str>>);
+
+    impl Log {
+        fn push(&self, entry: &'static str) {
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    impl Metrics for Log {
+        fn attempt(&self, _labels: &Labels) {
+            self.push("attempt");
+        }
+
+        fn approval(&self, _labels: &Labels) {
+            self.push("approval");
+        }
+    }
+
+    /// Gateway charging vault tokens, while authorizing cards.
+    #[derive(Default)]
+    struct TokenGateway {
+        mock: MockGateway,
+        log: Arc<Log>,
+    }
+
+    #[async_trait]
+    impl Gateway for TokenGateway {
+        type Payment = Payment<Vault>;
+        type Installments = NoInstallments;
+        type PaymentMethod = CreditCard;
+        type AuthorizedPaymentMethod = CreditCard;
+        type SecuredPaymentMethod = CreditCard;
+
+        async fn authorize(
+            &self,
+            request: authorize::Request<CreditCard>,
+        ) -> Result<authorize::Response<CreditCard>, Error> {
+            self.mock.authorize(request).await
+        }
+
+        async fn secure(
+            &self,
+            request: secure::Request<CreditCard>,
+        ) -> Result<secure::Response<CreditCard>, Error> {
+            self.mock.secure(request).await
+        }
+    }
+
+    #[async_trait]
+    impl ImmediatePayments for TokenGateway {
+        async fn charge(
+            &self,
+            _payment: Payment<Vault>,
+            _installments: NoInstallments,
+            _merchant_initiated_type: Option<MerchantInitiatedType>,
+            _stored_credential_usage: Option<StoredCredentialUsage>,
+        ) -> Result<Transaction, Error> {
+            self.log.push("charge");
+            self.mock.respond()
+        }
+    }
+
+    fn token_payment() -> Payment<Vault> {
+        inputs::Payment {
+            payment_method: "tok_1234567890ebadf",
+            currency: Currency::EUR,
+            total_amount: Decimal::new(1999, 2),
+            base_amount: Decimal::new(1999, 2),
+            idempotence_key: test_support::IDEMPOTENCE_KEY,
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    fn token_gateway<M: Metrics>(log: Arc<Log>, metrics: M) -> Metered<TokenGateway, M> {
+        let mock = MockGateway {
+            status: Some(TransactionStatus::Captured),
+            ..Default::default()
+        };
+        Metered::new("mock", TokenGateway { mock, log }, metrics)
+    }
+
+    #[test]
+    fn reports_attempt_before_call() {
+        let log = Arc::new(Log::default());
+        let gateway = token_gateway(log.clone(), log.clone());
+        block_on(gateway.charge(token_payment(), NoInstallments, None, None)).unwrap();
+
+        assert_eq!(*log.0.lock().unwrap(), ["attempt", "charge", "approval"]);
+    }
+
+    #[test]
+    fn labels_charges_by_payment_method_of_payment() {
+        let gateway = token_gateway(Arc::default(), InMemoryMetrics::default());
+        block_on(gateway.charge(token_payment(), NoInstallments, None, None)).unwrap();
+
+        let labels = Labels {
+            payment_method: "vault",
+            ..LABELS
+        };
+        assert_eq!(gateway.metrics().attempts(&labels), 1);
+        assert_eq!(gateway.metrics().approvals(&labels), 1);
+        assert_eq!(gateway.metrics().approvals(&LABELS), 0);
+    }
+
+    #[test]
+    fn labels_calls_without_payment_consistently() {
+        let gateway = gateway(Some(TransactionStatus::Captured), None);
+        block_on(gateway.status(test_support::transaction_id())).unwrap();
+
+        let labels = Labels {
+            flow: "status",
+            currency: None,
+            ..LABELS
+        };
+        assert_eq!(gateway.metrics().attempts(&labels), 1);
+        assert_eq!(gateway.metrics().approvals(&labels), 1);
+        let eur = Labels {
+            flow: "status",
+            ..LABELS
+        };
+        assert_eq!(gateway.metrics().approvals(&eur), 0);
+    }
+}
@@ -0,0 +1,688 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::time::Instant;
+use tracing::field::{Empty, debug, display};
+use tracing::{Instrument, Span};
+
+use crate::flows::change_authorization::{ChangesByDelta, ChangesByTotal};
+use crate::flows::{
+    AdjustAuthorization, CancelPayments, CheckTransaction, DeferredPayments, EditAuthorization,
+    ExternalPayments, ImmediatePayments, RecoverTransactions, RecurrentPayments, RefundPayments,
+    ReversePayment, StoreCredentials,
+};
+use crate::gateway::{authorize, secure};
+use crate::types::payments::PaymentMarker;
+use crate::types::{
+    ExternalPayment, ExternalPaymentData, ExternalPaymentMethod, InternalPaymentMethod,
+    ReversalReason, StoredCredentialUsage, Subscription, SubscriptionId, SubscriptionTerms,
+    Transaction, TransactionId, TransactionIdempotenceKey, VaultPaymentMethod,
+};
+use crate::{Error, Gateway, MerchantInitiatedType};
+
+/// Gateway decorator emitting a `tracing` span for every flow call.
+///
+/// Each span is named `payment_gateway` and carries the following fields:
+///
+/// * `gateway` — the name given to the decorator (like `stripe` or `adyen`),
+/// * `operation` — the name of the flow method (like `charge` or `capture`),
+/// * `idempotence_key`, `currency` and `amount` — taken from the payment (when available),
+/// * `transaction_id`, `status` — taken from the returned transaction,
+/// * `subscription_id` — taken from the subscription (for subscription flows),
+/// * `latency_ms` — the duration of the call to the wrapped gateway,
+/// * `error` — the canonical error if the call failed.
+///
+/// Nested events emitted by the wrapped adapter are recorded inside the span.
+///
+/// The decorator covers the payment flows (immediate, deferred and external payments,
+/// authorization changes, status checks, cancels, refunds and reversals),
+/// subscriptions (`RecurrentPayments`), stored credentials and transaction recovery.
+/// Other flows (like subscription edits, transaction search or customer management)
+/// are not traced, and must be called on the [`inner`](Self::inner) gateway.
+///
+/// # Data Protection
+/// Sensitive values are recorded through their `Debug` implementations only,
+/// which are backed by the masking rules of every secure type.
+/// Payment methods, metadata and other PII are never recorded.
+///
+/// ```skip
+/// let gateway = Traced::new("stripe", StripeGateway::new(config));
+/// let transaction = gateway.charge(payment, NoInstallments, None, None).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Traced<G> {
+    name: &'static str,
+    inner: G,
+}
+
+impl<G: Gateway> Traced<G> {
+    /// Wraps the gateway adapter under the given name.
+    #[inline]
+    pub fn new(name: &'static str, inner: G) -> Self {
+        Self { name, inner }
+    }
+
+    /// The name of the gateway recorded in spans.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The wrapped gateway adapter.
+    #[inline]
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// Unwraps the gateway adapter.
+    #[inline]
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+
+    fn span(&self, operation: &'static str) -> Span {
+        tracing::info_span!(
+            "payment_gateway",
+            gateway = self.name,
+            operation,
+            idempotence_key = Empty,
+            transaction_id = Empty,
+            subscription_id = Empty,
+            currency = Empty,
+            amount = Empty,
+            status = Empty,
+            latency_ms = Empty,
+            error = Empty,
+        )
+    }
+}
+
+/// Records the attributes of the payment sent to the gateway.
+fn record_payment<P: PaymentMarker>(span: &Span, payment: &P) {
+    span.record("idempotence_key", payment.idempotence_key().as_ref());
+    span.record("currency", payment.currency().code());
+    span.record("amount", display(payment.total_amount()));
+}
+
+/// Records the duration of the call along with its outcome.
+fn record_outcome<T>(span: &Span, started: Instant, result: &Result<T, Error>) {
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    if let Err(error) = result {
+        span.record("error", display(error));
+    }
+}
+
+/// Records the attributes of the transaction returned by the gateway.
+fn record_transaction(span: &Span, started: Instant, result: &Result<Transaction, Error>) {
+    if let Ok(transaction) = result {
+        span.record("transaction_id", debug(transaction.transaction_id()));
+        span.record(
+            "idempotence_key",
+            transaction.idempotence_key().as_ref() as &str,
+        );
+        span.record("currency", transaction.currency().code());
+        span.record("status", display(transaction.status()));
+    }
+    record_outcome(span, started, result);
+}
+
+/// Records the attributes of the subscription returned by the gateway.
+fn record_subscription(span: &Span, started: Instant, result: &Result<Subscription, Error>) {
+    if let Ok(subscription) = result {
+        span.record("subscription_id", debug(subscription.subscription_id()));
+        span.record("currency", subscription.currency().code());
+        span.record("status", display(subscription.status()));
+    }
+    record_outcome(span, started, result);
+}
+
+#[async_trait]
+impl<G: Gateway> Gateway for Traced<G> {
+    type Payment = G::Payment;
+    type Installments = G::Installments;
+    type PaymentMethod = G::PaymentMethod;
+    type AuthorizedPaymentMethod = G::AuthorizedPaymentMethod;
+    type SecuredPaymentMethod = G::SecuredPaymentMethod;
+
+    async fn authorize(
+        &self,
+        request: authorize::Request<Self::PaymentMethod>,
+    ) -> Result<authorize::Response<Self::AuthorizedPaymentMethod>, Error> {
+        let span = self.span("authorize_payment_method");
+        let started = Instant::now();
+        let result = self.inner.authorize(request).instrument(span.clone()).await;
+        if let Ok(authorize::Response::RequiresAction(_)) = result {
+            span.record("status", "RequiresAction");
+        }
+        record_outcome(&span, started, &result);
+        result
+    }
+
+    async fn secure(
+        &self,
+        request: secure::Request<Self::AuthorizedPaymentMethod>,
+    ) -> Result<secure::Response<Self::SecuredPaymentMethod>, Error> {
+        let span = self.span("secure_payment_method");
+        let started = Instant::now();
+        let result = self.inner.secure(request).instrument(span.clone()).await;
+        match result {
+            Ok(secure::Response::RequiresAction(_)) => {
+                span.record("status", "RequiresAction");
+            }
+            Ok(secure::Response::ExemptionRejected(_)) => {
+                span.record("status", "ExemptionRejected");
+            }
+            Ok(secure::Response::Declined(_)) => {
+                span.record("status", "Declined");
+            }
+            _ => {}
+        }
+        record_outcome(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ImmediatePayments for Traced<G>
+where
+    G: ImmediatePayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn charge(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("charge");
+        record_payment(&span, &payment);
+        let started = Instant::now();
+        let result = self
+            .inner
+            .charge(
+                payment,
+                installments,
+                merchant_initiated_type,
+                stored_credential_usage,
+            )
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> DeferredPayments for Traced<G>
+where
+    G: DeferredPayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    type AuthorizationChanges = G::AuthorizationChanges;
+    type CapturedAmount = G::CapturedAmount;
+    type CapturedDistribution = G::CapturedDistribution;
+
+    #[allow(private_interfaces)]
+    async fn authorize(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("authorize");
+        record_payment(&span, &payment);
+        let started = Instant::now();
+        let result = DeferredPayments::authorize(
+            &self.inner,
+            payment,
+            installments,
+            merchant_initiated_type,
+            stored_credential_usage,
+        )
+        .instrument(span.clone())
+        .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+
+    async fn capture(
+        &self,
+        transaction_id: TransactionId,
+        captured_amount: Self::CapturedAmount,
+        captured_distribution: Self::CapturedDistribution,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("capture");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .capture(transaction_id, captured_amount, captured_distribution)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ExternalPayments for Traced<G>
+where
+    G: ExternalPayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: ExternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn initiate(&self, payment: Self::Payment) -> Result<ExternalPayment, Error> {
+        let span = self.span("initiate");
+        record_payment(&span, &payment);
+        let started = Instant::now();
+        let result = self.inner.initiate(payment).instrument(span.clone()).await;
+        if let Ok(external_payment) = &result {
+            let transaction = external_payment.transaction();
+            span.record("transaction_id", debug(transaction.transaction_id()));
+            span.record("status", display(transaction.status()));
+        }
+        record_outcome(&span, started, &result);
+        result
+    }
+
+    async fn payment_data(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<ExternalPaymentData, Error> {
+        let span = self.span("payment_data");
+        span.record("transaction_id", debug(&transaction_id));
+        let started = Instant::now();
+        let result = self
+            .inner
+            .payment_data(transaction_id)
+            .instrument(span.clone())
+            .await;
+        record_outcome(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+impl<G: CheckTransaction> CheckTransaction for Traced<G> {
+    async fn status(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        let span = self.span("status");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .status(transaction_id)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+impl<G: CancelPayments> CancelPayments for Traced<G> {
+    async fn void(&self, transaction_id: TransactionId) -> Result<Transaction, Error> {
+        let span = self.span("void");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .void(transaction_id)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G: RefundPayments> RefundPayments for Traced<G> {
+    type RefundAmount = G::RefundAmount;
+    type RefundDistribution = G::RefundDistribution;
+
+    async fn refund(
+        &self,
+        transaction_id: TransactionId,
+        refund_amount: Self::RefundAmount,
+        refund_distribution: Self::RefundDistribution,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("refund");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .refund(transaction_id, refund_amount, refund_distribution)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> ReversePayment for Traced<G>
+where
+    G: ReversePayment,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    async fn reverse(
+        &self,
+        transaction_id: TransactionId,
+        reason: Option<ReversalReason>,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("reverse");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .reverse(transaction_id, reason)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> EditAuthorization for Traced<G>
+where
+    G: EditAuthorization + DeferredPayments<AuthorizationChanges = ChangesByTotal>,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    async fn edit_authorization(
+        &self,
+        transaction_id: TransactionId,
+        new_amount: Decimal,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("edit_authorization");
+        span.record("amount", display(new_amount));
+        let started = Instant::now();
+        let result = self
+            .inner
+            .edit_authorization(transaction_id, new_amount)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> AdjustAuthorization for Traced<G>
+where
+    G: AdjustAuthorization + DeferredPayments<AuthorizationChanges = ChangesByDelta>,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    async fn increment_authorization(
+        &self,
+        transaction_id: TransactionId,
+        additional_amount: Decimal,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("increment_authorization");
+        span.record("amount", display(additional_amount));
+        let started = Instant::now();
+        let result = self
+            .inner
+            .increment_authorization(transaction_id, additional_amount)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+
+    async fn decrement_authorization(
+        &self,
+        transaction_id: TransactionId,
+        amount_to_release: Decimal,
+    ) -> Result<Transaction, Error> {
+        let span = self.span("decrement_authorization");
+        span.record("amount", display(amount_to_release));
+        let started = Instant::now();
+        let result = self
+            .inner
+            .decrement_authorization(transaction_id, amount_to_release)
+            .instrument(span.clone())
+            .await;
+        record_transaction(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G> RecurrentPayments for Traced<G>
+where
+    G: RecurrentPayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    #[allow(private_interfaces)]
+    async fn create_subscription(
+        &self,
+        payment: Self::Payment,
+        terms: SubscriptionTerms,
+    ) -> Result<Subscription, Error> {
+        let span = self.span("create_subscription");
+        record_payment(&span, &payment);
+        let started = Instant::now();
+        let result = self
+            .inner
+            .create_subscription(payment, terms)
+            .instrument(span.clone())
+            .await;
+        record_subscription(&span, started, &result);
+        result
+    }
+
+    async fn cancel_subscription(&self, subscription_id: SubscriptionId) -> Result<(), Error> {
+        let span = self.span("cancel_subscription");
+        span.record("subscription_id", debug(&subscription_id));
+        let started = Instant::now();
+        let result = self
+            .inner
+            .cancel_subscription(subscription_id)
+            .instrument(span.clone())
+            .await;
+        record_outcome(&span, started, &result);
+        result
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: SubscriptionId,
+    ) -> Result<Subscription, Error> {
+        let span = self.span("get_subscription");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .get_subscription(subscription_id)
+            .instrument(span.clone())
+            .await;
+        record_subscription(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds, private_interfaces)]
+impl<G> StoreCredentials for Traced<G>
+where
+    G: StoreCredentials,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: VaultPaymentMethod,
+{
+    type StoredPaymentMethod = G::StoredPaymentMethod;
+
+    async fn store(
+        &self,
+        payment_method: Self::StoredPaymentMethod,
+    ) -> Result<<<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod, Error> {
+        let span = self.span("store");
+        let started = Instant::now();
+        let result = self
+            .inner
+            .store(payment_method)
+            .instrument(span.clone())
+            .await;
+        record_outcome(&span, started, &result);
+        result
+    }
+
+    async fn unstore(
+        &self,
+        token: <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod,
+    ) -> Result<(), Error> {
+        let span = self.span("unstore");
+        let started = Instant::now();
+        let result = self.inner.unstore(token).instrument(span.clone()).await;
+        record_outcome(&span, started, &result);
+        result
+    }
+}
+
+#[async_trait]
+impl<G: RecoverTransactions> RecoverTransactions for Traced<G> {
+    type Iterator = G::Iterator;
+
+    async fn transactions(&self, idempotence_key: TransactionIdempotenceKey) -> Self::Iterator {
+        let span = self.span("transactions");
+        span.record("idempotence_key", idempotence_key.as_ref() as &str);
+        let started = Instant::now();
+        let iterator = self
+            .inner
+            .transactions(idempotence_key)
+            .instrument(span.clone())
+            .await;
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+        iterator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionStatus;
+    use crate::test_support::{self, MockGateway};
+    use crate::types::NoInstallments;
+    use futures::executor::block_on;
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    type Fields = HashMap<&'static str, String>;
+
+    /// Subscriber collecting the fields of all spans.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Fields>>>);
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let mut spans = self.0.lock().unwrap();
+            let mut fields = Fields::new();
+            attributes.record(&mut Visitor(&mut fields));
+            spans.push(fields);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            let fields = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut Visitor(fields));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn trace<T>(call: impl FnOnce() -> T) -> Vec<Fields> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), call);
+        recorder.0.lock().unwrap().clone()
+    }
+
+    fn gateway(status: Option<TransactionStatus>) -> Traced<MockGateway> {
+        Traced::new(
+            "mock",
+            MockGateway {
+                status,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn records_charge() {
+        let spans = trace(|| {
+            let gateway = gateway(Some(TransactionStatus::Captured));
+            block_on(gateway.charge(test_support::payment(), NoInstallments, None, None)).unwrap();
+        });
+
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert_eq!(span["gateway"], "mock");
+        assert_eq!(span["operation"], "charge");
+        assert_eq!(span["idempotence_key"], test_support::IDEMPOTENCE_KEY);
+        assert_eq!(span["currency"], "EUR");
+        assert_eq!(span["amount"], "19.99");
+        assert_eq!(span["status"], "Captured");
+        assert_eq!(span["transaction_id"], r#"TransactionId("T***8")"#);
+        assert!(span.contains_key("latency_ms"));
+        assert!(!span.contains_key("error"));
+    }
+
+    #[test]
+    fn records_error() {
+        let spans = trace(|| {
+            let gateway = gateway(None);
+            let result = block_on(gateway.status(test_support::transaction_id()));
+            assert!(result.is_err());
+        });
+
+        let span = &spans[0];
+        assert_eq!(span["operation"], "status");
+        assert_eq!(span["error"], "Operation not supported: mock failure");
+        assert!(!span.contains_key("status"));
+        assert!(span.contains_key("latency_ms"));
+    }
+
+    #[test]
+    fn does_not_leak_sensitive_data() {
+        let spans = trace(|| {
+            let gateway = gateway(Some(TransactionStatus::Captured));
+            block_on(gateway.charge(test_support::payment(), NoInstallments, None, None)).unwrap();
+            block_on(gateway.status(test_support::transaction_id())).unwrap();
+        });
+
+        for span in spans {
+            for value in span.values() {
+                assert!(
+                    !value.contains(test_support::TRANSACTION_ID),
+                    "leaked {value}"
+                );
+                assert!(!value.contains(test_support::PAN), "leaked {value}");
+            }
+        }
+    }
+}
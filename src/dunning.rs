@@ -0,0 +1,690 @@
+//! Recovery of failed subscription renewals (dunning).
+//!
+//! When a renewal fails, the subscription becomes `PastDue`, and the [`DunningPolicy`]
+//! schedules retries depending on the canonical [`DeclineReason`]: issuer outages are retried
+//! within hours, insufficient funds within days, and hard declines are not retried at all.
+//! Declines fixed by a reissued card (like an expired card) wait for the account updater
+//! to refresh the stored credential. When no retries are left, or the grace period
+//! is over, the subscription is paused or canceled.
+//!
+//! The policy is a pure state machine: it takes the [`DunningState`] persisted by the application,
+//! and returns the [`DunningOutcome`] with the updated state, the subscription status, and the
+//! [`DunningEvent`]s to notify the customer or update the records.
+//!
+//! For gateways that don't manage subscriptions natively, the [`Dunning`] engine
+//! charges the stored credential via [`ImmediatePayments::charge`] as a recurring
+//! merchant-initiated transaction, and applies the policy to the result:
+//!
+//! ```skip
+//! let dunning = Dunning::new(&gateway, &policy, &SystemClock);
+//!
+//! // Renewal on the billing date
+//! let outcome = dunning.renew(renewal_payment(), NoInstallments, initial_network_transaction_id(), None).await?;
+//!
+//! // Retry when due
+//! if let Some(state) = outcome.state().filter(|state| state.is_retry_due(SystemClock.now())) {
+//!     let outcome = dunning.renew(renewal_payment(), NoInstallments, initial_network_transaction_id(), Some(*state)).await?;
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::clock::Clock;
+use crate::flows::ImmediatePayments;
+use crate::types::payments::PaymentMarker;
+use crate::types::{
+    InternalPaymentMethod, NetworkTransactionId, StoredCredentialUsage, Transaction,
+};
+use crate::{
+    DeclineReason, Error, Gateway, MerchantInitiatedType, SubscriptionStatus, TransactionStatus,
+};
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+/// What happens to the subscription when the dunning is exhausted.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DunningAction {
+    /// Pause the subscription until the customer updates the payment method.
+    Pause,
+    /// Cancel the subscription.
+    Cancel,
+}
+
+impl DunningAction {
+    /// The status of the subscription after the action.
+    #[inline]
+    pub fn status(&self) -> SubscriptionStatus {
+        match self {
+            Self::Pause => SubscriptionStatus::Paused,
+            Self::Cancel => SubscriptionStatus::Canceled,
+        }
+    }
+}
+
+/// Rules of retrying failed renewals.
+///
+/// Retry delays are counted from the first failed attempt of the renewal.
+/// After every failure, the next retry is the first delay of the latest decline reason
+/// following the last attempt. Retries after the grace period are dropped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DunningPolicy {
+    /// Retry delays by the decline reason (an empty list disables retries).
+    pub retries: HashMap<DeclineReason, Vec<Duration>>,
+    /// Retry delays of soft declines and failures without a reason missing in the `retries`.
+    pub default_retries: Vec<Duration>,
+    /// The time since the first failed attempt the subscription stays `PastDue`.
+    pub grace_period: Duration,
+    /// What happens to the subscription when the dunning is exhausted.
+    pub on_exhaustion: DunningAction,
+}
+
+impl Default for DunningPolicy {
+    /// Retries soft declines after 1, 3, 5 and 7 days, issuer outages after 1 and 6 hours,
+    /// 1 and 3 days, and insufficient funds after 3, 5 and 7 days (around paydays).
+    /// Declines requiring authentication are not retried, as the customer must be present.
+    /// Cancels the subscription after 14 days.
+    fn default() -> Self {
+        let days = |days: &[u64]| days.iter().map(|n| Duration::from_secs(n * DAY)).collect();
+        let hours = |hours: &[u64]| {
+            hours
+                .iter()
+                .map(|n| Duration::from_secs(n * HOUR))
+                .collect()
+        };
+        Self {
+            retries: HashMap::from([
+                (DeclineReason::InsufficientFunds, days(&[3, 5, 7])),
+                (DeclineReason::IssuerUnavailable, hours(&[1, 6, 24, 72])),
+                (DeclineReason::AuthenticationRequired, Vec::new()),
+            ]),
+            default_retries: days(&[1, 3, 5, 7]),
+            grace_period: Duration::from_secs(14 * DAY),
+            on_exhaustion: DunningAction::Cancel,
+        }
+    }
+}
+
+/// The progress of the dunning of a renewal, persisted by the application between attempts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DunningState {
+    /// The moment of the first failed attempt (Unix timestamp).
+    pub failed_at: i64,
+    /// The moment of the last attempt (Unix timestamp).
+    pub last_attempt_at: i64,
+    /// The number of failed attempts (including the first one).
+    pub attempts: u32,
+    /// The reason of the last decline (None for failures without a reason).
+    pub decline_reason: Option<DeclineReason>,
+    /// The moment of the next retry (None while waiting for the credential update).
+    pub next_retry_at: Option<i64>,
+}
+
+impl DunningState {
+    /// Whether the retry is due at the moment (Unix timestamp).
+    #[inline]
+    pub fn is_retry_due(&self, now: i64) -> bool {
+        self.next_retry_at.is_some_and(|at| at <= now)
+    }
+}
+
+/// The notification about the dunning progress.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DunningEvent {
+    /// The renewal (or its retry) failed.
+    PaymentFailed {
+        /// The number of the failed attempt (1 for the renewal itself).
+        attempt: u32,
+        /// The canonical reason of the decline (None for failures without a reason).
+        decline_reason: Option<DeclineReason>,
+    },
+    /// The next retry is scheduled.
+    RetryScheduled {
+        /// The moment of the retry (Unix timestamp).
+        at: i64,
+    },
+    /// The retry waits for the customer or the account updater to refresh the stored credential.
+    CredentialUpdateRequired,
+    /// The stored credential was refreshed by the account updater.
+    CredentialUpdated,
+    /// The payment succeeded after the number of failed attempts.
+    Recovered {
+        /// The number of failed attempts before the success.
+        attempts: u32,
+    },
+    /// No retries are left, and the subscription is paused or canceled.
+    Exhausted {
+        /// The action applied to the subscription.
+        action: DunningAction,
+    },
+}
+
+/// The result of a dunning step.
+#[derive(Clone, Debug)]
+pub struct DunningOutcome {
+    status: SubscriptionStatus,
+    state: Option<DunningState>,
+    events: Vec<DunningEvent>,
+    transaction: Option<Transaction>,
+}
+
+impl DunningOutcome {
+    /// The status of the subscription after the step.
+    #[inline]
+    pub fn status(&self) -> SubscriptionStatus {
+        self.status
+    }
+
+    /// The state to persist until the next step (None when the dunning is over).
+    #[inline]
+    pub fn state(&self) -> Option<&DunningState> {
+        self.state.as_ref()
+    }
+
+    /// The events of the step, in order.
+    #[inline]
+    pub fn events(&self) -> &[DunningEvent] {
+        &self.events
+    }
+
+    /// The transaction charged by the [`Dunning`] engine.
+    #[inline]
+    pub fn transaction(&self) -> Option<&Transaction> {
+        self.transaction.as_ref()
+    }
+
+    /// Whether the charged transaction awaits settlement (pending or processing),
+    /// so that the state and the status are unchanged until its final status is known.
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.transaction
+            .as_ref()
+            .is_some_and(|transaction| !transaction.status().is_final())
+    }
+
+    fn new(status: SubscriptionStatus, state: Option<DunningState>) -> Self {
+        Self {
+            status,
+            state,
+            events: Vec::new(),
+            transaction: None,
+        }
+    }
+}
+
+impl DunningPolicy {
+    /// Apply the failed attempt at the moment (Unix timestamp) to the state
+    /// (None for the failure of the renewal itself).
+    pub fn on_failure(
+        &self,
+        state: Option<DunningState>,
+        decline_reason: Option<DeclineReason>,
+        now: i64,
+    ) -> DunningOutcome {
+        let state = DunningState {
+            failed_at: state.map_or(now, |state| state.failed_at),
+            last_attempt_at: now,
+            attempts: state.map_or(1, |state| state.attempts + 1),
+            decline_reason,
+            next_retry_at: None,
+        };
+        let failed = DunningEvent::PaymentFailed {
+            attempt: state.attempts,
+            decline_reason,
+        };
+
+        if let Some(at) = self.next_retry(&state) {
+            let state = DunningState {
+                next_retry_at: Some(at),
+                ..state
+            };
+            let mut outcome = DunningOutcome::new(SubscriptionStatus::PastDue, Some(state));
+            outcome.events = vec![failed, DunningEvent::RetryScheduled { at }];
+            return outcome;
+        }
+
+        if decline_reason.is_some_and(fixed_by_update) && !self.is_over(&state, now) {
+            let mut outcome = DunningOutcome::new(SubscriptionStatus::PastDue, Some(state));
+            outcome.events = vec![failed, DunningEvent::CredentialUpdateRequired];
+            return outcome;
+        }
+
+        let mut outcome = self.exhausted();
+        outcome.events.insert(0, failed);
+        outcome
+    }
+
+    /// Apply the successful attempt to the state (None for the successful renewal itself).
+    pub fn on_success(&self, state: Option<DunningState>) -> DunningOutcome {
+        let mut outcome = DunningOutcome::new(SubscriptionStatus::Active, None);
+        if let Some(state) = state {
+            outcome.events = vec![DunningEvent::Recovered {
+                attempts: state.attempts,
+            }];
+        }
+        outcome
+    }
+
+    /// Apply the refresh of the stored credential (by the account updater or the customer)
+    /// at the moment (Unix timestamp): the retry becomes due immediately within the grace period.
+    pub fn on_credential_updated(&self, state: DunningState, now: i64) -> DunningOutcome {
+        if self.is_over(&state, now) {
+            return self.exhausted();
+        }
+
+        let state = DunningState {
+            next_retry_at: Some(now),
+            ..state
+        };
+        let mut outcome = DunningOutcome::new(SubscriptionStatus::PastDue, Some(state));
+        outcome.events = vec![
+            DunningEvent::CredentialUpdated,
+            DunningEvent::RetryScheduled { at: now },
+        ];
+        outcome
+    }
+
+    /// Pause the billing when the stored credential can't be charged anymore
+    /// (the account is closed, or the cardholder has to be contacted),
+    /// until the customer provides a new payment method.
+    pub fn on_customer_required(&self) -> DunningOutcome {
+        let action = DunningAction::Pause;
+        let mut outcome = DunningOutcome::new(action.status(), None);
+        outcome.events = vec![
+            DunningEvent::CredentialUpdateRequired,
+            DunningEvent::Exhausted { action },
+        ];
+        outcome
+    }
+
+    /// Apply the final status of the renewal transaction (or its retry) to the state
+    /// at the moment (Unix timestamp), or None while the transaction is pending.
+    ///
+    /// Voided and refunded renewals weren't collected, and count as failures.
+    pub fn on_settled(
+        &self,
+        state: Option<DunningState>,
+        transaction: &Transaction,
+        now: i64,
+    ) -> Option<DunningOutcome> {
+        match transaction.status() {
+            TransactionStatus::Captured | TransactionStatus::Authorized => {
+                Some(self.on_success(state))
+            }
+            TransactionStatus::Declined
+            | TransactionStatus::Failed
+            | TransactionStatus::Voided
+            | TransactionStatus::Refunded => {
+                Some(self.on_failure(state, transaction.decline_reason(), now))
+            }
+            TransactionStatus::Pending | TransactionStatus::Processing => None,
+        }
+    }
+
+    /// Exhaust the dunning waiting for the credential update after the grace period
+    /// (None while the dunning is in progress).
+    pub fn on_timeout(&self, state: DunningState, now: i64) -> Option<DunningOutcome> {
+        (state.next_retry_at.is_none() && self.is_over(&state, now)).then(|| self.exhausted())
+    }
+
+    /// The first retry after the last attempt within the grace period.
+    fn next_retry(&self, state: &DunningState) -> Option<i64> {
+        let delays = match state.decline_reason {
+            Some(reason) => match self.retries.get(&reason) {
+                Some(delays) => delays,
+                None if reason.is_soft() => &self.default_retries,
+                None => return None,
+            },
+            None => &self.default_retries,
+        };
+        delays
+            .iter()
+            .filter(|delay| **delay <= self.grace_period)
+            .map(|delay| state.failed_at.saturating_add(seconds(*delay)))
+            .find(|at| *at > state.last_attempt_at)
+    }
+
+    fn is_over(&self, state: &DunningState, now: i64) -> bool {
+        now >= state.failed_at.saturating_add(seconds(self.grace_period))
+    }
+
+    fn exhausted(&self) -> DunningOutcome {
+        let action = self.on_exhaustion;
+        let mut outcome = DunningOutcome::new(action.status(), None);
+        outcome.events = vec![DunningEvent::Exhausted { action }];
+        outcome
+    }
+}
+
+/// Dunning engine charging renewals for gateways without native subscriptions.
+pub struct Dunning<'a, G> {
+    gateway: &'a G,
+    policy: &'a DunningPolicy,
+    clock: &'a dyn Clock,
+}
+
+#[allow(private_bounds)]
+impl<'a, G> Dunning<'a, G>
+where
+    G: ImmediatePayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+{
+    /// Build the engine for the gateway.
+    pub fn new(gateway: &'a G, policy: &'a DunningPolicy, clock: &'a dyn Clock) -> Self {
+        Self {
+            gateway,
+            policy,
+            clock,
+        }
+    }
+
+    /// Charge the renewal (or its retry for the state) with the stored credential
+    /// of the initial customer-initiated transaction.
+    ///
+    /// Declined, failed, voided and refunded transactions advance the dunning.
+    /// Pending transactions keep the state unchanged (see [`DunningOutcome::is_pending`]):
+    /// check their status later, and apply it with [`DunningPolicy::on_settled`]
+    /// instead of retrying. Errors of the gateway are returned as is, without counting the attempt.
+    #[allow(private_interfaces)]
+    pub async fn renew(
+        &self,
+        payment: <G as Gateway>::Payment,
+        installments: <G as Gateway>::Installments,
+        initial_network_transaction_id: NetworkTransactionId,
+        state: Option<DunningState>,
+    ) -> Result<DunningOutcome, Error> {
+        let usage = StoredCredentialUsage::Subsequent(initial_network_transaction_id);
+        let transaction = self
+            .gateway
+            .charge(
+                payment,
+                installments,
+                Some(MerchantInitiatedType::Recurring),
+                Some(usage),
+            )
+            .await?;
+
+        let mut outcome = self
+            .policy
+            .on_settled(state, &transaction, self.clock.now())
+            .unwrap_or_else(|| {
+                let status = match state {
+                    Some(_) => SubscriptionStatus::PastDue,
+                    None => SubscriptionStatus::Active,
+                };
+                DunningOutcome::new(status, state)
+            });
+        outcome.transaction = Some(transaction);
+        Ok(outcome)
+    }
+}
+
+/// Whether the decline is fixed by a reissued card (refreshed by the account updater).
+fn fixed_by_update(reason: DeclineReason) -> bool {
+    matches!(
+        reason,
+        DeclineReason::ExpiredCard
+            | DeclineReason::InvalidAccount
+            | DeclineReason::LostOrStolen
+            | DeclineReason::AccountClosed
+    )
+}
+
+fn seconds(duration: Duration) -> i64 {
+    i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::test_support::{MockGateway, network_transaction_id, payment};
+    use crate::types::NoInstallments;
+    use futures::executor::block_on;
+
+    const NOW: i64 = 1_748_736_000;
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn schedules_retries_of_soft_declines() {
+        let policy = DunningPolicy::default();
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::DoNotHonor), NOW);
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        assert_eq!(
+            outcome.events(),
+            [
+                DunningEvent::PaymentFailed {
+                    attempt: 1,
+                    decline_reason: Some(DeclineReason::DoNotHonor)
+                },
+                DunningEvent::RetryScheduled { at: NOW + DAY }
+            ]
+        );
+
+        let state = *outcome.state().unwrap();
+        let outcome = policy.on_failure(Some(state), Some(DeclineReason::DoNotHonor), NOW + DAY);
+        let state = outcome.state().unwrap();
+        assert_eq!(state.attempts, 2);
+        assert_eq!(state.failed_at, NOW);
+        assert_eq!(state.next_retry_at, Some(NOW + 3 * DAY));
+    }
+
+    #[test]
+    fn follows_latest_decline_reason() {
+        let policy = DunningPolicy::default();
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::IssuerUnavailable), NOW);
+        let state = *outcome.state().unwrap();
+        assert_eq!(state.next_retry_at, Some(NOW + 3600));
+
+        let outcome = policy.on_failure(
+            Some(state),
+            Some(DeclineReason::InsufficientFunds),
+            NOW + 3600,
+        );
+        assert_eq!(outcome.state().unwrap().next_retry_at, Some(NOW + 3 * DAY));
+    }
+
+    #[test]
+    fn exhausts_after_last_retry() {
+        let policy = DunningPolicy {
+            on_exhaustion: DunningAction::Pause,
+            ..Default::default()
+        };
+
+        let mut state = None;
+        for _ in 0..3 {
+            let now = state.map_or(NOW, |state: DunningState| state.next_retry_at.unwrap());
+            let outcome = policy.on_failure(state, Some(DeclineReason::InsufficientFunds), now);
+            state = Some(*outcome.state().unwrap());
+        }
+
+        let outcome =
+            policy.on_failure(state, Some(DeclineReason::InsufficientFunds), NOW + 7 * DAY);
+        assert_eq!(outcome.status(), SubscriptionStatus::Paused);
+        assert!(outcome.state().is_none());
+        assert_eq!(
+            outcome.events()[1],
+            DunningEvent::Exhausted {
+                action: DunningAction::Pause
+            }
+        );
+    }
+
+    #[test]
+    fn does_not_retry_hard_declines() {
+        let policy = DunningPolicy::default();
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::SuspectedFraud), NOW);
+        assert_eq!(outcome.status(), SubscriptionStatus::Canceled);
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::AuthenticationRequired), NOW);
+        assert_eq!(outcome.status(), SubscriptionStatus::Canceled);
+    }
+
+    #[test]
+    fn drops_retries_after_grace_period() {
+        let policy = DunningPolicy {
+            grace_period: Duration::from_secs(4 * DAY as u64),
+            ..Default::default()
+        };
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::DoNotHonor), NOW);
+        let state = *outcome.state().unwrap();
+        let outcome = policy.on_failure(Some(state), Some(DeclineReason::DoNotHonor), NOW + DAY);
+        let state = *outcome.state().unwrap();
+        assert_eq!(state.next_retry_at, Some(NOW + 3 * DAY));
+
+        let outcome =
+            policy.on_failure(Some(state), Some(DeclineReason::DoNotHonor), NOW + 3 * DAY);
+        assert_eq!(outcome.status(), SubscriptionStatus::Canceled);
+    }
+
+    #[test]
+    fn waits_for_credential_update() {
+        let policy = DunningPolicy::default();
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::ExpiredCard), NOW);
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        assert_eq!(outcome.events()[1], DunningEvent::CredentialUpdateRequired);
+        let state = *outcome.state().unwrap();
+        assert!(!state.is_retry_due(NOW + DAY));
+        assert!(policy.on_timeout(state, NOW + DAY).is_none());
+
+        let outcome = policy.on_credential_updated(state, NOW + 2 * DAY);
+        assert_eq!(
+            outcome.events(),
+            [
+                DunningEvent::CredentialUpdated,
+                DunningEvent::RetryScheduled { at: NOW + 2 * DAY }
+            ]
+        );
+        assert!(outcome.state().unwrap().is_retry_due(NOW + 2 * DAY));
+    }
+
+    #[test]
+    fn exhausts_without_credential_update() {
+        let policy = DunningPolicy::default();
+
+        let outcome = policy.on_failure(None, Some(DeclineReason::ExpiredCard), NOW);
+        let state = *outcome.state().unwrap();
+
+        let outcome = policy.on_timeout(state, NOW + 14 * DAY).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Canceled);
+
+        let outcome = policy.on_credential_updated(state, NOW + 15 * DAY);
+        assert_eq!(outcome.status(), SubscriptionStatus::Canceled);
+    }
+
+    #[test]
+    fn renews_declined_charge() {
+        let gateway = MockGateway {
+            status: Some(TransactionStatus::Declined),
+            decline_reason: Some(DeclineReason::InsufficientFunds),
+            ..Default::default()
+        };
+        let policy = DunningPolicy::default();
+        let dunning = Dunning::new(&gateway, &policy, &FixedClock(NOW));
+
+        let outcome =
+            block_on(dunning.renew(payment(), NoInstallments, network_transaction_id(), None))
+                .unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        assert_eq!(outcome.state().unwrap().next_retry_at, Some(NOW + 3 * DAY));
+        assert_eq!(
+            outcome.transaction().unwrap().status(),
+            &TransactionStatus::Declined
+        );
+    }
+
+    #[test]
+    fn fails_voided_and_refunded_charges() {
+        for status in [TransactionStatus::Voided, TransactionStatus::Refunded] {
+            let gateway = MockGateway {
+                status: Some(status),
+                ..Default::default()
+            };
+            let policy = DunningPolicy::default();
+            let dunning = Dunning::new(&gateway, &policy, &FixedClock(NOW));
+
+            let outcome =
+                block_on(dunning.renew(payment(), NoInstallments, network_transaction_id(), None))
+                    .unwrap();
+            assert_eq!(outcome.status(), SubscriptionStatus::PastDue, "{status}");
+            assert!(outcome.state().is_some(), "{status}");
+        }
+    }
+
+    #[test]
+    fn recovers_on_successful_retry() {
+        let gateway = MockGateway {
+            status: Some(TransactionStatus::Captured),
+            ..Default::default()
+        };
+        let policy = DunningPolicy::default();
+        let dunning = Dunning::new(&gateway, &policy, &FixedClock(NOW + DAY));
+        let state = *policy
+            .on_failure(None, Some(DeclineReason::DoNotHonor), NOW)
+            .state()
+            .unwrap();
+
+        let outcome = block_on(dunning.renew(
+            payment(),
+            NoInstallments,
+            network_transaction_id(),
+            Some(state),
+        ))
+        .unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+        assert!(outcome.state().is_none());
+        assert_eq!(outcome.events(), [DunningEvent::Recovered { attempts: 1 }]);
+    }
+
+    #[test]
+    fn keeps_state_of_pending_charge() {
+        let gateway = MockGateway {
+            status: Some(TransactionStatus::Pending),
+            ..Default::default()
+        };
+        let policy = DunningPolicy::default();
+        let dunning = Dunning::new(&gateway, &policy, &FixedClock(NOW));
+
+        let outcome =
+            block_on(dunning.renew(payment(), NoInstallments, network_transaction_id(), None))
+                .unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+        assert!(outcome.is_pending());
+        assert!(outcome.events().is_empty());
+    }
+
+    #[test]
+    fn applies_settled_status() {
+        let policy = DunningPolicy::default();
+        let transaction = |status| {
+            MockGateway {
+                status: Some(status),
+                decline_reason: (status == TransactionStatus::Declined)
+                    .then_some(DeclineReason::InsufficientFunds),
+                ..Default::default()
+            }
+            .respond()
+            .unwrap()
+        };
+
+        let pending = transaction(TransactionStatus::Processing);
+        assert!(policy.on_settled(None, &pending, NOW).is_none());
+
+        let declined = transaction(TransactionStatus::Declined);
+        let outcome = policy.on_settled(None, &declined, NOW).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+
+        let captured = transaction(TransactionStatus::Captured);
+        let outcome = policy
+            .on_settled(outcome.state().copied(), &captured, NOW + DAY)
+            .unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+        assert_eq!(outcome.events(), [DunningEvent::Recovered { attempts: 1 }]);
+    }
+}
@@ -2,18 +2,42 @@
 
 mod account_holder_type;
 mod account_type;
+mod card_network;
 mod color_depth;
+mod decline_reason;
 mod eci;
+mod exemption_outcome;
+mod mandate_sequence;
+mod mandate_status;
 mod merchant_initiated_type;
+mod proration_kind;
+mod proration_policy;
+mod sca_exemption;
+mod sdk_ui_type;
 mod subscription_status;
 mod three_ds_version;
+mod three_ri_indicator;
 mod transaction_status;
+mod wallet;
+mod weekday;
 
 pub use account_holder_type::AccountHolderType;
 pub use account_type::AccountType;
+pub use card_network::CardNetwork;
 pub use color_depth::ColorDepth;
+pub use decline_reason::DeclineReason;
 pub use eci::ECI;
+pub use exemption_outcome::ExemptionOutcome;
+pub use mandate_sequence::MandateSequence;
+pub use mandate_status::MandateStatus;
 pub use merchant_initiated_type::MerchantInitiatedType;
+pub use proration_kind::ProrationKind;
+pub use proration_policy::ProrationPolicy;
+pub use sca_exemption::ScaExemption;
+pub use sdk_ui_type::SdkUiType;
 pub use subscription_status::SubscriptionStatus;
 pub use three_ds_version::ThreeDSVersion;
+pub use three_ri_indicator::ThreeRIIndicator;
 pub use transaction_status::TransactionStatus;
+pub use wallet::Wallet;
+pub use weekday::Weekday;
@@ -0,0 +1,182 @@
+use std::ops::RangeInclusive;
+use strum_macros::{AsRefStr, Display};
+
+/// Card network (scheme) of a payment card
+///
+/// The network is detected by the IIN (issuer identification number) prefix
+/// of the card number. Prefix ranges of the major networks are stable,
+/// so the detection doesn't require a BIN database.
+///
+/// # Data Protection
+/// This is a public value, neither secret nor even PII.
+/// Card networks are standardized classifiers requiring no security protection.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum CardNetwork {
+    /// Visa
+    Visa,
+    /// Mastercard
+    Mastercard,
+    /// American Express
+    Amex,
+    /// Discover
+    Discover,
+    /// JCB
+    Jcb,
+    /// UnionPay
+    UnionPay,
+    /// Diners Club International
+    DinersClub,
+    /// Maestro (debit network of Mastercard)
+    Maestro,
+    /// Elo (Brazil)
+    Elo,
+    /// RuPay (India)
+    RuPay,
+    /// Mir (Russia)
+    Mir,
+}
+
+/// IIN ranges as (network, first prefix, last prefix), compared by the first digits
+/// of the card number (as many as the prefixes have).
+///
+/// Ranges of local networks overlapping with global ones (Elo, RuPay, Mir)
+/// go first, so that the more specific range wins.
+const IIN_RANGES: &[(CardNetwork, u32, u32)] = &[
+    (CardNetwork::Elo, 401178, 401179),
+    (CardNetwork::Elo, 431274, 431274),
+    (CardNetwork::Elo, 438935, 438935),
+    (CardNetwork::Elo, 451416, 451416),
+    (CardNetwork::Elo, 457393, 457393),
+    (CardNetwork::Elo, 457631, 457632),
+    (CardNetwork::Elo, 504175, 504175),
+    (CardNetwork::Elo, 506699, 506778),
+    (CardNetwork::Elo, 509000, 509999),
+    (CardNetwork::Elo, 627780, 627780),
+    (CardNetwork::Elo, 636297, 636297),
+    (CardNetwork::Elo, 636368, 636368),
+    (CardNetwork::Elo, 650031, 650033),
+    (CardNetwork::Elo, 650035, 650051),
+    (CardNetwork::Elo, 650405, 650439),
+    (CardNetwork::Elo, 650485, 650538),
+    (CardNetwork::Elo, 650541, 650598),
+    (CardNetwork::Elo, 650700, 650718),
+    (CardNetwork::Elo, 650720, 650727),
+    (CardNetwork::Elo, 650901, 650978),
+    (CardNetwork::Elo, 651652, 651679),
+    (CardNetwork::Elo, 655000, 655019),
+    (CardNetwork::Elo, 655021, 655058),
+    (CardNetwork::Mir, 2200, 2204),
+    (CardNetwork::RuPay, 508500, 508999),
+    (CardNetwork::RuPay, 606985, 607984),
+    (CardNetwork::RuPay, 608001, 608500),
+    (CardNetwork::RuPay, 652150, 653149),
+    (CardNetwork::Amex, 34, 34),
+    (CardNetwork::Amex, 37, 37),
+    (CardNetwork::DinersClub, 300, 305),
+    (CardNetwork::DinersClub, 3095, 3095),
+    (CardNetwork::DinersClub, 36, 36),
+    (CardNetwork::DinersClub, 38, 39),
+    (CardNetwork::Jcb, 3528, 3589),
+    (CardNetwork::Mastercard, 51, 55),
+    (CardNetwork::Mastercard, 2221, 2720),
+    (CardNetwork::Maestro, 5018, 5018),
+    (CardNetwork::Maestro, 5020, 5020),
+    (CardNetwork::Maestro, 5038, 5038),
+    (CardNetwork::Maestro, 5893, 5893),
+    (CardNetwork::Maestro, 6304, 6304),
+    (CardNetwork::Maestro, 6759, 6759),
+    (CardNetwork::Maestro, 6761, 6763),
+    (CardNetwork::UnionPay, 62, 62),
+    (CardNetwork::UnionPay, 81, 81),
+    (CardNetwork::Discover, 6011, 6011),
+    (CardNetwork::Discover, 644, 649),
+    (CardNetwork::Discover, 65, 65),
+    (CardNetwork::Visa, 4, 4),
+];
+
+impl CardNetwork {
+    /// Detect the network by the leading digits of the card number.
+    ///
+    /// Returns `None` when the prefix matches no known range
+    /// (or when there are too few digits to decide).
+    pub fn detect(number: &str) -> Option<Self> {
+        IIN_RANGES
+            .iter()
+            .find(|(_, from, to)| {
+                let digits = from.ilog10() as usize + 1;
+                number
+                    .get(..digits)
+                    .filter(|prefix| prefix.bytes().all(|b| b.is_ascii_digit()))
+                    .and_then(|prefix| prefix.parse::<u32>().ok())
+                    .is_some_and(|prefix| (*from..=*to).contains(&prefix))
+            })
+            .map(|(network, _, _)| *network)
+    }
+
+    /// Valid lengths of card numbers issued by the network.
+    pub fn number_lengths(&self) -> RangeInclusive<usize> {
+        match self {
+            Self::Amex => 15..=15,
+            Self::DinersClub => 14..=19,
+            Self::Maestro => 12..=19,
+            Self::Mastercard | Self::Elo | Self::RuPay => 16..=16,
+            Self::Visa => 13..=19,
+            Self::Discover | Self::Jcb | Self::UnionPay | Self::Mir => 16..=19,
+        }
+    }
+
+    /// Length of the card verification value (4 digits of CID for Amex, 3 digits otherwise).
+    pub fn cvv_length(&self) -> usize {
+        match self {
+            Self::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_networks_by_prefix() {
+        for (number, network) in [
+            ("4532015112830366", CardNetwork::Visa),
+            ("5425233430109903", CardNetwork::Mastercard),
+            ("2223000048410010", CardNetwork::Mastercard),
+            ("374245455400126", CardNetwork::Amex),
+            ("6011000990139424", CardNetwork::Discover),
+            ("3530111333300000", CardNetwork::Jcb),
+            ("6200000000000005", CardNetwork::UnionPay),
+            ("36227206271667", CardNetwork::DinersClub),
+            ("6759649826438453", CardNetwork::Maestro),
+            ("5067000000000000", CardNetwork::Elo),
+            ("4011780000000000", CardNetwork::Elo),
+            ("6521500000000000", CardNetwork::RuPay),
+            ("2200000000000000", CardNetwork::Mir),
+        ] {
+            assert_eq!(CardNetwork::detect(number), Some(network), "{number}");
+        }
+    }
+
+    #[test]
+    fn detects_nothing_for_unknown_prefixes() {
+        assert_eq!(CardNetwork::detect("9999999999999999"), None);
+        assert_eq!(CardNetwork::detect("1234567890123"), None);
+        assert_eq!(CardNetwork::detect(""), None);
+    }
+
+    #[test]
+    fn requires_four_digit_cvv_for_amex() {
+        assert_eq!(CardNetwork::Amex.cvv_length(), 4);
+        assert_eq!(CardNetwork::Visa.cvv_length(), 3);
+    }
+
+    #[test]
+    fn defines_number_lengths() {
+        assert!(CardNetwork::Amex.number_lengths().contains(&15));
+        assert!(!CardNetwork::Mastercard.number_lengths().contains(&19));
+    }
+}
@@ -0,0 +1,89 @@
+use strum_macros::{AsRefStr, Display, EnumString};
+
+/// Canonical reason of a declined transaction
+///
+/// Gateway adapters map issuer and gateway-specific response codes
+/// to these reasons, so that client code (retry logic, analytics)
+/// can handle declines consistently across gateways.
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq)]
+pub enum DeclineReason {
+    /// Not enough funds or credit available on the account (soft decline)
+    InsufficientFunds,
+    /// Amount or frequency limit of the account exceeded (soft decline)
+    LimitExceeded,
+    /// Issuer requires Strong Customer Authentication (soft decline, 3DS required)
+    AuthenticationRequired,
+    /// Issuer or network is temporarily unavailable (soft decline)
+    IssuerUnavailable,
+    /// Generic decline without a specific reason (Do Not Honor)
+    DoNotHonor,
+    /// The card or account has expired
+    ExpiredCard,
+    /// The card number or account identifier is invalid
+    InvalidAccount,
+    /// The card verification value is incorrect
+    IncorrectCvv,
+    /// The card is reported lost or stolen (hard decline)
+    LostOrStolen,
+    /// The transaction is suspected to be fraudulent (hard decline)
+    SuspectedFraud,
+    /// The account is closed (hard decline)
+    AccountClosed,
+    /// The transaction is not permitted for the card or the merchant
+    TransactionNotPermitted,
+    /// Any other reason not covered by the canonical categories
+    Other,
+}
+
+impl DeclineReason {
+    /// Whether the decline is temporary, and the transaction may succeed when retried later.
+    pub fn is_soft(&self) -> bool {
+        matches!(
+            self,
+            Self::InsufficientFunds
+                | Self::LimitExceeded
+                | Self::AuthenticationRequired
+                | Self::IssuerUnavailable
+                | Self::DoNotHonor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinguishes_soft_declines() {
+        for reason in [
+            DeclineReason::InsufficientFunds,
+            DeclineReason::LimitExceeded,
+            DeclineReason::AuthenticationRequired,
+            DeclineReason::IssuerUnavailable,
+            DeclineReason::DoNotHonor,
+        ] {
+            assert!(reason.is_soft(), "{reason} should be soft");
+        }
+    }
+
+    #[test]
+    fn distinguishes_hard_declines() {
+        for reason in [
+            DeclineReason::ExpiredCard,
+            DeclineReason::InvalidAccount,
+            DeclineReason::IncorrectCvv,
+            DeclineReason::LostOrStolen,
+            DeclineReason::SuspectedFraud,
+            DeclineReason::AccountClosed,
+            DeclineReason::TransactionNotPermitted,
+            DeclineReason::Other,
+        ] {
+            assert!(!reason.is_soft(), "{reason} should be hard");
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use strum_macros::{AsRefStr, Display};
 
+use crate::Error;
+use crate::enums::CardNetwork;
+
 /// Electronic Commerce Indicator from 3D Secure authentication
 ///
 /// ECI indicates the result of the 3D Secure authentication attempt and is used
@@ -25,3 +28,79 @@ pub enum ECI {
     /// Visa/AMEX/JCB/Discover: 07, Mastercard: 00
     NoAuthentication,
 }
+
+impl ECI {
+    /// Parse the two-digit ECI code returned by a 3DS server for the card network.
+    ///
+    /// Mastercard and Maestro use codes `02`, `01` and `00`,
+    /// other networks use codes `05`, `06` and `07`.
+    pub fn from_code(code: &str, network: CardNetwork) -> Result<Self, Error> {
+        let code = code.trim();
+        [
+            Self::FullyAuthenticated,
+            Self::AttemptedAuth,
+            Self::NoAuthentication,
+        ]
+        .into_iter()
+        .find(|eci| eci.code(network) == code)
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("ECI {code:?} is not valid for {network} cards"))
+        })
+    }
+
+    /// The two-digit ECI code for the card network.
+    pub fn code(&self, network: CardNetwork) -> &'static str {
+        let mastercard = matches!(network, CardNetwork::Mastercard | CardNetwork::Maestro);
+        match (self, mastercard) {
+            (Self::FullyAuthenticated, true) => "02",
+            (Self::AttemptedAuth, true) => "01",
+            (Self::NoAuthentication, true) => "00",
+            (Self::FullyAuthenticated, false) => "05",
+            (Self::AttemptedAuth, false) => "06",
+            (Self::NoAuthentication, false) => "07",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_codes_per_network() {
+        assert_eq!(
+            ECI::from_code("05", CardNetwork::Visa).unwrap(),
+            ECI::FullyAuthenticated
+        );
+        assert_eq!(
+            ECI::from_code("02", CardNetwork::Mastercard).unwrap(),
+            ECI::FullyAuthenticated
+        );
+        assert_eq!(
+            ECI::from_code("06", CardNetwork::Amex).unwrap(),
+            ECI::AttemptedAuth
+        );
+        assert_eq!(
+            ECI::from_code("00", CardNetwork::Maestro).unwrap(),
+            ECI::NoAuthentication
+        );
+    }
+
+    #[test]
+    fn rejects_codes_of_other_networks() {
+        assert!(matches!(
+            ECI::from_code("02", CardNetwork::Visa),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            ECI::from_code("05", CardNetwork::Mastercard),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn formats_codes_per_network() {
+        assert_eq!(ECI::AttemptedAuth.code(CardNetwork::Jcb), "06");
+        assert_eq!(ECI::AttemptedAuth.code(CardNetwork::Mastercard), "01");
+    }
+}
@@ -0,0 +1,20 @@
+use strum_macros::{AsRefStr, Display};
+
+use crate::enums::DeclineReason;
+
+/// Outcome of the requested SCA exemption
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum ExemptionOutcome {
+    /// The exemption was granted, and the payment was secured without a challenge
+    Granted,
+    /// The issuer rejected the exemption with a soft decline,
+    /// and the payment was secured via the challenge instead
+    Rejected(DeclineReason),
+    /// The exemption doesn't apply to the payment (like out-of-scope regions or networks)
+    NotApplicable,
+}
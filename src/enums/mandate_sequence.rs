@@ -0,0 +1,91 @@
+use strum_macros::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+use crate::Error;
+
+/// Sequence type of the direct debit collected under the mandate
+///
+/// SEPA rulebooks required the sequence type (`FRST`, `RCUR`, `OOFF`, `FNAL`)
+/// in every collection, and some banks still reject the collections without it.
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` (the ISO 20022 code) are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, Hash, IntoStaticStr, PartialEq)]
+#[strum(ascii_case_insensitive)]
+pub enum MandateSequence {
+    /// The first collection of the recurrent mandate (`FRST`)
+    #[strum(serialize = "FRST")]
+    First,
+    /// A later collection of the recurrent mandate (`RCUR`)
+    #[strum(serialize = "RCUR")]
+    Recurring,
+    /// The only collection of the one-off mandate (`OOFF`)
+    #[strum(serialize = "OOFF")]
+    OneOff,
+    /// The last collection of the recurrent mandate (`FNAL`)
+    #[strum(serialize = "FNAL")]
+    Final,
+}
+
+impl MandateSequence {
+    /// Parse the ISO 20022 code (like `FRST`).
+    pub fn from_code(code: &str) -> Result<Self, Error> {
+        let code = code.trim();
+        code.parse()
+            .map_err(|_| Error::InvalidInput(format!("{code:?} is not a mandate sequence type")))
+    }
+
+    /// The ISO 20022 code of the sequence type.
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        self.into()
+    }
+
+    /// The sequence type of the next collection (None after the last one).
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Self::First | Self::Recurring => Some(Self::Recurring),
+            Self::OneOff | Self::Final => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_codes() {
+        assert_eq!(
+            MandateSequence::from_code(" frst ").unwrap(),
+            MandateSequence::First
+        );
+        assert_eq!(
+            MandateSequence::from_code("OOFF").unwrap(),
+            MandateSequence::OneOff
+        );
+        assert!(MandateSequence::from_code("NEXT").is_err());
+        assert!(MandateSequence::from_code("First").is_err());
+    }
+
+    #[test]
+    fn displays_codes() {
+        assert_eq!(MandateSequence::Recurring.code(), "RCUR");
+        assert_eq!(MandateSequence::Final.to_string(), "FNAL");
+    }
+
+    #[test]
+    fn advances_sequence() {
+        assert_eq!(
+            MandateSequence::First.next(),
+            Some(MandateSequence::Recurring)
+        );
+        assert_eq!(
+            MandateSequence::Recurring.next(),
+            Some(MandateSequence::Recurring)
+        );
+        assert_eq!(MandateSequence::OneOff.next(), None);
+        assert_eq!(MandateSequence::Final.next(), None);
+    }
+}
@@ -0,0 +1,41 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Status of a direct debit mandate
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum MandateStatus {
+    /// Mandate is signed but not yet confirmed by the gateway or the debtor's bank
+    Pending,
+    /// Mandate can be used to collect direct debits
+    Active,
+    /// Mandate was revoked by the debtor or the creditor
+    Revoked,
+    /// Mandate expired (SEPA mandates expire 36 months after the last collection)
+    Expired,
+    /// Mandate was rejected by the debtor's bank
+    Failed,
+}
+
+impl MandateStatus {
+    /// Whether direct debits can be collected under the mandate.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_active_mandates_collect() {
+        assert!(MandateStatus::Active.is_active());
+        assert!(!MandateStatus::Pending.is_active());
+        assert!(!MandateStatus::Revoked.is_active());
+    }
+}
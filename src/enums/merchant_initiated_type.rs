@@ -1,10 +1,10 @@
-use strum_macros::{AsRefStr, Display};
+use strum_macros::{AsRefStr, Display, EnumString};
 
 /// Merchant-Initiated Transaction type
 ///
 /// Categorizes transactions initiated by merchant without active customer participation.
 /// Required for Visa/Mastercard COF (Credential-on-File) mandate compliance.
-#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq)]
 pub enum MerchantInitiatedType {
     /// Interval recurring payments (subscriptions, memberships)
     Recurring,
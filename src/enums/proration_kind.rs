@@ -0,0 +1,15 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Direction of a proration line
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum ProrationKind {
+    /// Refund for the unused time of the previous plan
+    Credit,
+    /// Charge for the remaining time on the new plan
+    Debit,
+}
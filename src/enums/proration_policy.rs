@@ -0,0 +1,17 @@
+use strum_macros::{AsRefStr, Display};
+
+/// How the price difference is settled when a subscription changes mid-cycle
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum ProrationPolicy {
+    /// No proration: the new price applies from the next billing cycle
+    None,
+    /// The credit for the unused time and the debit for the new plan are added to the next billing
+    NextBilling,
+    /// The difference is charged (or refunded) immediately
+    Immediate,
+}
@@ -0,0 +1,27 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Exemption from Strong Customer Authentication (PSD2 RTS)
+///
+/// Requested by the merchant in the secure step to skip the challenge
+/// of the cardholder. The issuer has the final word and can reject the exemption
+/// (see [`ExemptionOutcome`](crate::ExemptionOutcome)).
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum ScaExemption {
+    /// Low-value payment (up to 30 EUR, within cumulative limits)
+    LowValue,
+    /// Transaction risk analysis by the acquirer or the issuer
+    TransactionRiskAnalysis,
+    /// The merchant is trusted by the cardholder at the issuer (allow list)
+    TrustedBeneficiary,
+    /// Payment by a corporate card via dedicated secure processes
+    SecureCorporate,
+    /// Subsequent payment of a series with the same amount to the same merchant
+    RecurringFixedAmount,
+    /// Merchant-initiated transaction (out of the scope of SCA)
+    MerchantInitiated,
+}
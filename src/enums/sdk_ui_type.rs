@@ -0,0 +1,24 @@
+use strum_macros::{AsRefStr, Display};
+
+/// UI type of a challenge supported by the 3DS SDK of a mobile app.
+///
+/// Values per EMVCo 3D Secure specification (`sdkUiType`).
+///
+/// # Data Protection
+/// This is a public value, neither secret nor even PII.
+/// UI types are generic capabilities of the SDK requiring no security protection.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum SdkUiType {
+    /// Text input (like OTP)
+    Text,
+    /// Single choice from the list
+    SingleSelect,
+    /// Multiple choices from the list
+    MultiSelect,
+    /// Out-of-band authentication (like a banking app)
+    OutOfBand,
+    /// HTML rendered by the ACS
+    HtmlOther,
+}
@@ -1,7 +1,7 @@
-use strum_macros::{AsRefStr, Display};
+use strum_macros::{AsRefStr, Display, EnumString};
 
 /// Status of a recurring payment subscription
-#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq)]
 pub enum SubscriptionStatus {
     /// Subscription is currently active and processing payments
     Active,
@@ -1,5 +1,7 @@
 use strum_macros::{AsRefStr, Display};
 
+use crate::Error;
+
 /// 3D Secure protocol version
 ///
 /// Indicates which version of the 3D Secure protocol was used for authentication.
@@ -16,4 +18,50 @@ pub enum ThreeDSVersion {
     V2_1_0,
     /// 3D Secure 2.2.0
     V2_2_0,
+    /// 3D Secure 2.3.0
+    V2_3_0,
+    /// 3D Secure 2.3.1
+    V2_3_1,
+}
+
+impl TryFrom<&str> for ThreeDSVersion {
+    type Error = Error;
+
+    /// Parse the message version as returned by 3DS servers (like `2.2.0`).
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        match input.trim() {
+            "2.1.0" => Ok(Self::V2_1_0),
+            "2.2.0" => Ok(Self::V2_2_0),
+            "2.3.0" => Ok(Self::V2_3_0),
+            "2.3.1" => Ok(Self::V2_3_1),
+            other => Err(Error::InvalidInput(format!(
+                "unsupported 3DS message version {other:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_message_versions() {
+        assert_eq!(
+            ThreeDSVersion::try_from("2.1.0").unwrap(),
+            ThreeDSVersion::V2_1_0
+        );
+        assert_eq!(
+            ThreeDSVersion::try_from("2.3.1").unwrap(),
+            ThreeDSVersion::V2_3_1
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_versions() {
+        for version in ["1.0.2", "2.3", "3.0.0", ""] {
+            let result = ThreeDSVersion::try_from(version);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{version:?}");
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Reason of the 3DS Requestor Initiated (3RI) authentication.
+///
+/// 3RI authentication runs without the cardholder, for example, to get
+/// a fresh authentication value for a merchant-initiated transaction.
+/// Values per EMVCo 3D Secure specification (`threeRIInd`).
+///
+/// # Data Protection
+/// This is a public value, neither secret nor even PII.
+/// The indicator is a standardized classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum ThreeRIIndicator {
+    /// Recurring transaction
+    Recurring,
+    /// Installment transaction
+    Installment,
+    /// Adding a card to the customer's account
+    AddCard,
+    /// Maintaining the card information
+    MaintainCard,
+    /// Account verification
+    AccountVerification,
+    /// Split or delayed shipment
+    SplitShipment,
+    /// Top-up of a balance
+    TopUp,
+    /// Mail order
+    MailOrder,
+    /// Telephone order
+    TelephoneOrder,
+    /// Trust list status check
+    TrustListCheck,
+    /// Other payment
+    OtherPayment,
+}
@@ -1,4 +1,4 @@
-use strum_macros::{AsRefStr, Display};
+use strum_macros::{AsRefStr, Display, EnumString};
 
 /// Status of a payment transaction
 ///
@@ -6,7 +6,7 @@ use strum_macros::{AsRefStr, Display};
 /// This is a commonly used classifier requiring no security protection.
 ///
 /// Consequently, both `Debug` and `Display` are implemented without masking.
-#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq)]
 pub enum TransactionStatus {
     /// Funds reserved but not yet captured
     Authorized,
@@ -25,3 +25,25 @@ pub enum TransactionStatus {
     /// Transaction currently being processed by gateway
     Processing,
 }
+
+impl TransactionStatus {
+    /// Whether the status can't change without an explicit operation of the merchant
+    /// (like capture, void or refund), so that polling of the status can be stopped.
+    #[inline]
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::Pending | Self::Processing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_and_processing_are_not_final() {
+        assert!(!TransactionStatus::Pending.is_final());
+        assert!(!TransactionStatus::Processing.is_final());
+        assert!(TransactionStatus::Authorized.is_final());
+        assert!(TransactionStatus::Declined.is_final());
+    }
+}
@@ -0,0 +1,18 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Digital wallet which provisioned the network token
+///
+/// # Data Protection
+/// This is a public value, neither secret nor even PII.
+/// Wallet names are commonly used classifiers requiring no security protection.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Wallet {
+    /// Apple Pay
+    ApplePay,
+    /// Google Pay
+    GooglePay,
+    /// Samsung Pay
+    SamsungPay,
+}
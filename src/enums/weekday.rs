@@ -0,0 +1,33 @@
+use strum_macros::{AsRefStr, Display};
+
+/// Day of the week (ISO 8601 order, starting from Monday)
+///
+/// # Data Protection
+/// This is a commonly used classifier requiring no security protection.
+///
+/// Consequently, both `Debug` and `Display` are implemented without masking.
+#[derive(AsRefStr, Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Weekday {
+    /// The first day of the ISO 8601 week
+    Monday,
+    /// The second day of the week
+    Tuesday,
+    /// The third day of the week
+    Wednesday,
+    /// The fourth day of the week
+    Thursday,
+    /// The last business day of the week
+    Friday,
+    /// The first day of the weekend (no bank processing)
+    Saturday,
+    /// The last day of the weekend (no bank processing)
+    Sunday,
+}
+
+impl Weekday {
+    /// Whether the day falls on the weekend (Saturday or Sunday).
+    #[inline]
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Self::Saturday | Self::Sunday)
+    }
+}
@@ -18,4 +18,24 @@ pub enum Error {
     /// Operation is not supported by the gateway adapter.
     #[error("Operation not supported: {0}")]
     NotSupported(String),
+
+    /// Failed to read or write the records of a repository.
+    #[error("Storage failed: {0}")]
+    Storage(String),
+
+    /// Operation was interrupted before completion (by a deadline or cancellation).
+    #[error("Operation interrupted: {0}")]
+    Interrupted(String),
+}
+
+impl Error {
+    /// The name of the error variant (like `InvalidInput`) suitable for logs and metrics.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidInput(_) => "InvalidInput",
+            Self::NotSupported(_) => "NotSupported",
+            Self::Storage(_) => "Storage",
+            Self::Interrupted(_) => "Interrupted",
+        }
+    }
 }
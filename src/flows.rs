@@ -1,24 +1,32 @@
 //! Declare flows that can be supported by payment gateways.
 
+mod account_updater;
 mod cancel_payments;
 pub mod change_authorization;
 mod check_transactions;
 mod deferred_payments;
 mod external_payments;
 mod immediate_payments;
+mod manage_customers;
+mod manage_mandates;
 mod recover_transactions;
 mod recurrent_payments;
 mod refund_payments;
 mod reverse_payment;
+mod search_transactions;
 mod store_credentials;
+mod transaction_stream;
 mod verify_authorization;
 
+pub use account_updater::AccountUpdater;
 pub use cancel_payments::CancelPayments;
 pub use change_authorization::{AdjustAuthorization, EditAuthorization};
 pub use check_transactions::CheckTransaction;
 pub use deferred_payments::DeferredPayments;
 pub use external_payments::ExternalPayments;
 pub use immediate_payments::ImmediatePayments;
+pub use manage_customers::ManageCustomers;
+pub use manage_mandates::ManageMandates;
 pub use recover_transactions::{RecoverTransactions, TransactionIterator};
 pub use recurrent_payments::{
     EditSubscriptionAmount, EditSubscriptionInterval, EditSubscriptionRecipients,
@@ -26,5 +34,7 @@ pub use recurrent_payments::{
 };
 pub use refund_payments::RefundPayments;
 pub use reverse_payment::ReversePayment;
+pub use search_transactions::SearchTransactions;
 pub use store_credentials::StoreCredentials;
+pub use transaction_stream::TransactionStream;
 pub use verify_authorization::VerifyAuthorization;
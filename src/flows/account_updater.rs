@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::types::{AccountUpdate, UpdatableCredential};
+use crate::{Error, Gateway};
+
+/// Optional trait for payment gateways that refresh stored cards via the card networks'
+/// account updater services (Visa Account Updater, Mastercard Automatic Billing Updater).
+///
+/// Stored cards go stale when they're reissued (expired, lost, upgraded).
+/// Submitting the credentials before the next billing date lets the merchant
+/// charge the refreshed card instead of receiving a decline.
+///
+/// # Batch Processing
+///
+/// Networks process the batches asynchronously (usually within 1-3 days).
+/// Adapters of gateways with asynchronous batches should wait for the results
+/// (or return `Error::Interrupted` to be called again later with the same batch).
+///
+/// # Gateway Support
+///
+/// * **Support**: Stripe (automatic), Adyen, Braintree, Worldpay, Cybersource
+/// * **No support**: non-card payment methods, most regional acquirers
+#[async_trait]
+pub trait AccountUpdater: Gateway {
+    /// Submit the batch of stored credentials to the account updater.
+    ///
+    /// # Parameters
+    ///
+    /// * `credentials` - Stored credentials or vault tokens to be checked
+    ///
+    /// # Returns
+    ///
+    /// The outcome for every submitted credential (in the order of submission):
+    /// updated expiry, replaced credential, closed account, the request to contact
+    /// the cardholder, or no match
+    async fn update_accounts(
+        &self,
+        credentials: Vec<UpdatableCredential>,
+    ) -> Result<Vec<(UpdatableCredential, AccountUpdate)>, Error>;
+}
@@ -9,11 +9,11 @@ use crate::types::{
 };
 use crate::{Error, Gateway, MerchantInitiatedType};
 
-trait CapturedAmount {}
+trait CapturedAmount: Send {}
 impl CapturedAmount for CaptureAuthorized {}
 impl CapturedAmount for Option<Decimal> {}
 
-trait CapturedDistribution {}
+trait CapturedDistribution: Send {}
 impl CapturedDistribution for CaptureAuthorized {}
 impl CapturedDistribution for Option<Recipients> {}
 
@@ -40,6 +40,8 @@ impl CapturedDistribution for Option<Recipients> {}
 ///
 /// * `PaymentMethod` - Payment method type constrained to internal methods (cards, tokens, etc.).
 ///   Determines which payment instruments can be used with this gateway for deferred payments.
+///   Use `ExternallyAuthenticated` to authorize card payments with the results of the merchant's
+///   own 3DS server without the `Gateway::secure` step.
 #[async_trait]
 #[allow(private_bounds)]
 pub trait DeferredPayments: Gateway
@@ -16,6 +16,8 @@ use crate::{Error, Gateway, MerchantInitiatedType};
 /// * Payment methods that don't support separate capture (some wallets, vouchers)
 /// * Gateways that only provide combined auth+capture operations
 ///
+/// Card payments authenticated by the merchant's own 3DS server are charged
+/// with the `ExternallyAuthenticated` payment method without the `Gateway::secure` step.
 #[async_trait]
 #[allow(private_bounds)]
 pub trait ImmediatePayments: Gateway
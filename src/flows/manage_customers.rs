@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+
+use crate::types::{Customer, CustomerDetails, CustomerId, CustomerPaymentMethod};
+use crate::{Error, Gateway};
+
+/// Optional trait for payment gateways that manage customers in their vault.
+///
+/// Gateway customers hold the billing details and the payment methods
+/// stored via [`StoreCredentials`](crate::flows::StoreCredentials),
+/// which are referenced by `CustomerId` of the `StoredCredential`.
+///
+/// # Gateway Support
+///
+/// * **Support**: Stripe, Braintree, Adyen (shopper references), Checkout.com, Square
+/// * **No support**: voucher systems, crypto processors
+#[async_trait]
+pub trait ManageCustomers: Gateway {
+    /// Create a new customer.
+    ///
+    /// # Parameters
+    ///
+    /// * `details` - Billing details of the customer
+    ///
+    /// # Returns
+    ///
+    /// Created customer record with the gateway-assigned ID
+    async fn create_customer(&self, details: CustomerDetails) -> Result<Customer, Error>;
+
+    /// Update the billing details of an existing customer.
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - ID of the customer to update
+    /// * `details` - New billing details (the details left empty are not changed)
+    ///
+    /// # Returns
+    ///
+    /// Updated customer record
+    async fn update_customer(
+        &self,
+        customer_id: CustomerId,
+        details: CustomerDetails,
+    ) -> Result<Customer, Error>;
+
+    /// Get the current customer details.
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - ID of the customer to query
+    ///
+    /// # Returns
+    ///
+    /// Current customer record
+    async fn get_customer(&self, customer_id: CustomerId) -> Result<Customer, Error>;
+
+    /// Delete the customer along with the payment methods stored for them.
+    /// This operation is idempotent - deleting an already deleted customer does not return an error
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - ID of the customer to delete
+    async fn delete_customer(&self, customer_id: CustomerId) -> Result<(), Error>;
+
+    /// List the payment methods stored for the customer.
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - ID of the customer to query
+    ///
+    /// # Returns
+    ///
+    /// Display metadata (network, last 4 digits, expiry) and vault tokens
+    /// of the stored payment methods
+    async fn list_payment_methods(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<CustomerPaymentMethod>, Error>;
+}
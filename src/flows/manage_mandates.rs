@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+
+use crate::types::{CustomerId, Mandate, MandateReference};
+use crate::{Error, Gateway};
+
+/// Optional trait for payment gateways that manage direct debit mandates
+/// (SEPA Direct Debit, ACH debit, Bacs, BECS, etc.).
+///
+/// Mandates are created by `Gateway::authorize` of the bank debit payment methods,
+/// and referenced by the unique mandate reference afterwards.
+///
+/// # Gateway Support
+///
+/// * **Support**: Stripe, Adyen, GoCardless, Mollie, Checkout.com
+/// * **No support**: card-only gateways
+#[async_trait]
+pub trait ManageMandates: Gateway {
+    /// List the mandates signed by the customer.
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - ID of the customer (debtor) to query
+    ///
+    /// # Returns
+    ///
+    /// Mandates of the customer in all statuses
+    async fn list_mandates(&self, customer_id: CustomerId) -> Result<Vec<Mandate>, Error>;
+
+    /// Get the current mandate details.
+    ///
+    /// # Parameters
+    ///
+    /// * `mandate_reference` - Reference of the mandate to query
+    ///
+    /// # Returns
+    ///
+    /// Current mandate record
+    async fn get_mandate(&self, mandate_reference: MandateReference) -> Result<Mandate, Error>;
+
+    /// Revoke the mandate so that no further debits are collected under it.
+    /// This operation is idempotent - revoking an already revoked mandate does not return an error
+    ///
+    /// # Parameters
+    ///
+    /// * `mandate_reference` - Reference of the mandate to revoke
+    ///
+    /// # Returns
+    ///
+    /// Updated mandate record with the revoked status
+    async fn revoke_mandate(&self, mandate_reference: MandateReference) -> Result<Mandate, Error>;
+}
@@ -25,6 +25,8 @@ use crate::types::{Transaction, TransactionIdempotenceKey};
 ///
 /// Client must deduplicate results as gateway cannot guarantee uniqueness within a page.
 ///
+/// Use `repository::recover_transactions` to look at own records before searching the gateway.
+///
 /// # Gateway Support
 ///
 /// Not all gateways support transaction search:
@@ -51,10 +53,25 @@ pub trait RecoverTransactions: Gateway {
 }
 
 /// Async iterator for paginated transaction search results.
+///
+/// Use [`TransactionStream`](crate::flows::TransactionStream) to consume it as a `Stream`.
 #[async_trait]
 pub trait TransactionIterator: Sized {
     /// Fetch the next transaction from search results.
     ///
     /// Returns `None` when no more results are available.
     async fn next(&mut self) -> Option<Result<Transaction, Error>>;
+
+    /// Hint the number of transactions to fetch per request to the gateway.
+    ///
+    /// Adapters may ignore the hint or clamp it to the limits of the gateway.
+    /// Takes effect starting from the next page.
+    fn set_page_size(&mut self, _page_size: usize) {}
+
+    /// Bounds on the number of the remaining results (like `Iterator::size_hint`).
+    ///
+    /// Adapters may use the total count reported by the gateway, if available.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
 }
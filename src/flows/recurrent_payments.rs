@@ -3,9 +3,10 @@ use rust_decimal::Decimal;
 
 use crate::types::payments::PaymentMarker;
 use crate::types::{
-    InternalPaymentMethod, Recipients, Subscription, SubscriptionId, SubscriptionInterval,
+    Date, InternalPaymentMethod, ProrationPreview, Recipients, Subscription, SubscriptionId,
+    SubscriptionInterval, SubscriptionTerms,
 };
-use crate::{Error, Gateway};
+use crate::{Error, Gateway, ProrationPolicy};
 
 /// Payment gateway trait for recurrent payment subscriptions.
 ///
@@ -26,11 +27,22 @@ where
     <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
 {
     /// Create a new recurrent payment subscription.
+    ///
+    /// # Parameters
+    ///
+    /// * `payment` - Payment charged on every billing
+    /// * `terms` - Billing interval, trial, first billing date, setup fee and proration policy.
+    ///   Implementations should return `Error::NotSupported` for the terms
+    ///   the gateway can't apply (like billing anchors or setup fees).
+    ///
+    /// # Returns
+    ///
+    /// Created subscription record
     #[allow(private_interfaces)]
     async fn create_subscription(
         &self,
         payment: <Self as Gateway>::Payment,
-        interval: SubscriptionInterval,
+        terms: SubscriptionTerms,
     ) -> Result<Subscription, Error>;
 
     /// Cancel an existing subscription.
@@ -120,12 +132,34 @@ pub trait EditSubscriptionAmount: RecurrentPayments
 where
     <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
 {
+    /// Preview the proration of the amount change before committing it.
+    ///
+    /// # Parameters
+    ///
+    /// * `subscription_id` - ID of the subscription to edit
+    /// * `total_amount` - New subscription amount
+    /// * `proration` - How the price difference is settled
+    /// * `date` - The date of the change (within the current billing period)
+    ///
+    /// # Returns
+    ///
+    /// Credit and debit lines, either returned by the gateway or computed
+    /// locally via `ProrationPreview::amount_change`
+    async fn preview_subscription_amount(
+        &self,
+        subscription_id: SubscriptionId,
+        total_amount: Decimal,
+        proration: ProrationPolicy,
+        date: Date,
+    ) -> Result<ProrationPreview, Error>;
+
     /// Edit the amount of an existing subscription.
     ///
     /// # Parameters
     ///
     /// * `subscription_id` - ID of the subscription to edit
-    /// * `amount` - New subscription amount
+    /// * `total_amount` - New subscription amount
+    /// * `proration` - How the price difference is settled
     ///
     /// # Returns
     ///
@@ -134,6 +168,7 @@ where
         &self,
         subscription_id: SubscriptionId,
         total_amount: Decimal,
+        proration: ProrationPolicy,
     ) -> Result<(), Error>;
 }
 
@@ -182,12 +217,34 @@ pub trait EditSubscriptionInterval: RecurrentPayments
 where
     <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
 {
+    /// Preview the proration of the interval change before committing it.
+    ///
+    /// # Parameters
+    ///
+    /// * `subscription_id` - ID of the subscription to edit
+    /// * `interval` - New billing interval
+    /// * `proration` - How the unused time of the current period is settled
+    /// * `date` - The date of the change (within the current billing period)
+    ///
+    /// # Returns
+    ///
+    /// Credit and debit lines, either returned by the gateway or computed
+    /// locally via `ProrationPreview::interval_change`
+    async fn preview_subscription_interval(
+        &self,
+        subscription_id: SubscriptionId,
+        interval: SubscriptionInterval,
+        proration: ProrationPolicy,
+        date: Date,
+    ) -> Result<ProrationPreview, Error>;
+
     /// Edit the billing interval of an existing subscription.
     ///
     /// # Parameters
     ///
     /// * `subscription_id` - ID of the subscription to edit
     /// * `interval` - New billing interval
+    /// * `proration` - How the unused time of the current period is settled
     ///
     /// # Returns
     ///
@@ -196,5 +253,6 @@ where
         &self,
         subscription_id: SubscriptionId,
         interval: SubscriptionInterval,
+        proration: ProrationPolicy,
     ) -> Result<(), Error>;
 }
@@ -4,11 +4,11 @@ use rust_decimal::Decimal;
 use crate::types::{Recipients, TotalRefund, Transaction, TransactionId};
 use crate::{Error, Gateway};
 
-trait RefundAmount {}
+trait RefundAmount: Send {}
 impl RefundAmount for TotalRefund {}
 impl RefundAmount for Option<Decimal> {}
 
-trait RefundDistribution {}
+trait RefundDistribution: Send {}
 impl RefundDistribution for TotalRefund {}
 impl RefundDistribution for Option<Recipients> {}
 
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::flows::RecoverTransactions;
+use crate::types::TransactionFilter;
+
+/// Payment gateway trait for searching transactions by criteria.
+///
+/// Extends [`RecoverTransactions`] with the search by date range, statuses,
+/// currency and customer, which is used by reconciliation jobs to match
+/// gateway transactions with internal records.
+///
+/// # Search Behavior
+///
+/// Returns the same async iterator as [`RecoverTransactions`], so that results
+/// can be paginated and consumed as a stream via `TransactionStream`.
+/// Adapters must apply all criteria supported by the gateway on its side,
+/// and reject the filter with `Error::NotSupported` (returned by the iterator)
+/// if some criterion can't be applied.
+///
+/// # Gateway Support
+///
+/// * **Support**: Stripe, Braintree, Adyen (reports), PayPal
+/// * **No support**: crypto processors, voucher systems
+#[async_trait]
+pub trait SearchTransactions: RecoverTransactions {
+    /// Search for transactions matching the filter.
+    ///
+    /// # Parameters
+    ///
+    /// * `filter` - Criteria the transactions must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Async iterator yielding matching transactions
+    async fn search(&self, filter: TransactionFilter) -> Self::Iterator;
+}
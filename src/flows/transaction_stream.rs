@@ -0,0 +1,190 @@
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::Error;
+use crate::flows::TransactionIterator;
+use crate::types::Transaction;
+
+type Fetching<I> = Pin<Box<dyn Future<Output = (I, Option<Result<Transaction, Error>>)> + Send>>;
+
+/// Adapter of a [`TransactionIterator`] to the `Stream` of transactions.
+///
+/// Allows to use stream combinators (like `filter`, `take` or `try_collect`)
+/// with the results of transaction search.
+///
+/// ```skip
+/// let transactions: Vec<Transaction> = TransactionStream::new(gateway.search(filter).await)
+///     .with_page_size(100)
+///     .try_collect()
+///     .await?;
+/// ```
+pub struct TransactionStream<I> {
+    state: State<I>,
+}
+
+enum State<I> {
+    Idle(I),
+    Fetching(Fetching<I>),
+    Done,
+}
+
+impl<I> TransactionStream<I>
+where
+    I: TransactionIterator + Send + 'static,
+{
+    /// Wrap the iterator.
+    #[inline]
+    pub fn new(iterator: I) -> Self {
+        Self {
+            state: State::Idle(iterator),
+        }
+    }
+
+    /// Hint the number of transactions to fetch per request to the gateway
+    /// (see [`TransactionIterator::set_page_size`]).
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        if let State::Idle(iterator) = &mut self.state {
+            iterator.set_page_size(page_size);
+        }
+        self
+    }
+}
+
+// The iterator is never pinned in place: it is either owned by value,
+// or moved into the boxed future.
+impl<I> Unpin for TransactionStream<I> {}
+
+impl<I> Stream for TransactionStream<I>
+where
+    I: TransactionIterator + Send + 'static,
+{
+    type Item = Result<Transaction, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match std::mem::replace(&mut self.state, State::Done) {
+                State::Idle(mut iterator) => {
+                    self.state = State::Fetching(Box::pin(async move {
+                        let item = iterator.next().await;
+                        (iterator, item)
+                    }));
+                }
+                State::Fetching(mut fetching) => match fetching.as_mut().poll(cx) {
+                    Poll::Pending => {
+                        self.state = State::Fetching(fetching);
+                        return Poll::Pending;
+                    }
+                    Poll::Ready((iterator, item)) => {
+                        if item.is_some() {
+                            self.state = State::Idle(iterator);
+                        }
+                        return Poll::Ready(item);
+                    }
+                },
+                State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.state {
+            State::Idle(iterator) => iterator.size_hint(),
+            State::Fetching(_) => (0, None),
+            State::Done => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TransactionStatus, inputs};
+    use async_trait::async_trait;
+    use futures::StreamExt;
+    use futures::executor::block_on;
+    use iso_currency::Currency;
+
+    /// Iterator fetching pages of the requested size from the list of statuses.
+    struct Pages {
+        statuses: Vec<TransactionStatus>,
+        page: Vec<TransactionStatus>,
+        page_size: usize,
+    }
+
+    #[async_trait]
+    impl TransactionIterator for Pages {
+        async fn next(&mut self) -> Option<Result<Transaction, Error>> {
+            if self.page.is_empty() && !self.statuses.is_empty() {
+                let size = self.page_size.min(self.statuses.len());
+                self.page = self.statuses.drain(..size).rev().collect();
+            }
+            let status = self.page.pop()?;
+            Some(
+                inputs::Transaction {
+                    transaction_id: "txn_12345678",
+                    idempotence_key: "order-42",
+                    status,
+                    decline_reason: None,
+                    currency: Currency::EUR,
+                    recipients: None,
+                    merchant_initiated_type: None,
+                    network_transaction_id: None,
+                }
+                .try_into(),
+            )
+        }
+
+        fn set_page_size(&mut self, page_size: usize) {
+            self.page_size = page_size;
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.statuses.len() + self.page.len();
+            (remaining, Some(remaining))
+        }
+    }
+
+    fn pages() -> Pages {
+        use TransactionStatus::*;
+        Pages {
+            statuses: vec![Captured, Declined, Refunded],
+            page: vec![],
+            page_size: 1,
+        }
+    }
+
+    #[test]
+    fn streams_transactions() {
+        let stream = TransactionStream::new(pages());
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+
+        let statuses: Vec<_> = block_on(
+            stream
+                .map(|result| *result.unwrap().status())
+                .collect::<Vec<_>>(),
+        );
+        use TransactionStatus::*;
+        assert_eq!(statuses, [Captured, Declined, Refunded]);
+    }
+
+    #[test]
+    fn passes_page_size_hint() {
+        let stream = TransactionStream::new(pages()).with_page_size(2);
+
+        let State::Idle(iterator) = &stream.state else {
+            panic!("the stream is not idle");
+        };
+        assert_eq!(iterator.page_size, 2);
+    }
+
+    #[test]
+    fn stays_finished() {
+        let mut stream = TransactionStream::new(pages());
+        while block_on(stream.next()).is_some() {}
+
+        assert!(block_on(stream.next()).is_none());
+        assert_eq!(stream.size_hint(), (0, Some(0)));
+    }
+}
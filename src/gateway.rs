@@ -85,6 +85,9 @@ pub trait Gateway: Send + Sync {
     ///
     /// * `Response::Secured` — payment method secured, ready for charge
     /// * `Response::RequiresAction` — customer action needed (3DS challenge)
+    /// * `Response::ExemptionRejected` — the issuer rejected the requested SCA exemption
+    ///   with a soft decline (see `secure::secure_with_exemption`)
+    /// * `Response::Declined` — the issuer declined the payment with a hard decline
     async fn secure(
         &self,
         request: secure::Request<Self::AuthorizedPaymentMethod>,
@@ -15,7 +15,7 @@ use crate::{Error, Gateway};
 /// Can be either:
 /// - `StoredCredential` — when authorization creates a reusable token (mandate, SetupIntent)
 /// - Any `PaymentMethod` — when authorization is a passthrough (no transformation)
-pub(crate) trait AuthorizedPaymentMethod {}
+pub(crate) trait AuthorizedPaymentMethod: Send {}
 impl AuthorizedPaymentMethod for StoredCredential {}
 impl<T: PaymentMethod> AuthorizedPaymentMethod for T {}
 
@@ -2,29 +2,46 @@
 //!
 //! This step handles:
 //! - 3DS authentication for CIT card payments (PSD2/SCA compliance)
+//! - SCA exemptions (low value, TRA, etc.) with a fallback to the challenge
+//!   when the issuer rejects the exemption with a soft decline
 //! - Passthrough for MIT and non-card payments
 
+use crate::enums::{DeclineReason, ExemptionOutcome, ScaExemption};
 use crate::gateway::authorize::AuthorizedPaymentMethod;
-use crate::types::{BrowserInfo, Confirmation, RequiredAction, SecuredPayment};
+use crate::types::{Confirmation, DeviceChannel, RequiredAction, SecuredPayment};
+use crate::{Error, Gateway};
 
 /// Marker trait for payment types that passed the secure() step.
 ///
 /// Provides access to 3DS authentication result data. Returns `None` for all
 /// fields when the payment is a passthrough (MIT or non-card).
-pub trait SecuredPaymentMarker {}
+pub trait SecuredPaymentMarker {
+    /// Outcome of the requested SCA exemption reported by the gateway.
+    fn exemption_outcome(&self) -> Option<ExemptionOutcome> {
+        None
+    }
+}
 
 // Passthrough: all AuthorizedPaymentMethod types automatically implement SecuredPaymentMarker
 impl<T: AuthorizedPaymentMethod> SecuredPaymentMarker for T {}
 
-impl SecuredPaymentMarker for SecuredPayment {}
+impl SecuredPaymentMarker for SecuredPayment {
+    #[inline]
+    fn exemption_outcome(&self) -> Option<ExemptionOutcome> {
+        self.exemption_outcome
+    }
+}
 
 /// Request for the secure step.
 #[allow(private_bounds)]
 pub struct Request<P: AuthorizedPaymentMethod> {
     /// The authorized payment method to secure.
     pub payment_method: P,
-    /// Browser information for risk-based authentication.
-    pub browser_info: Option<BrowserInfo>,
+    /// Channel of the authentication (browser, app or 3RI) with the device data
+    /// for risk-based authentication.
+    pub device_channel: Option<DeviceChannel>,
+    /// SCA exemption requested to skip the challenge.
+    pub exemption: Option<ScaExemption>,
     /// Confirmation data from a completed customer action (3DS challenge).
     pub confirmation: Option<Confirmation>,
 }
@@ -36,4 +53,266 @@ pub enum Response<P: SecuredPaymentMarker> {
     Secured(P),
     /// Customer action required (3DS challenge).
     RequiresAction(RequiredAction),
+    /// The issuer rejected the requested exemption with a soft decline.
+    ///
+    /// Repeat the step without the exemption to challenge the cardholder
+    /// (see [`secure_with_exemption`]).
+    ExemptionRejected(DeclineReason),
+    /// The issuer declined the payment with a hard decline (like suspected fraud).
+    Declined(DeclineReason),
+}
+
+/// The response of the secure step requesting an exemption, along with the exemption outcome.
+#[allow(private_bounds)]
+pub struct ExemptionResponse<P: SecuredPaymentMarker> {
+    /// The response of the last secure step.
+    pub response: Response<P>,
+    /// The outcome of the requested exemption: rejected with a soft decline, or
+    /// granted or not applicable as reported by the gateway with the secured payment
+    /// (`None` if no exemption was requested, the issuer declined the payment,
+    /// or the gateway reported no outcome, like for the challenge required anyway).
+    pub exemption_outcome: Option<ExemptionOutcome>,
+}
+
+/// Run the secure step requesting the exemption, and fall back to the challenge path.
+///
+/// When the issuer rejects the exemption because the authentication is required,
+/// the step is repeated without the exemption, so that the gateway starts the regular
+/// 3DS authentication (usually returning `RequiresAction` with the challenge).
+/// The rejection is returned as is for other soft decline reasons, and for requestor-initiated
+/// (3RI) authentications, where the cardholder can't be challenged. Rejections with
+/// a hard decline are returned as `Response::Declined` without the exemption outcome.
+///
+/// The outcome of the exemption is reported even when the challenge replaced it.
+#[allow(private_bounds)]
+pub async fn secure_with_exemption<G>(
+    gateway: &G,
+    request: Request<G::AuthorizedPaymentMethod>,
+) -> Result<ExemptionResponse<G::SecuredPaymentMethod>, Error>
+where
+    G: Gateway,
+    G::AuthorizedPaymentMethod: Clone,
+{
+    let Request {
+        payment_method,
+        device_channel,
+        exemption,
+        confirmation,
+    } = request;
+
+    let challengeable = device_channel
+        .as_ref()
+        .is_none_or(DeviceChannel::is_cardholder_present);
+    let retry = (exemption.is_some() && challengeable).then(|| Request {
+        payment_method: payment_method.clone(),
+        device_channel: device_channel.clone(),
+        exemption: None,
+        confirmation: confirmation.clone(),
+    });
+    let request = Request {
+        payment_method,
+        device_channel,
+        exemption,
+        confirmation,
+    };
+
+    let response = match gateway.secure(request).await? {
+        Response::ExemptionRejected(reason) if !reason.is_soft() => Response::Declined(reason),
+        response => response,
+    };
+    let exemption_outcome = match (&response, exemption) {
+        (_, None) | (Response::RequiresAction(_) | Response::Declined(_), _) => None,
+        (Response::Secured(payment_method), _) => payment_method.exemption_outcome(),
+        (Response::ExemptionRejected(reason), _) => Some(ExemptionOutcome::Rejected(*reason)),
+    };
+
+    let response = match (response, retry) {
+        (Response::ExemptionRejected(DeclineReason::AuthenticationRequired), Some(retry)) => {
+            gateway.secure(retry).await?
+        }
+        (response, _) => response,
+    };
+    Ok(ExemptionResponse {
+        response,
+        exemption_outcome,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::authorize;
+    use crate::types::{CreditCard, NoInstallments, Payment};
+    use crate::{ThreeRIIndicator, inputs, test_support};
+    use async_trait::async_trait;
+    use futures::executor::block_on;
+
+    /// Gateway securing card payments with the exemption outcome it reports,
+    /// or rejecting the exemption with the decline reason.
+    #[derive(Default)]
+    struct ExemptionGateway {
+        outcome: Option<ExemptionOutcome>,
+        decline_reason: Option<DeclineReason>,
+    }
+
+    #[async_trait]
+    impl Gateway for ExemptionGateway {
+        type Payment = Payment<CreditCard>;
+        type Installments = NoInstallments;
+        type PaymentMethod = CreditCard;
+        type AuthorizedPaymentMethod = CreditCard;
+        type SecuredPaymentMethod = SecuredPayment;
+
+        async fn authorize(
+            &self,
+            request: authorize::Request<CreditCard>,
+        ) -> Result<authorize::Response<CreditCard>, Error> {
+            Ok(authorize::Response::Authorized {
+                payment_method: request.payment_method,
+                verified: true,
+                metadata: Default::default(),
+            })
+        }
+
+        async fn secure(
+            &self,
+            request: Request<CreditCard>,
+        ) -> Result<Response<SecuredPayment>, Error> {
+            if let (Some(_), Some(reason)) = (request.exemption, self.decline_reason) {
+                return Ok(Response::ExemptionRejected(reason));
+            }
+            let secured = inputs::SecuredPayment {
+                token: Some("pi_1234567890abcdef"),
+                cavv: None,
+                eci: None,
+                ds_transaction_id: None,
+                version: None,
+                exemption: request.exemption,
+                exemption_outcome: request.exemption.and(self.outcome),
+            };
+            Ok(Response::Secured(secured.try_into()?))
+        }
+    }
+
+    fn request(exemption: Option<ScaExemption>) -> Request<CreditCard> {
+        Request {
+            payment_method: test_support::payment().payment_method().clone(),
+            device_channel: None,
+            exemption,
+            confirmation: None,
+        }
+    }
+
+    fn secure(
+        gateway: &ExemptionGateway,
+        request: Request<CreditCard>,
+    ) -> ExemptionResponse<SecuredPayment> {
+        block_on(secure_with_exemption(gateway, request)).unwrap()
+    }
+
+    fn reporting(outcome: Option<ExemptionOutcome>) -> ExemptionGateway {
+        ExemptionGateway {
+            outcome,
+            ..Default::default()
+        }
+    }
+
+    fn rejecting(reason: DeclineReason) -> ExemptionGateway {
+        ExemptionGateway {
+            decline_reason: Some(reason),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_exemption_outcome_of_gateway() {
+        for outcome in [
+            Some(ExemptionOutcome::Granted),
+            Some(ExemptionOutcome::NotApplicable),
+            None,
+        ] {
+            let result = secure(&reporting(outcome), request(Some(ScaExemption::LowValue)));
+
+            assert!(matches!(result.response, Response::Secured(_)));
+            assert_eq!(result.exemption_outcome, outcome);
+        }
+    }
+
+    #[test]
+    fn secures_without_exemption() {
+        let gateway = reporting(Some(ExemptionOutcome::Granted));
+        let result = secure(&gateway, request(None));
+
+        assert!(matches!(result.response, Response::Secured(_)));
+        assert_eq!(result.exemption_outcome, None);
+    }
+
+    #[test]
+    fn retries_without_exemption_when_authentication_required() {
+        let gateway = rejecting(DeclineReason::AuthenticationRequired);
+        let result = secure(
+            &gateway,
+            request(Some(ScaExemption::TransactionRiskAnalysis)),
+        );
+
+        assert!(matches!(result.response, Response::Secured(_)));
+        assert_eq!(
+            result.exemption_outcome,
+            Some(ExemptionOutcome::Rejected(
+                DeclineReason::AuthenticationRequired
+            ))
+        );
+    }
+
+    #[test]
+    fn returns_rejection_with_other_soft_declines() {
+        for reason in [DeclineReason::InsufficientFunds, DeclineReason::DoNotHonor] {
+            let result = secure(
+                &rejecting(reason),
+                request(Some(ScaExemption::TransactionRiskAnalysis)),
+            );
+
+            assert!(
+                matches!(result.response, Response::ExemptionRejected(r) if r == reason),
+                "{reason}"
+            );
+            assert_eq!(
+                result.exemption_outcome,
+                Some(ExemptionOutcome::Rejected(reason))
+            );
+        }
+    }
+
+    #[test]
+    fn returns_hard_declines_without_exemption_outcome() {
+        for reason in [DeclineReason::SuspectedFraud, DeclineReason::LostOrStolen] {
+            let result = secure(
+                &rejecting(reason),
+                request(Some(ScaExemption::TransactionRiskAnalysis)),
+            );
+
+            assert!(
+                matches!(result.response, Response::Declined(r) if r == reason),
+                "{reason}"
+            );
+            assert_eq!(result.exemption_outcome, None);
+        }
+    }
+
+    #[test]
+    fn does_not_challenge_requestor_initiated_authentication() {
+        let gateway = rejecting(DeclineReason::AuthenticationRequired);
+        let request = Request {
+            device_channel: Some(DeviceChannel::RequestorInitiated(
+                ThreeRIIndicator::Recurring,
+            )),
+            ..request(Some(ScaExemption::MerchantInitiated))
+        };
+        let result = secure(&gateway, request);
+
+        assert!(matches!(
+            result.response,
+            Response::ExemptionRejected(DeclineReason::AuthenticationRequired)
+        ));
+    }
 }
@@ -3,18 +3,25 @@
 
 use std::collections::HashMap;
 
+mod account_update;
 mod address;
+mod app_info;
 mod bank_payment;
 mod birth_date;
 mod bnpl;
 mod browser_info;
 mod card_expiry;
 mod cash_voucher;
+mod checkpoint;
 mod credentials;
 mod credit_card;
 mod crypto_payment;
+mod customer;
+mod date;
+mod device_channel;
 mod direct_carrier;
 mod distributed_value;
+mod external_authentication;
 mod external_payment;
 mod external_payment_data;
 mod installments;
@@ -23,6 +30,8 @@ mod installments_gcc;
 mod installments_in;
 mod installments_jp;
 mod instant_payment;
+mod mandate;
+mod network_token;
 mod payment;
 mod recipients;
 mod required_action;
@@ -34,20 +43,30 @@ mod stored_credential;
 mod stored_credential_usage;
 mod subscription;
 mod subscription_interval;
+mod subscription_terms;
 mod transaction;
+mod transaction_filter;
+mod wallet_card;
 
+pub use account_update::{AccountUpdate, UpdatableCredential};
 pub use address::Address;
+pub use app_info::AppInfo;
 pub use bank_payment::{BankPayment, BankPaymentCredentials};
 pub use birth_date::BirthDate;
 pub use bnpl::BNPL;
 pub use browser_info::BrowserInfo;
 pub use card_expiry::CardExpiry;
 pub use cash_voucher::CashVoucher;
+pub use checkpoint::Checkpoint;
 pub use credentials::Credentials;
 pub use credit_card::CreditCard;
 pub use crypto_payment::CryptoPayment;
+pub use customer::{Customer, CustomerDetails, CustomerPaymentMethod};
+pub use date::Date;
+pub use device_channel::DeviceChannel;
 pub use direct_carrier::DirectCarrier;
 pub use distributed_value::DistributedValue;
+pub use external_authentication::{ExternalAuthentication, ExternallyAuthenticated};
 pub use external_payment::ExternalPayment;
 pub use external_payment_data::ExternalPaymentData;
 pub use installments::Installments;
@@ -56,6 +75,8 @@ pub use installments_gcc::InstallmentsGCC;
 pub use installments_in::InstallmentsIN;
 pub use installments_jp::InstallmentsJP;
 pub use instant_payment::InstantPayment;
+pub use mandate::Mandate;
+pub use network_token::NetworkToken;
 pub use payment::Payment;
 pub use recipients::Recipients;
 pub use required_action::RequiredAction;
@@ -67,7 +88,10 @@ pub use stored_credential::StoredCredential;
 pub use stored_credential_usage::StoredCredentialUsage;
 pub use subscription::Subscription;
 pub use subscription_interval::SubscriptionInterval;
+pub use subscription_terms::SubscriptionTerms;
 pub use transaction::Transaction;
+pub use transaction_filter::TransactionFilter;
+pub use wallet_card::WalletCard;
 
 /// Insecure container of additional adapter-specific parameters
 /// convertible to `SecureMetadata`.
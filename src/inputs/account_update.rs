@@ -0,0 +1,28 @@
+use crate::inputs::{CardExpiry, StoredCredential};
+
+/// Stored credential submitted to the account updater.
+pub enum UpdatableCredential<'a> {
+    /// Gateway-specific stored credential (mandate, setup intent, network token).
+    StoredCredential(StoredCredential<'a>),
+    /// Token of the payment method stored in the gateway vault.
+    Token(&'a str),
+}
+
+/// Outcome of the account updater for one stored credential.
+///
+/// ```skip
+/// let update = AccountUpdate::CredentialReplaced(UpdatableCredential::Token("tok_1234567890ebadf"))
+///     .try_into()?;
+/// ```
+pub enum AccountUpdate<'a> {
+    /// The card was reissued with the same number and a new expiry.
+    ExpiryUpdated(CardExpiry),
+    /// The card was reissued with a new number, stored under the new credential.
+    CredentialReplaced(UpdatableCredential<'a>),
+    /// The account was closed by the issuer.
+    AccountClosed,
+    /// The issuer asks the merchant to contact the cardholder for new details.
+    ContactCardholder,
+    /// The issuer doesn't participate in the account updater, or has no changes.
+    NoMatch,
+}
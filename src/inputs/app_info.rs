@@ -0,0 +1,39 @@
+use crate::enums::SdkUiType;
+
+/// Mobile app information for 3D Secure authentication in the app channel.
+///
+/// The values are provided by the EMVCo-certified 3DS SDK embedded in the app.
+///
+/// ```skip
+/// let app_info = AppInfo {
+///     sdk_app_id: "9063b12c-fcde-43c7-b28e-8d0af5520e8a",
+///     sdk_encrypted_data: "eyJhbGciOiJSU0EtT0FFUC0yNTYiLCJlbmMiOiJBMTI4R0NNIn0.a2V5.aXY.Y2lwaGVy.dGFn",
+///     sdk_ephemeral_public_key: r#"{"kty":"EC","crv":"P-256","x":"...","y":"..."}"#,
+///     sdk_reference_number: "3DS_LOA_SDK_ABCD_020100_00001",
+///     sdk_transaction_id: "b2385523-a66c-4907-ac3c-91848e8c0067",
+///     max_timeout: 10,
+///     ui_types: &[SdkUiType::Text, SdkUiType::SingleSelect, SdkUiType::OutOfBand],
+/// }.try_into()?;
+/// ```
+pub struct AppInfo<'a> {
+    /// Unique identifier of the app installation generated by the SDK (UUID).
+    pub sdk_app_id: &'a str,
+
+    /// Device data encrypted by the SDK for the directory server (JWE compact serialization).
+    pub sdk_encrypted_data: &'a str,
+
+    /// Public key of the ephemeral key pair generated by the SDK (JWK as JSON).
+    pub sdk_ephemeral_public_key: &'a str,
+
+    /// Identifier of the SDK assigned by EMVCo.
+    pub sdk_reference_number: &'a str,
+
+    /// Unique identifier of the transaction generated by the SDK (UUID).
+    pub sdk_transaction_id: &'a str,
+
+    /// Maximum time for the challenge in minutes (at least 5).
+    pub max_timeout: u8,
+
+    /// Challenge UI types supported by the SDK.
+    pub ui_types: &'a [SdkUiType],
+}
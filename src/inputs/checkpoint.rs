@@ -0,0 +1,11 @@
+/// Information to build a checkpoint of a payment orchestration.
+pub struct Checkpoint<'a> {
+    /// The idempotency key of the orchestrated payment.
+    pub idempotence_key: &'a str,
+    /// The transaction ID returned by the gateway (if the gateway was called already).
+    pub transaction_id: Option<&'a str>,
+    /// The name of the last completed step defined by the orchestrator.
+    pub step: &'a str,
+    /// The moment the step was completed (Unix timestamp).
+    pub recorded_at: i64,
+}
@@ -0,0 +1,46 @@
+use crate::CardNetwork;
+use crate::inputs::{Address, CardExpiry};
+
+/// Billing details of the gateway customer (all optional).
+///
+/// ```skip
+/// let details = CustomerDetails {
+///     full_name: Some("John Doe"),
+///     email: Some("john.doe@example.com"),
+///     phone: None,
+///     billing_address: None,
+/// }.try_into()?;
+/// ```
+pub struct CustomerDetails<'a> {
+    /// Customer full name
+    pub full_name: Option<&'a str>,
+    /// Customer email address
+    pub email: Option<&'a str>,
+    /// Customer phone number
+    pub phone: Option<&'a str>,
+    /// Customer billing address
+    pub billing_address: Option<Address<'a>>,
+}
+
+/// Information to build a customer in Gateway adapters implementations.
+pub struct Customer<'a> {
+    /// The customer ID assigned by the payment gateway.
+    pub customer_id: &'a str,
+    /// The billing details of the customer.
+    pub details: CustomerDetails<'a>,
+}
+
+/// Information to build a stored payment method of the customer
+/// in Gateway adapters implementations.
+pub struct CustomerPaymentMethod<'a> {
+    /// The vault token of the payment method.
+    pub token: &'a str,
+    /// The card network (for cards only).
+    pub card_network: Option<CardNetwork>,
+    /// The last 4 digits of the card or bank account number.
+    pub last4: Option<&'a str>,
+    /// The card expiry (for cards only).
+    pub card_expiry: Option<CardExpiry>,
+    /// Whether the payment method is the default one of the customer.
+    pub is_default: bool,
+}
@@ -0,0 +1,15 @@
+/// Calendar date (day, month, year) in UTC.
+///
+/// ```skip
+/// let date = Date {
+///     day: 31,
+///     month: 12,
+///     year: 2030,
+/// }.try_into()?;
+/// ```
+#[derive(Clone, Copy)]
+pub struct Date {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
@@ -0,0 +1,21 @@
+use crate::enums::ThreeRIIndicator;
+use crate::inputs::{AppInfo, BrowserInfo};
+
+/// Channel of the 3D Secure authentication.
+///
+/// ```skip
+/// let channel = DeviceChannel::App(AppInfo { ... }).try_into()?;
+/// ```
+pub enum DeviceChannel<'a> {
+    /// The cardholder is authenticated in the browser.
+    Browser(BrowserInfo<'a>),
+
+    /// The cardholder is authenticated in the mobile app via the 3DS SDK.
+    App(AppInfo<'a>),
+
+    /// The authentication is initiated by the merchant without the cardholder (3RI).
+    RequestorInitiated {
+        /// The reason of the authentication.
+        indicator: ThreeRIIndicator,
+    },
+}
@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::enums::{CardNetwork, ThreeDSVersion};
+
+/// Result of 3D Secure authentication performed outside the gateway
+/// (by the merchant's own 3DS server, a.k.a. standalone MPI).
+///
+/// ```skip
+/// let authentication = ExternalAuthentication {
+///     network: CardNetwork::Visa,
+///     cavv: Some("AAABBBCCCdddeeefff111222333"),
+///     eci: "05",
+///     ds_transaction_id: Uuid::parse_str("64d76f6d-e512-4aba-ae29-f7af0dc7db09")?,
+///     version: ThreeDSVersion::V2_3_1,
+/// };
+/// ```
+pub struct ExternalAuthentication<'a> {
+    /// The network of the authenticated card (defines valid ECI codes).
+    pub network: CardNetwork,
+    /// Cardholder Authentication Verification Value (absent when not authenticated).
+    pub cavv: Option<&'a str>,
+    /// Two-digit Electronic Commerce Indicator as returned by the 3DS server.
+    pub eci: &'a str,
+    /// Directory Server Transaction ID.
+    pub ds_transaction_id: Uuid,
+    /// 3D Secure protocol version.
+    pub version: ThreeDSVersion,
+}
+
+/// Payment method authenticated outside the gateway.
+pub struct ExternallyAuthenticated<'a, M> {
+    /// The payment method (like `CreditCard`).
+    pub payment_method: M,
+    /// The result of the external authentication.
+    pub authentication: ExternalAuthentication<'a>,
+}
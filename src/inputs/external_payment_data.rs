@@ -1,7 +1,54 @@
-use std::marker::PhantomData;
+use rust_decimal::Decimal;
 
 /// The data for completing an external payment.
-#[derive(Default)]
-pub struct ExternalPaymentData<'a> {
-    _marker: PhantomData<&'a ()>,
+///
+/// ```skip
+/// let payment_data = ExternalPaymentData::Voucher {
+///     barcode: "23793381286000000000000000000000000000000000",
+///     digitable_line: Some("23793.38128 60000.000003 00000.000000 0 00000000000000"),
+///     expires_at: Some(1_767_225_600),
+///     printable_url: Some("https://gateway.example.com/boleto/123.pdf"),
+/// }.try_into()?;
+/// ```
+pub enum ExternalPaymentData<'a> {
+    /// Voucher to be paid in cash (Boleto, OXXO, Konbini, etc.).
+    Voucher {
+        /// The barcode (or the reference number) of the voucher.
+        barcode: &'a str,
+        /// The human-readable line to type the voucher in a banking app.
+        digitable_line: Option<&'a str>,
+        /// The moment the voucher expires (Unix timestamp).
+        expires_at: Option<i64>,
+        /// The URL of the printable voucher.
+        printable_url: Option<&'a str>,
+    },
+    /// QR code to be scanned by a mobile app (PIX, UPI, WeChat Pay, etc.).
+    QRCode {
+        /// The content encoded in the QR code (can be copied instead of scanning).
+        payload: &'a str,
+        /// The URL (or the `data:image/...` URI) of the QR code image.
+        image: Option<&'a str>,
+        /// The moment the QR code expires (Unix timestamp).
+        expires_at: Option<i64>,
+    },
+    /// Instructions for a bank transfer made by the customer.
+    BankTransfer {
+        /// The IBAN of the beneficiary (either IBAN or account number is required).
+        iban: Option<&'a str>,
+        /// The account number of the beneficiary (for non-SEPA transfers).
+        account_number: Option<&'a str>,
+        /// The bank code of the beneficiary (for non-SEPA transfers).
+        bank_code: Option<&'a str>,
+        /// The reference to be provided by the customer to match the transfer.
+        reference: &'a str,
+        /// The amount to be transferred.
+        amount: Decimal,
+    },
+    /// Link opening the app of the payment provider.
+    DeepLink {
+        /// The app link (like `bankapp://pay?id=123` or a universal `https` link).
+        url: &'a str,
+        /// The web page to open when the app is not installed.
+        fallback_url: Option<&'a str>,
+    },
 }
@@ -0,0 +1,29 @@
+use crate::inputs::Date;
+use crate::{MandateSequence, MandateStatus};
+
+/// Information to build a direct debit mandate in Gateway adapters implementations.
+///
+/// ```skip
+/// let mandate = Mandate {
+///     mandate_reference: "MANDATE-2025/0042",
+///     signed_on: Date { day: 15, month: 1, year: 2025 },
+///     sequence: MandateSequence::Recurring,
+///     creditor_id: Some("DE98ZZZ09999999999"),
+///     customer_id: Some("cus_123"),
+///     status: MandateStatus::Active,
+/// }.try_into()?;
+/// ```
+pub struct Mandate<'a> {
+    /// The unique mandate reference assigned by the creditor.
+    pub mandate_reference: &'a str,
+    /// The date the debtor signed the mandate.
+    pub signed_on: Date,
+    /// The sequence type of the next collection under the mandate.
+    pub sequence: MandateSequence,
+    /// The SEPA creditor identifier (None for non-SEPA debits like ACH).
+    pub creditor_id: Option<&'a str>,
+    /// The gateway customer the mandate belongs to.
+    pub customer_id: Option<&'a str>,
+    /// The current status of the mandate.
+    pub status: MandateStatus,
+}
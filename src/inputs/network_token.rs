@@ -0,0 +1,30 @@
+use crate::enums::Wallet;
+use crate::inputs::CardExpiry;
+
+/// Network token (DPAN) with the one-time cryptogram
+///
+/// Used both for tokens provisioned by the merchant via the network token services
+/// (Visa Token Service, Mastercard MDES) and for decrypted wallet payloads
+/// (Apple Pay, Google Pay).
+///
+/// ```skip
+/// let token = NetworkToken {
+///     number: "4895370012003478",
+///     token_expiry: CardExpiry { month: 12, year: 2030 },
+///     cryptogram: "AgAAAAAABk4DWZ4C28yUQAAAAAA=",
+///     eci: Some("07"),
+///     wallet: Some(Wallet::ApplePay),
+/// }.try_into()?;
+/// ```
+pub struct NetworkToken<'a> {
+    /// The token number (device or merchant PAN)
+    pub number: &'a str,
+    /// The expiry of the token (not of the underlying card)
+    pub token_expiry: CardExpiry,
+    /// The one-time cryptogram (TAVV or the online payment cryptogram)
+    pub cryptogram: &'a str,
+    /// The two-digit ECI code returned with the cryptogram (if any)
+    pub eci: Option<&'a str>,
+    /// The wallet which provisioned the token (None for merchant network tokens)
+    pub wallet: Option<Wallet>,
+}
@@ -1,4 +1,3 @@
-// TODO: Add more variants (Iframe, QRCode, Voucher, BankTransfer, etc.)
 /// Customer action required to complete the authorization.
 ///
 /// ```skip
@@ -15,4 +14,64 @@ pub enum RequiredAction<'a> {
         /// The URL to return to after the customer completes the action.
         return_url: &'a str,
     },
+    /// Collect the device fingerprint by posting the 3DS method data
+    /// to the ACS from a hidden iframe (EMV 3DS).
+    ThreeDSMethod {
+        /// The 3DS method URL of the ACS.
+        url: &'a str,
+        /// The `threeDSMethodData` to post (Base64url-encoded).
+        data: &'a str,
+    },
+    /// Show the 3DS challenge by posting the CReq to the ACS (EMV 3DS).
+    ThreeDSChallenge {
+        /// The URL of the ACS to post the challenge request to.
+        acs_url: &'a str,
+        /// The challenge request (CReq, Base64url-encoded).
+        creq: &'a str,
+        /// The `threeDSSessionData` to post along with the CReq (Base64url-encoded).
+        session_data: Option<&'a str>,
+    },
+    /// Show the QR code to be scanned by the customer's mobile app.
+    QRCode {
+        /// The content encoded in the QR code.
+        payload: &'a str,
+        /// The URL (or the `data:image/...` URI) of the QR code image.
+        image: Option<&'a str>,
+        /// The moment the QR code expires (Unix timestamp).
+        expires_at: Option<i64>,
+    },
+    /// Ask the customer for the one-time password sent by the issuer or the provider.
+    Otp {
+        /// The number of characters of the password (if known).
+        length: Option<u8>,
+        /// The masked destination the password was sent to (like `+44*****123`).
+        destination_hint: Option<&'a str>,
+        /// The moment the password expires (Unix timestamp).
+        expires_at: Option<i64>,
+    },
+    /// Switch the customer to the app of the payment provider (app-to-app flow).
+    DeepLink {
+        /// The app link (like `bankapp://pay?id=123` or a universal `https` link).
+        url: &'a str,
+        /// The web page to open when the app is not installed.
+        fallback_url: Option<&'a str>,
+    },
+    /// Show the voucher to be paid by the customer.
+    Voucher {
+        /// The barcode (or the reference number) of the voucher.
+        barcode: &'a str,
+        /// The human-readable line to type the voucher in a banking app.
+        digitable_line: Option<&'a str>,
+        /// The moment the voucher expires (Unix timestamp).
+        expires_at: Option<i64>,
+        /// The URL of the printable voucher.
+        printable_url: Option<&'a str>,
+    },
+    /// Wait for the customer to approve the payment in the app (push notification).
+    AwaitApproval {
+        /// The name of the app to approve the payment in (like `MobilePay`).
+        app_name: Option<&'a str>,
+        /// The moment the approval expires (Unix timestamp).
+        expires_at: Option<i64>,
+    },
 }
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::enums::{ECI, ThreeDSVersion};
+use crate::enums::{ECI, ExemptionOutcome, ScaExemption, ThreeDSVersion};
 
 /// Input for 3D Secure authentication result.
 ///
@@ -19,4 +19,8 @@ pub struct SecuredPayment<'a> {
     pub ds_transaction_id: Option<Uuid>,
     /// 3D Secure protocol version.
     pub version: Option<ThreeDSVersion>,
+    /// SCA exemption requested in the secure step.
+    pub exemption: Option<ScaExemption>,
+    /// Outcome of the requested SCA exemption.
+    pub exemption_outcome: Option<ExemptionOutcome>,
 }
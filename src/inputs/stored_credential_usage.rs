@@ -5,6 +5,6 @@ pub enum StoredCredentialUsage<'a> {
     /// The first use of credentials for storage (Customer Initiated Transaction).
     Initial,
     /// A later use of stored credentials (Merchant Initiated Transaction).
-    /// Contains reference to the original Initial transaction ID.
+    /// Contains the network transaction ID of the original Initial transaction.
     Subsequent(&'a str),
 }
@@ -2,12 +2,18 @@
 ///
 /// ```skip
 /// let daily = SubscriptionInterval::Day(1);
+/// let weekly = SubscriptionInterval::Week(1);
 /// let monthly = SubscriptionInterval::Month(1);
+/// let yearly = SubscriptionInterval::Year(1);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SubscriptionInterval {
     /// Duration-based: exactly 24 hours * count from start_date
     Day(u32),
+    /// Duration-based: exactly 7 days * count from start_date
+    Week(u32),
     /// Calendar-based: calendar month * count
     Month(u32),
+    /// Calendar-based: calendar year * count
+    Year(u32),
 }
@@ -0,0 +1,28 @@
+use rust_decimal::Decimal;
+
+use crate::{Date, ProrationPolicy, SubscriptionInterval};
+
+/// Insecure representation of the terms of a new subscription.
+///
+/// ```skip
+/// let terms = SubscriptionTerms {
+///     interval: SubscriptionInterval::Month(1),
+///     trial: Some(SubscriptionInterval::Day(14)),
+///     billing_anchor: Some(Date { day: 1, month: 7, year: 2025 }),
+///     setup_fee: Some(dec!(9.99)),
+///     proration: ProrationPolicy::NextBilling,
+/// };
+/// ```
+#[derive(Clone, Copy)]
+pub struct SubscriptionTerms {
+    /// Billing interval (how often the customer is charged).
+    pub interval: SubscriptionInterval,
+    /// The length of the free trial (None to start billing immediately).
+    pub trial: Option<SubscriptionInterval>,
+    /// The date of the first billing (None to bill at the start or at the end of the trial).
+    pub billing_anchor: Option<Date>,
+    /// One-time fee charged on creation of the subscription (on top of the first billing).
+    pub setup_fee: Option<Decimal>,
+    /// How the changes of the subscription are settled mid-cycle.
+    pub proration: ProrationPolicy,
+}
@@ -1,6 +1,6 @@
 use iso_currency::Currency;
 
-use crate::{MerchantInitiatedType, Recipients, TransactionStatus};
+use crate::{DeclineReason, MerchantInitiatedType, Recipients, TransactionStatus};
 
 /// Information to build a transaction in Gateway adapters implementations.
 pub struct Transaction<'a> {
@@ -10,10 +10,14 @@ pub struct Transaction<'a> {
     pub idempotence_key: &'a str,
     /// The canonical status of the transaction.
     pub status: TransactionStatus,
+    /// The canonical reason of the decline (for declined transactions only).
+    pub decline_reason: Option<DeclineReason>,
     /// The currency of the transaction.
     pub currency: Currency,
     /// The payment recipients.
     pub recipients: Option<Recipients<'a>>,
     /// The MIT (merchant initiated type of the transaction)
     pub merchant_initiated_type: Option<MerchantInitiatedType>,
+    /// The network transaction ID assigned by the card scheme (if returned by the gateway).
+    pub network_transaction_id: Option<&'a str>,
 }
@@ -0,0 +1,20 @@
+use iso_currency::Currency;
+
+use crate::TransactionStatus;
+
+/// Information to build a filter of transaction search.
+///
+/// Empty (default) criteria match all transactions.
+#[derive(Default)]
+pub struct TransactionFilter<'a> {
+    /// The earliest creation time of transactions (Unix timestamp, inclusive).
+    pub created_from: Option<i64>,
+    /// The latest creation time of transactions (Unix timestamp, exclusive).
+    pub created_to: Option<i64>,
+    /// The statuses of transactions (empty = any status).
+    pub statuses: &'a [TransactionStatus],
+    /// The currency of transactions.
+    pub currency: Option<Currency>,
+    /// The customer who made transactions.
+    pub customer_id: Option<&'a str>,
+}
@@ -0,0 +1,20 @@
+use crate::enums::Wallet;
+use crate::inputs::CardExpiry;
+
+/// Funding card returned by a wallet without a cryptogram
+///
+/// ```skip
+/// let card = WalletCard {
+///     number: "4111111111111111",
+///     card_expiry: CardExpiry { month: 12, year: 2030 },
+///     wallet: Wallet::GooglePay,
+/// }.try_into()?;
+/// ```
+pub struct WalletCard<'a> {
+    /// Primary Account Number (PAN) of the funding card
+    pub number: &'a str,
+    /// Card expiration date (month and year)
+    pub card_expiry: CardExpiry,
+    /// The wallet which returned the card
+    pub wallet: Wallet,
+}
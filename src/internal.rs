@@ -1,10 +1,12 @@
 //! The module keeps sealed internal traits and types used exclusively
 //! by types in this crate to DRY the code and prevent errors.
 
+mod hex;
 mod masked;
 mod validated;
 
 pub(crate) mod sanitized;
 
+pub(crate) use hex::{hex, unhex};
 pub(crate) use masked::Masked;
-pub(crate) use validated::Validated;
+pub(crate) use validated::{Validated, days_in_month};
@@ -0,0 +1,17 @@
+//! Hex encoding of binary values (signatures, digests, identifiers).
+
+/// Encode bytes as lowercase hex.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Decode hex (in any case), or return None for malformed input.
+pub(crate) fn unhex(input: &str) -> Option<Vec<u8>> {
+    if !input.len().is_multiple_of(2) || !input.is_ascii() {
+        return None;
+    }
+    (0..input.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&input[i..i + 2], 16).ok())
+        .collect()
+}
@@ -97,6 +97,25 @@ pub(crate) trait Validated: Sized + Debug {
         }
     }
 
+    /// Validates the URL with one of the given schemes (like `https`)
+    /// and a non-empty rest without whitespaces or control characters.
+    fn _validate_url(&self, input: &str, schemes: &[&str]) -> Result<(), Error> {
+        self._validate_length(input, 1, 2048)?;
+        let valid = input.split_once("://").is_some_and(|(scheme, rest)| {
+            schemes.contains(&scheme)
+                && !rest.is_empty()
+                && !rest.chars().any(|c| c.is_whitespace() || c.is_control())
+        });
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!(
+                "{self:?} URL is invalid (expected schemes: {})",
+                schemes.join(", ")
+            )))
+        }
+    }
+
     fn _validate_no_trailing_spaces(&self, input: &str) -> Result<(), Error> {
         if input.trim() == input {
             Ok(())
@@ -114,7 +133,7 @@ fn is_leap_year(year: &u16) -> bool {
 }
 
 #[inline]
-fn days_in_month(year: &u16, month: &u8) -> u8 {
+pub(crate) fn days_in_month(year: &u16, month: &u8) -> u8 {
     match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
@@ -3,10 +3,26 @@ mod enums;
 mod error;
 mod inputs;
 mod internal;
+#[cfg(test)]
+mod test_support;
 
+pub mod calendar;
+pub mod clock;
+pub mod compliance;
+pub mod decorators;
+pub mod dunning;
 pub mod flows;
 pub mod gateway;
+pub mod metrics;
+pub mod polling;
+pub mod prenotification;
+pub mod repository;
+pub mod return_url;
+pub mod schedule;
+pub mod subscriptions;
 pub mod types;
+#[cfg(feature = "wallet-decryption")]
+pub mod wallet_decryption;
 
 pub use as_unsafe_ref::AsUnsafeRef;
 pub use enums::*;
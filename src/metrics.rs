@@ -0,0 +1,334 @@
+//! Hooks for collecting metrics of payment outcomes.
+//!
+//! The [`Metrics`] trait is called by the [`Metered`](crate::decorators::Metered) decorator
+//! for every flow call, so that approval rates, decline reasons, errors and latencies
+//! can be reported per gateway without instrumenting every adapter by hand.
+//!
+//! Implement the trait to forward measurements to a metrics backend (Prometheus, StatsD, etc.).
+//! The crate provides two implementations:
+//!
+//! * [`NoopMetrics`] discards all measurements,
+//! * [`InMemoryMetrics`] keeps them in memory for tests.
+
+use iso_currency::Currency;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::{DeclineReason, TransactionStatus};
+
+/// Dimensions every measurement is broken down by.
+///
+/// # Data Protection
+/// Labels contain public classifiers only and can be exported to any metrics backend.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Labels {
+    /// The name of the gateway (acquirer), like `stripe` or `adyen`.
+    pub gateway: &'static str,
+    /// The name of the flow method, like `charge` or `capture`.
+    pub flow: &'static str,
+    /// The kind of the payment method, like `credit_card` or `sepa`.
+    pub payment_method: &'static str,
+    /// The currency of the payment, if known.
+    pub currency: Option<Currency>,
+}
+
+/// Classification of a transaction status for metrics.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// The operation was accepted (authorized, captured, voided or refunded).
+    Approved,
+    /// The operation was rejected by the issuer or the gateway.
+    Declined,
+    /// The operation failed on the gateway or the processor side (not a decline).
+    Failed,
+    /// The operation is not completed yet (pending or processing).
+    Pending,
+}
+
+impl From<TransactionStatus> for Outcome {
+    fn from(status: TransactionStatus) -> Self {
+        match status {
+            TransactionStatus::Authorized
+            | TransactionStatus::Captured
+            | TransactionStatus::Voided
+            | TransactionStatus::Refunded => Self::Approved,
+            TransactionStatus::Declined => Self::Declined,
+            TransactionStatus::Failed => Self::Failed,
+            TransactionStatus::Pending | TransactionStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+/// Receiver of the measurements of payment flow calls.
+///
+/// All methods are no-op by default, so implementors can override only those
+/// they are interested in. The methods are called synchronously on the flow's
+/// hot path, and must not block.
+pub trait Metrics: Send + Sync {
+    /// A flow call is started.
+    fn attempt(&self, _labels: &Labels) {}
+
+    /// A flow call returned an approved transaction.
+    fn approval(&self, _labels: &Labels) {}
+
+    /// A flow call returned a declined transaction with an optional canonical reason.
+    fn decline(&self, _labels: &Labels, _reason: Option<DeclineReason>) {}
+
+    /// A flow call returned a transaction that is not completed yet.
+    fn pending(&self, _labels: &Labels) {}
+
+    /// A flow call returned a transaction failed for technical reasons
+    /// (counted apart from declines, so that outages don't skew approval rates).
+    fn failure(&self, _labels: &Labels) {}
+
+    /// A flow call failed with the error of the given kind (see `Error::kind`).
+    fn error(&self, _labels: &Labels, _kind: &'static str) {}
+
+    /// A flow call took the given time to complete (successfully or not).
+    fn latency(&self, _labels: &Labels, _latency: Duration) {}
+}
+
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn attempt(&self, labels: &Labels) {
+        (**self).attempt(labels)
+    }
+
+    fn approval(&self, labels: &Labels) {
+        (**self).approval(labels)
+    }
+
+    fn decline(&self, labels: &Labels, reason: Option<DeclineReason>) {
+        (**self).decline(labels, reason)
+    }
+
+    fn pending(&self, labels: &Labels) {
+        (**self).pending(labels)
+    }
+
+    fn failure(&self, labels: &Labels) {
+        (**self).failure(labels)
+    }
+
+    fn error(&self, labels: &Labels, kind: &'static str) {
+        (**self).error(labels, kind)
+    }
+
+    fn latency(&self, labels: &Labels, latency: Duration) {
+        (**self).latency(labels, latency)
+    }
+}
+
+/// Metrics implementation discarding all measurements.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+/// Metrics implementation keeping all measurements in memory.
+///
+/// Intended for tests and debugging, as the memory usage grows
+/// with the number of latency observations.
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics(Mutex<Counters>);
+
+#[derive(Debug, Default)]
+struct Counters {
+    attempts: HashMap<Labels, u64>,
+    approvals: HashMap<Labels, u64>,
+    declines: HashMap<(Labels, Option<DeclineReason>), u64>,
+    pending: HashMap<Labels, u64>,
+    failures: HashMap<Labels, u64>,
+    errors: HashMap<(Labels, &'static str), u64>,
+    latencies: HashMap<Labels, Vec<Duration>>,
+}
+
+impl InMemoryMetrics {
+    /// The number of calls started with the given labels.
+    pub fn attempts(&self, labels: &Labels) -> u64 {
+        self.read(|c| c.attempts.get(labels).copied())
+    }
+
+    /// The number of approved transactions with the given labels.
+    pub fn approvals(&self, labels: &Labels) -> u64 {
+        self.read(|c| c.approvals.get(labels).copied())
+    }
+
+    /// The number of declined transactions with the given labels and reason.
+    pub fn declines(&self, labels: &Labels, reason: Option<DeclineReason>) -> u64 {
+        self.read(|c| c.declines.get(&(*labels, reason)).copied())
+    }
+
+    /// The number of pending transactions with the given labels.
+    pub fn pending_count(&self, labels: &Labels) -> u64 {
+        self.read(|c| c.pending.get(labels).copied())
+    }
+
+    /// The number of failed transactions with the given labels.
+    pub fn failures(&self, labels: &Labels) -> u64 {
+        self.read(|c| c.failures.get(labels).copied())
+    }
+
+    /// The number of errors of the given kind with the given labels.
+    pub fn errors(&self, labels: &Labels, kind: &str) -> u64 {
+        self.read(|c| {
+            c.errors
+                .iter()
+                .find(|((l, k), _)| l == labels && *k == kind)
+                .map(|(_, count)| *count)
+        })
+    }
+
+    /// The share of approvals among completed (approved or declined) transactions.
+    ///
+    /// Returns `None` if no transaction has been completed yet.
+    pub fn approval_rate(&self, labels: &Labels) -> Option<f64> {
+        let approvals = self.approvals(labels);
+        let declines: u64 = self.read(|c| {
+            Some(
+                c.declines
+                    .iter()
+                    .filter(|((l, _), _)| l == labels)
+                    .map(|(_, count)| count)
+                    .sum(),
+            )
+        });
+        let total = approvals + declines;
+        (total > 0).then(|| approvals as f64 / total as f64)
+    }
+
+    /// All observed latencies with the given labels, in the order of observation.
+    pub fn latencies(&self, labels: &Labels) -> Vec<Duration> {
+        let counters = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        counters.latencies.get(labels).cloned().unwrap_or_default()
+    }
+
+    /// Counts observed latencies with the given labels per bucket.
+    ///
+    /// Every bucket is defined by its inclusive upper bound, and the result
+    /// contains one count per bucket, plus the last one for latencies exceeding all bounds.
+    pub fn histogram(&self, labels: &Labels, bounds: &[Duration]) -> Vec<u64> {
+        let mut counts = vec![0; bounds.len() + 1];
+        for latency in self.latencies(labels) {
+            let bucket = bounds
+                .iter()
+                .position(|bound| latency <= *bound)
+                .unwrap_or(bounds.len());
+            counts[bucket] += 1;
+        }
+        counts
+    }
+
+    fn read(&self, get: impl FnOnce(&Counters) -> Option<u64>) -> u64 {
+        let counters = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        get(&counters).unwrap_or_default()
+    }
+
+    fn write(&self, update: impl FnOnce(&mut Counters)) {
+        let mut counters = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        update(&mut counters);
+    }
+}
+
+impl Metrics for InMemoryMetrics {
+    fn attempt(&self, labels: &Labels) {
+        self.write(|c| *c.attempts.entry(*labels).or_default() += 1);
+    }
+
+    fn approval(&self, labels: &Labels) {
+        self.write(|c| *c.approvals.entry(*labels).or_default() += 1);
+    }
+
+    fn decline(&self, labels: &Labels, reason: Option<DeclineReason>) {
+        self.write(|c| *c.declines.entry((*labels, reason)).or_default() += 1);
+    }
+
+    fn pending(&self, labels: &Labels) {
+        self.write(|c| *c.pending.entry(*labels).or_default() += 1);
+    }
+
+    fn failure(&self, labels: &Labels) {
+        self.write(|c| *c.failures.entry(*labels).or_default() += 1);
+    }
+
+    fn error(&self, labels: &Labels, kind: &'static str) {
+        self.write(|c| *c.errors.entry((*labels, kind)).or_default() += 1);
+    }
+
+    fn latency(&self, labels: &Labels, latency: Duration) {
+        self.write(|c| c.latencies.entry(*labels).or_default().push(latency));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABELS: Labels = Labels {
+        gateway: "mock",
+        flow: "charge",
+        payment_method: "credit_card",
+        currency: Some(Currency::EUR),
+    };
+
+    #[test]
+    fn classifies_statuses() {
+        for (status, outcome) in [
+            (TransactionStatus::Authorized, Outcome::Approved),
+            (TransactionStatus::Captured, Outcome::Approved),
+            (TransactionStatus::Voided, Outcome::Approved),
+            (TransactionStatus::Refunded, Outcome::Approved),
+            (TransactionStatus::Declined, Outcome::Declined),
+            (TransactionStatus::Failed, Outcome::Failed),
+            (TransactionStatus::Pending, Outcome::Pending),
+            (TransactionStatus::Processing, Outcome::Pending),
+        ] {
+            assert_eq!(Outcome::from(status), outcome, "{status}");
+        }
+    }
+
+    #[test]
+    fn counts_measurements() {
+        let metrics = InMemoryMetrics::default();
+        metrics.attempt(&LABELS);
+        metrics.attempt(&LABELS);
+        metrics.attempt(&LABELS);
+        metrics.approval(&LABELS);
+        metrics.decline(&LABELS, Some(DeclineReason::InsufficientFunds));
+        metrics.error(&LABELS, "InvalidInput");
+
+        assert_eq!(metrics.attempts(&LABELS), 3);
+        assert_eq!(metrics.approvals(&LABELS), 1);
+        assert_eq!(
+            metrics.declines(&LABELS, Some(DeclineReason::InsufficientFunds)),
+            1
+        );
+        assert_eq!(metrics.declines(&LABELS, None), 0);
+        assert_eq!(metrics.errors(&LABELS, "InvalidInput"), 1);
+        assert_eq!(metrics.approval_rate(&LABELS), Some(0.5));
+    }
+
+    #[test]
+    fn separates_labels() {
+        let metrics = InMemoryMetrics::default();
+        let other = Labels {
+            gateway: "other",
+            ..LABELS
+        };
+        metrics.approval(&LABELS);
+
+        assert_eq!(metrics.approvals(&other), 0);
+        assert_eq!(metrics.approval_rate(&other), None);
+    }
+
+    #[test]
+    fn builds_latency_histogram() {
+        let metrics = InMemoryMetrics::default();
+        for ms in [5, 50, 100, 700] {
+            metrics.latency(&LABELS, Duration::from_millis(ms));
+        }
+
+        let bounds = [Duration::from_millis(10), Duration::from_millis(100)];
+        assert_eq!(metrics.histogram(&LABELS, &bounds), vec![1, 2, 1]);
+    }
+}
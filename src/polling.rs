@@ -0,0 +1,441 @@
+//! Polling of transaction statuses for asynchronous payments.
+//!
+//! External payments (vouchers, bank transfers, QR codes) and transactions
+//! returned as `Pending` or `Processing` reach their final status later.
+//! The [`Poller`] checks the status via [`CheckTransaction::status`]
+//! with exponential backoff until the status becomes final, the deadline passes,
+//! or the polling is cancelled.
+//!
+//! The crate doesn't depend on any async runtime, so the sleeping is delegated
+//! to the [`Timer`] provided by the application:
+//!
+//! ```skip
+//! struct TokioTimer;
+//!
+//! #[async_trait]
+//! impl Timer for TokioTimer {
+//!     async fn sleep(&self, duration: Duration) {
+//!         tokio::time::sleep(duration).await
+//!     }
+//! }
+//!
+//! let transaction = poll_until_final(&gateway, transaction_id, &PollingPolicy::default(), &TokioTimer).await?;
+//! ```
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::flows::CheckTransaction;
+use crate::types::{Transaction, TransactionId};
+use crate::{Error, TransactionStatus};
+
+/// Schedule of status checks.
+///
+/// The first check is made immediately. The delay before the next check starts
+/// from `initial_delay` and is multiplied by `multiplier` after every check,
+/// but never exceeds `max_delay`. No checks are made after the `deadline`
+/// (counted from the first check).
+///
+/// # Validation
+/// * initial_delay: positive, so that the gateway is never polled in a busy loop,
+/// * multiplier: at least 1 (delays never decrease),
+/// * max_delay: not less than the initial delay.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PollingPolicy {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    deadline: Duration,
+}
+
+impl PollingPolicy {
+    /// Build the policy from the delays, the growth factor of delays and the deadline.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the policy would poll the gateway without delays.
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        multiplier: u32,
+        deadline: Duration,
+    ) -> Result<Self, Error> {
+        if initial_delay.is_zero() {
+            return Err(Error::InvalidInput(
+                "Initial delay of polling must be positive".into(),
+            ));
+        }
+        if multiplier == 0 {
+            return Err(Error::InvalidInput(
+                "Multiplier of polling delays must be at least 1".into(),
+            ));
+        }
+        if max_delay < initial_delay {
+            return Err(Error::InvalidInput(format!(
+                "Max delay {max_delay:?} is less than the initial delay {initial_delay:?}"
+            )));
+        }
+        Ok(Self {
+            initial_delay,
+            max_delay,
+            multiplier,
+            deadline,
+        })
+    }
+
+    /// The delay before the second check.
+    #[inline]
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    /// The upper limit of the delay between checks.
+    #[inline]
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// The factor of the delay growth (1 for constant delays).
+    #[inline]
+    pub fn multiplier(&self) -> u32 {
+        self.multiplier
+    }
+
+    /// The maximum duration of the polling.
+    #[inline]
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+}
+
+impl Default for PollingPolicy {
+    /// Checks after 1, 2, 4... seconds (up to 1 minute between checks) during 15 minutes.
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2,
+            deadline: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Source of time for polling, backed by the application's async runtime.
+#[async_trait]
+pub trait Timer: Send + Sync {
+    /// Wait for the duration without blocking the thread.
+    async fn sleep(&self, duration: Duration);
+
+    /// The current moment.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Flag to cancel polling from another task.
+///
+/// Clones share the same flag.
+#[derive(Clone, Debug, Default)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    /// Cancel polling. The poller stops before the next check.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the polling was cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Async iterator over the changes of the transaction status.
+///
+/// Yields the transaction after the first check, and then every time its status changes.
+/// The iteration ends after yielding:
+/// * the transaction with the final status,
+/// * the error returned by the gateway,
+/// * `Error::Interrupted` when the deadline passed or the polling was cancelled.
+pub struct Poller<'a, G, T> {
+    gateway: &'a G,
+    transaction_id: TransactionId,
+    policy: PollingPolicy,
+    timer: &'a T,
+    cancellation: Cancellation,
+    started_at: Option<Instant>,
+    delay: Duration,
+    status: Option<TransactionStatus>,
+    finished: bool,
+}
+
+impl<'a, G: CheckTransaction, T: Timer> Poller<'a, G, T> {
+    /// Build the poller for the transaction.
+    pub fn new(
+        gateway: &'a G,
+        transaction_id: TransactionId,
+        policy: PollingPolicy,
+        timer: &'a T,
+    ) -> Self {
+        Self {
+            gateway,
+            transaction_id,
+            policy,
+            timer,
+            cancellation: Cancellation::default(),
+            started_at: None,
+            delay: policy.initial_delay,
+            status: None,
+            finished: false,
+        }
+    }
+
+    /// Stop polling when the flag is cancelled.
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Check the status until it changes, and return the updated transaction.
+    ///
+    /// Returns `None` when the iteration is finished.
+    pub async fn next(&mut self) -> Option<Result<Transaction, Error>> {
+        while !self.finished {
+            if let Err(error) = self.wait().await {
+                self.finished = true;
+                return Some(Err(error));
+            }
+
+            let transaction = match self.gateway.status(self.transaction_id.clone()).await {
+                Ok(transaction) => transaction,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            };
+
+            let status = *transaction.status();
+            self.finished = status.is_final();
+            if self.finished || self.status != Some(status) {
+                self.status = Some(status);
+                return Some(Ok(transaction));
+            }
+        }
+        None
+    }
+
+    /// Wait for the next check (if it is not the first one) following the policy.
+    async fn wait(&mut self) -> Result<(), Error> {
+        self.check_cancellation()?;
+
+        let Some(started_at) = self.started_at else {
+            self.started_at = Some(self.timer.now());
+            return Ok(());
+        };
+
+        let elapsed = self.timer.now().saturating_duration_since(started_at);
+        let remaining = self.policy.deadline.saturating_sub(elapsed);
+        if remaining.is_zero() {
+            return Err(Error::Interrupted(format!(
+                "the status is still {} after {:?}",
+                self.status.map(|s| s.to_string()).unwrap_or_default(),
+                self.policy.deadline
+            )));
+        }
+
+        self.timer.sleep(self.delay.min(remaining)).await;
+        self.delay = self
+            .delay
+            .saturating_mul(self.policy.multiplier)
+            .min(self.policy.max_delay);
+
+        self.check_cancellation()
+    }
+
+    fn check_cancellation(&self) -> Result<(), Error> {
+        if self.cancellation.is_cancelled() {
+            Err(Error::Interrupted("polling was cancelled".into()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Poll the transaction status until it becomes final.
+///
+/// Returns the transaction with the final status, the error of the gateway,
+/// or `Error::Interrupted` if the deadline passed. Drop the future to cancel polling,
+/// or use the [`Poller`] to observe the intermediate statuses.
+pub async fn poll_until_final<G: CheckTransaction, T: Timer>(
+    gateway: &G,
+    transaction_id: TransactionId,
+    policy: &PollingPolicy,
+    timer: &T,
+) -> Result<Transaction, Error> {
+    let mut poller = Poller::new(gateway, transaction_id, *policy, timer);
+    let mut last = None;
+    while let Some(result) = poller.next().await {
+        last = Some(result?);
+    }
+    last.ok_or_else(|| Error::Interrupted("no status was received".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, MockGateway};
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+
+    /// Timer advancing its clock instead of sleeping.
+    struct FakeTimer {
+        now: Mutex<Instant>,
+        sleeps: Mutex<Vec<Duration>>,
+    }
+
+    impl FakeTimer {
+        fn new() -> Self {
+            Self {
+                now: Mutex::new(Instant::now()),
+                sleeps: Mutex::new(vec![]),
+            }
+        }
+
+        fn sleeps(&self) -> Vec<Duration> {
+            self.sleeps.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl Timer for FakeTimer {
+        async fn sleep(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+            self.sleeps.lock().unwrap().push(duration);
+        }
+
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn gateway(statuses: &[TransactionStatus]) -> MockGateway {
+        MockGateway {
+            status: statuses.last().copied(),
+            sequence: Mutex::new(statuses.iter().rev().copied().collect()),
+            ..Default::default()
+        }
+    }
+
+    fn policy() -> PollingPolicy {
+        PollingPolicy::new(
+            Duration::from_secs(1),
+            Duration::from_secs(3),
+            2,
+            Duration::from_secs(10),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn polls_until_final_status_with_backoff() {
+        use TransactionStatus::*;
+        let gateway = gateway(&[Pending, Pending, Processing, Processing, Captured]);
+        let timer = FakeTimer::new();
+
+        let transaction = block_on(poll_until_final(
+            &gateway,
+            test_support::transaction_id(),
+            &policy(),
+            &timer,
+        ))
+        .unwrap();
+
+        assert_eq!(*transaction.status(), Captured);
+        assert_eq!(
+            timer.sleeps(),
+            [1, 2, 3, 3].map(Duration::from_secs).to_vec()
+        );
+    }
+
+    #[test]
+    fn yields_status_changes_only() {
+        use TransactionStatus::*;
+        let gateway = gateway(&[Pending, Pending, Processing, Processing, Captured]);
+        let timer = FakeTimer::new();
+        let mut poller = Poller::new(&gateway, test_support::transaction_id(), policy(), &timer);
+
+        let mut statuses = vec![];
+        while let Some(result) = block_on(poller.next()) {
+            statuses.push(*result.unwrap().status());
+        }
+        assert_eq!(statuses, [Pending, Processing, Captured]);
+    }
+
+    #[test]
+    fn stops_at_deadline() {
+        let gateway = gateway(&[TransactionStatus::Pending]);
+        let timer = FakeTimer::new();
+
+        let result = block_on(poll_until_final(
+            &gateway,
+            test_support::transaction_id(),
+            &policy(),
+            &timer,
+        ));
+
+        assert!(matches!(result, Err(Error::Interrupted(_))));
+        assert_eq!(timer.sleeps().iter().sum::<Duration>(), policy().deadline);
+    }
+
+    #[test]
+    fn stops_when_cancelled() {
+        let gateway = gateway(&[TransactionStatus::Pending]);
+        let timer = FakeTimer::new();
+        let cancellation = Cancellation::default();
+        let mut poller = Poller::new(&gateway, test_support::transaction_id(), policy(), &timer)
+            .with_cancellation(cancellation.clone());
+
+        assert!(block_on(poller.next()).unwrap().is_ok());
+        cancellation.cancel();
+        assert!(matches!(
+            block_on(poller.next()),
+            Some(Err(Error::Interrupted(_)))
+        ));
+        assert!(block_on(poller.next()).is_none());
+    }
+
+    #[test]
+    fn stops_on_gateway_error() {
+        let gateway = MockGateway::default();
+        let timer = FakeTimer::new();
+
+        let result = block_on(poll_until_final(
+            &gateway,
+            test_support::transaction_id(),
+            &policy(),
+            &timer,
+        ));
+
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+        assert!(timer.sleeps().is_empty());
+    }
+
+    #[test]
+    fn rejects_policies_without_delays() {
+        let second = Duration::from_secs(1);
+        for (initial_delay, max_delay, multiplier) in [
+            (Duration::ZERO, second, 2),
+            (second, second, 0),
+            (second * 2, second, 2),
+        ] {
+            assert!(matches!(
+                PollingPolicy::new(initial_delay, max_delay, multiplier, second * 10),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+        assert!(PollingPolicy::new(second, second, 1, second * 10).is_ok());
+    }
+}
@@ -0,0 +1,494 @@
+//! Pre-notifications of direct debit collections.
+//!
+//! SEPA rulebooks require the creditor to notify the debtor of the amount and the date
+//! of every collection in advance (14 calendar days unless agreed otherwise in the mandate),
+//! and NACHA has similar rules for variable ACH debits. Debtors' banks refund
+//! (and creditors' banks reject) the collections which were not pre-notified in time.
+//!
+//! The [`PreNotifications`] builds the records to be sent to the debtor from the scheduled
+//! charges of a subscription or from future-dated payments:
+//!
+//! ```skip
+//! let notifications = PreNotifications::new(&Weekdays).with_lead_time(LeadTime::CalendarDays(5));
+//!
+//! let today = time_zone.date_at(SystemClock.now());
+//! let schedule = BillingSchedule::new(anchor, *subscription.interval());
+//! for notice in
+//!     notifications.for_subscription(&mandate, &subscription, &schedule, amount, &time_zone, 3)?
+//! {
+//!     if notice.is_due(today) {
+//!         send_email(customer, &notice);
+//!     }
+//! }
+//! ```
+
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+
+use crate::calendar::BankingCalendar;
+use crate::clock::TimeZone;
+use crate::schedule::BillingSchedule;
+use crate::types::{CreditorId, Date, Mandate, MandateReference, Subscription};
+use crate::{Error, MandateSequence};
+
+/// The minimal time between the pre-notification and the collection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeadTime {
+    /// The number of calendar days (the SEPA rulebooks count calendar days).
+    CalendarDays(u32),
+    /// The number of business days of the calendar.
+    BusinessDays(u32),
+}
+
+impl Default for LeadTime {
+    /// The default lead time of the SEPA rulebooks (14 calendar days).
+    #[inline]
+    fn default() -> Self {
+        Self::CalendarDays(14)
+    }
+}
+
+/// The pre-notification of a direct debit collection
+///
+/// # Data Protection
+/// Pre-notifications are sent to the debtor, and are not masked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreNotification {
+    pub(crate) mandate_reference: MandateReference,
+    pub(crate) creditor_id: Option<CreditorId>,
+    pub(crate) sequence: MandateSequence,
+    pub(crate) amount: Decimal,
+    pub(crate) currency: Currency,
+    pub(crate) collection_date: Date,
+    pub(crate) notify_by: Date,
+}
+
+impl PreNotification {
+    /// The reference of the mandate the debit is collected under.
+    #[inline]
+    pub fn mandate_reference(&self) -> &MandateReference {
+        &self.mandate_reference
+    }
+
+    /// The SEPA creditor identifier (None for non-SEPA debits like ACH).
+    #[inline]
+    pub fn creditor_id(&self) -> Option<&CreditorId> {
+        self.creditor_id.as_ref()
+    }
+
+    /// The sequence type of the collection.
+    #[inline]
+    pub fn sequence(&self) -> MandateSequence {
+        self.sequence
+    }
+
+    /// The amount to be collected.
+    #[inline]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// The currency of the collection.
+    #[inline]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The date the debit is collected on (a business day).
+    #[inline]
+    pub fn collection_date(&self) -> Date {
+        self.collection_date
+    }
+
+    /// The last date to send the pre-notification on.
+    #[inline]
+    pub fn notify_by(&self) -> Date {
+        self.notify_by
+    }
+
+    /// Whether the pre-notification must be sent today (on the last day to notify the debtor).
+    #[inline]
+    pub fn is_due(&self, today: Date) -> bool {
+        today == self.notify_by
+    }
+
+    /// Whether the pre-notification was not sent in time to collect on the date.
+    #[inline]
+    pub fn is_late(&self, today: Date) -> bool {
+        today > self.notify_by
+    }
+}
+
+/// Builder of pre-notifications with the lead time and the banking calendar.
+pub struct PreNotifications<'a> {
+    calendar: &'a dyn BankingCalendar,
+    lead_time: LeadTime,
+}
+
+impl<'a> PreNotifications<'a> {
+    /// Build the pre-notifications with the default lead time (14 calendar days).
+    pub fn new(calendar: &'a dyn BankingCalendar) -> Self {
+        Self {
+            calendar,
+            lead_time: LeadTime::default(),
+        }
+    }
+
+    /// Use the lead time agreed in the mandate (or required by the scheme).
+    pub fn with_lead_time(mut self, lead_time: LeadTime) -> Self {
+        self.lead_time = lead_time;
+        self
+    }
+
+    /// The minimal time between the pre-notification and the collection.
+    #[inline]
+    pub fn lead_time(&self) -> LeadTime {
+        self.lead_time
+    }
+
+    /// The collection date of the charge scheduled on the date
+    /// (the date itself or the next business day).
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if there's no business day up to the last supported date.
+    pub fn collection_date(&self, date: Date) -> Result<Date, Error> {
+        self.calendar.next_business_day(date).ok_or_else(|| {
+            Error::InvalidInput(format!("No business day to collect on since {date:?}"))
+        })
+    }
+
+    /// The last date to send the pre-notification of the collection on.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the lead time starts before the first supported date.
+    pub fn notify_by(&self, collection_date: Date) -> Result<Date, Error> {
+        match self.lead_time {
+            LeadTime::CalendarDays(days) => collection_date.checked_add_days(-i64::from(days)),
+            LeadTime::BusinessDays(days) => self
+                .calendar
+                .add_business_days(collection_date, -i64::from(days)),
+        }
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "No date to notify of the collection on {collection_date:?}"
+            ))
+        })
+    }
+
+    /// The earliest collection date for the pre-notification sent on the date.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if there's no business day to collect on
+    /// up to the last supported date.
+    pub fn earliest_collection_date(&self, today: Date) -> Result<Date, Error> {
+        let mut date = self.collection_date(today)?;
+        while self.notify_by(date)? < today {
+            let next = date.checked_add_days(1).ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "No collection date for the pre-notification sent on {today:?}"
+                ))
+            })?;
+            date = self.collection_date(next)?;
+        }
+        Ok(date)
+    }
+
+    /// The pre-notification of the future-dated payment collected under the mandate
+    /// (on the date or the next business day).
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the mandate is not active, or the amount is not positive.
+    pub fn for_payment(
+        &self,
+        mandate: &Mandate,
+        amount: Decimal,
+        currency: Currency,
+        date: Date,
+    ) -> Result<PreNotification, Error> {
+        self.notification(mandate, mandate.sequence(), amount, currency, date)
+    }
+
+    /// The pre-notifications of the next scheduled charges of the subscription
+    /// (up to the count) collected under the mandate.
+    ///
+    /// The billing dates are taken from the schedule of the subscription (with its original
+    /// anchor, so that month-end billings are not drifting), starting from the next billing
+    /// date of the subscription in the customer's time zone. One-off and final mandates
+    /// cover a single collection. Subscriptions without the next billing get no notifications.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the mandate is not active, the amount is not positive,
+    /// or there's no business day to collect on.
+    pub fn for_subscription(
+        &self,
+        mandate: &Mandate,
+        subscription: &Subscription,
+        schedule: &BillingSchedule,
+        amount: Decimal,
+        time_zone: &dyn TimeZone,
+        count: usize,
+    ) -> Result<Vec<PreNotification>, Error> {
+        let Some(next_billing) = subscription.next_billing_date() else {
+            return Ok(Vec::new());
+        };
+        let next_billing = time_zone.date_at(next_billing);
+        let dates = (0..)
+            .map_while(|cycle| schedule.billing_date(cycle))
+            .skip_while(|date| *date < next_billing)
+            .take(count);
+
+        let mut notifications = Vec::new();
+        let mut sequence = Some(mandate.sequence());
+        for date in dates {
+            let Some(current) = sequence else { break };
+            notifications.push(self.notification(
+                mandate,
+                current,
+                amount,
+                subscription.currency(),
+                date,
+            )?);
+            sequence = current.next();
+        }
+        Ok(notifications)
+    }
+
+    fn notification(
+        &self,
+        mandate: &Mandate,
+        sequence: MandateSequence,
+        amount: Decimal,
+        currency: Currency,
+        date: Date,
+    ) -> Result<PreNotification, Error> {
+        if !mandate.is_active() {
+            return Err(Error::InvalidInput(format!(
+                "Mandate {:?} is {}",
+                mandate.mandate_reference(),
+                mandate.status()
+            )));
+        }
+        if amount <= Decimal::ZERO {
+            return Err(Error::InvalidInput(format!(
+                "Collection amount {amount} must be positive"
+            )));
+        }
+
+        let collection_date = self.collection_date(date)?;
+        Ok(PreNotification {
+            mandate_reference: mandate.mandate_reference().clone(),
+            creditor_id: mandate.creditor_id().cloned(),
+            sequence,
+            amount,
+            currency,
+            collection_date,
+            notify_by: self.notify_by(collection_date)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Weekdays;
+    use crate::clock::FixedOffset;
+    use crate::test_support::date;
+    use crate::types::SubscriptionInterval;
+    use crate::{MandateStatus, SubscriptionStatus, inputs};
+    use rust_decimal_macros::dec;
+
+    fn mandate(sequence: MandateSequence, status: MandateStatus) -> Mandate {
+        inputs::Mandate {
+            mandate_reference: "MANDATE-2025/0042",
+            signed_on: inputs::Date {
+                day: 15,
+                month: 1,
+                year: 2025,
+            },
+            sequence,
+            creditor_id: Some("DE98ZZZ09999999999"),
+            customer_id: None,
+            status,
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    fn subscription(next_billing: Date) -> Subscription {
+        Subscription {
+            subscription_id: "sub_123".try_into().unwrap(),
+            status: SubscriptionStatus::Active,
+            interval: SubscriptionInterval::Month(1),
+            currency: Currency::EUR,
+            recipients: None,
+            created_at: 0,
+            next_billing_date: Some(next_billing.timestamp()),
+        }
+    }
+
+    #[test]
+    fn rolls_payments_to_business_days() {
+        let mandate = mandate(MandateSequence::OneOff, MandateStatus::Active);
+        let notice = PreNotifications::new(&Weekdays)
+            .for_payment(&mandate, dec!(49.90), Currency::EUR, date(2025, 3, 1))
+            .unwrap();
+
+        assert_eq!(notice.mandate_reference().as_ref(), "MANDATE-2025/0042");
+        assert_eq!(notice.creditor_id().unwrap().as_ref(), "DE98ZZZ09999999999");
+        assert_eq!(notice.sequence(), MandateSequence::OneOff);
+        assert_eq!(
+            (notice.amount(), notice.currency()),
+            (dec!(49.90), Currency::EUR)
+        );
+        assert_eq!(notice.collection_date(), date(2025, 3, 3));
+        assert_eq!(notice.notify_by(), date(2025, 2, 17));
+    }
+
+    #[test]
+    fn counts_lead_time_in_business_days() {
+        let notifications =
+            PreNotifications::new(&Weekdays).with_lead_time(LeadTime::BusinessDays(2));
+
+        assert_eq!(
+            notifications.notify_by(date(2025, 3, 3)).unwrap(),
+            date(2025, 2, 27)
+        );
+        assert_eq!(
+            notifications
+                .earliest_collection_date(date(2025, 2, 28))
+                .unwrap(),
+            date(2025, 3, 4)
+        );
+        assert_eq!(
+            PreNotifications::new(&Weekdays)
+                .earliest_collection_date(date(2025, 2, 17))
+                .unwrap(),
+            date(2025, 3, 3)
+        );
+    }
+
+    fn schedule(anchor: Date) -> BillingSchedule {
+        BillingSchedule::new(anchor, SubscriptionInterval::Month(1))
+    }
+
+    #[test]
+    fn notifies_scheduled_subscription_charges() {
+        let mandate = mandate(MandateSequence::First, MandateStatus::Active);
+        let notices = PreNotifications::new(&Weekdays)
+            .for_subscription(
+                &mandate,
+                &subscription(date(2025, 5, 31)),
+                &schedule(date(2025, 1, 31)),
+                dec!(9.99),
+                &FixedOffset(0),
+                3,
+            )
+            .unwrap();
+
+        assert_eq!(
+            notices
+                .iter()
+                .map(|notice| (notice.sequence(), notice.collection_date()))
+                .collect::<Vec<_>>(),
+            [
+                (MandateSequence::First, date(2025, 6, 2)),
+                (MandateSequence::Recurring, date(2025, 6, 30)),
+                (MandateSequence::Recurring, date(2025, 7, 31)),
+            ]
+        );
+        assert!(
+            notices
+                .iter()
+                .all(|notice| notice.currency() == Currency::EUR)
+        );
+    }
+
+    #[test]
+    fn keeps_month_end_anchor_of_subscription() {
+        let mandate = mandate(MandateSequence::Recurring, MandateStatus::Active);
+        let notices = PreNotifications::new(&Weekdays)
+            .with_lead_time(LeadTime::CalendarDays(5))
+            .for_subscription(
+                &mandate,
+                &subscription(date(2025, 6, 30)),
+                &schedule(date(2025, 1, 31)),
+                dec!(9.99),
+                &FixedOffset(0),
+                3,
+            )
+            .unwrap();
+
+        // Collected on Jul 31 (not Jul 30), and on Monday after Aug 31
+        assert_eq!(
+            notices
+                .iter()
+                .map(|notice| (notice.collection_date(), notice.notify_by()))
+                .collect::<Vec<_>>(),
+            [
+                (date(2025, 6, 30), date(2025, 6, 25)),
+                (date(2025, 7, 31), date(2025, 7, 26)),
+                (date(2025, 9, 1), date(2025, 8, 27)),
+            ]
+        );
+    }
+
+    #[test]
+    fn notifies_single_collection_of_one_off_mandate() {
+        let mandate = mandate(MandateSequence::OneOff, MandateStatus::Active);
+        let notices = PreNotifications::new(&Weekdays)
+            .for_subscription(
+                &mandate,
+                &subscription(date(2025, 5, 31)),
+                &schedule(date(2025, 1, 31)),
+                dec!(9.99),
+                &FixedOffset(0),
+                3,
+            )
+            .unwrap();
+
+        assert_eq!(notices.len(), 1);
+    }
+
+    #[test]
+    fn rejects_inactive_mandates_and_non_positive_amounts() {
+        let notifications = PreNotifications::new(&Weekdays);
+        let revoked = mandate(MandateSequence::Recurring, MandateStatus::Revoked);
+        let active = mandate(MandateSequence::Recurring, MandateStatus::Active);
+
+        assert!(matches!(
+            notifications.for_payment(&revoked, dec!(10), Currency::EUR, date(2025, 3, 3)),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            notifications.for_payment(&active, dec!(0), Currency::EUR, date(2025, 3, 3)),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_collections_out_of_supported_dates() {
+        let notifications = PreNotifications::new(&Weekdays);
+
+        assert!(matches!(
+            notifications.earliest_collection_date(date(2199, 12, 30)),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            notifications.notify_by(date(1970, 1, 5)),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn tells_due_and_late_notices() {
+        let mandate = mandate(MandateSequence::OneOff, MandateStatus::Active);
+        let notice = PreNotifications::new(&Weekdays)
+            .for_payment(&mandate, dec!(10), Currency::EUR, date(2025, 3, 3))
+            .unwrap();
+
+        assert!(!notice.is_due(date(2025, 2, 16)));
+        assert!(notice.is_due(date(2025, 2, 17)));
+        assert!(!notice.is_late(date(2025, 2, 17)));
+        assert!(notice.is_late(date(2025, 2, 18)));
+    }
+}
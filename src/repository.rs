@@ -0,0 +1,203 @@
+//! Persistence of payment records on the merchant's side.
+//!
+//! The [`TransactionRepository`] trait stores transactions, subscriptions
+//! and orchestration checkpoints returned by the flows, so that the application
+//! can recover its state from its own records before asking the gateway
+//! (see [`recover_transactions`]).
+//!
+//! The crate provides two implementations:
+//!
+//! * [`InMemoryRepository`] keeps the records in memory (for tests and prototyping),
+//! * [`SqliteRepository`] keeps them in a SQLite database (requires the `sqlite` feature).
+//!
+//! # Data Protection
+//! Transaction and subscription IDs give access to operations on the payment,
+//! and must never be stored in plain text. Persistent implementations encrypt them
+//! with the application-provided [`Cipher`], and look them up by their keyed fingerprints.
+
+use async_trait::async_trait;
+use zeroize::Zeroizing;
+
+use crate::Error;
+use crate::flows::{RecoverTransactions, TransactionIterator};
+use crate::types::{
+    Checkpoint, Subscription, SubscriptionId, Transaction, TransactionId, TransactionIdempotenceKey,
+};
+
+mod in_memory;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use in_memory::InMemoryRepository;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteRepository;
+
+/// Storage of the payment records on the merchant's side.
+///
+/// Saving a record with the same key (transaction ID, subscription ID,
+/// or the idempotence key of a checkpoint) replaces the previous one.
+#[async_trait]
+pub trait TransactionRepository: Send + Sync {
+    /// Save (insert or replace) the transaction.
+    async fn save_transaction(&self, transaction: &Transaction) -> Result<(), Error>;
+
+    /// Find the transaction by its ID.
+    async fn transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Option<Transaction>, Error>;
+
+    /// Find all transactions with the idempotence key in the order of their first saving.
+    async fn transactions(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Vec<Transaction>, Error>;
+
+    /// Save (insert or replace) the subscription.
+    async fn save_subscription(&self, subscription: &Subscription) -> Result<(), Error>;
+
+    /// Find the subscription by its ID.
+    async fn subscription(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<Option<Subscription>, Error>;
+
+    /// Save (insert or replace) the checkpoint of the payment orchestration.
+    async fn save_checkpoint(&self, checkpoint: &Checkpoint) -> Result<(), Error>;
+
+    /// Find the last checkpoint of the payment orchestration by its idempotence key.
+    async fn checkpoint(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Option<Checkpoint>, Error>;
+}
+
+/// Encryption of the sensitive values stored by persistent repositories.
+///
+/// The crate doesn't manage keys: implement the trait on top of your KMS
+/// or an authenticated cipher (like AES-GCM) with a key from a secret storage.
+pub trait Cipher: Send + Sync {
+    /// Encrypt the plain text value.
+    fn encrypt(&self, plaintext: &str) -> Result<Vec<u8>, Error>;
+
+    /// Decrypt the value encrypted by the `encrypt` method.
+    ///
+    /// The plain text is zeroized on drop, so that it doesn't stay in memory.
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Zeroizing<String>, Error>;
+
+    /// Build a deterministic keyed digest (like HMAC-SHA256) of the value for lookups.
+    ///
+    /// The fingerprint must not be reversible without the key.
+    fn fingerprint(&self, plaintext: &str) -> Vec<u8>;
+}
+
+/// Recover transactions by the idempotence key, looking at own records first.
+///
+/// When the repository has no transactions with the key, searches them at the gateway,
+/// saves every found transaction to the repository, and returns them deduplicated.
+pub async fn recover_transactions<G, R>(
+    gateway: &G,
+    repository: &R,
+    idempotence_key: &TransactionIdempotenceKey,
+) -> Result<Vec<Transaction>, Error>
+where
+    G: RecoverTransactions,
+    R: TransactionRepository,
+{
+    let stored = repository.transactions(idempotence_key).await?;
+    if !stored.is_empty() {
+        return Ok(stored);
+    }
+
+    let mut found: Vec<Transaction> = Vec::new();
+    let mut iterator = gateway.transactions(idempotence_key.clone()).await;
+    while let Some(transaction) = iterator.next().await {
+        let transaction = transaction?;
+        repository.save_transaction(&transaction).await?;
+        match found
+            .iter_mut()
+            .find(|t| t.transaction_id == transaction.transaction_id)
+        {
+            Some(existing) => *existing = transaction,
+            None => found.push(transaction),
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, MockGateway};
+    use crate::{TransactionStatus, inputs};
+    use futures::executor::block_on;
+    use iso_currency::Currency;
+
+    pub(crate) struct Page(Vec<Transaction>);
+
+    #[async_trait]
+    impl TransactionIterator for Page {
+        async fn next(&mut self) -> Option<Result<Transaction, Error>> {
+            self.0.pop().map(Ok)
+        }
+    }
+
+    #[async_trait]
+    impl RecoverTransactions for MockGateway {
+        type Iterator = Page;
+
+        async fn transactions(&self, _key: TransactionIdempotenceKey) -> Self::Iterator {
+            let status = self.status.unwrap_or(TransactionStatus::Pending);
+            // The page repeats the same transaction
+            Page(vec![transaction(status), transaction(status)])
+        }
+    }
+
+    fn transaction(status: TransactionStatus) -> Transaction {
+        inputs::Transaction {
+            transaction_id: test_support::TRANSACTION_ID,
+            idempotence_key: test_support::IDEMPOTENCE_KEY,
+            status,
+            decline_reason: None,
+            currency: Currency::EUR,
+            recipients: None,
+            merchant_initiated_type: None,
+            network_transaction_id: None,
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    fn key() -> TransactionIdempotenceKey {
+        test_support::IDEMPOTENCE_KEY.try_into().unwrap()
+    }
+
+    #[test]
+    fn recovers_from_gateway_and_saves_transactions() {
+        let gateway = MockGateway {
+            status: Some(TransactionStatus::Captured),
+            ..Default::default()
+        };
+        let repository = InMemoryRepository::default();
+
+        let found = block_on(recover_transactions(&gateway, &repository, &key())).unwrap();
+        assert_eq!(found.len(), 1);
+
+        let stored = block_on(repository.transaction(&test_support::transaction_id())).unwrap();
+        assert_eq!(*stored.unwrap().status(), TransactionStatus::Captured);
+    }
+
+    #[test]
+    fn prefers_own_records() {
+        let gateway = MockGateway {
+            status: Some(TransactionStatus::Captured),
+            ..Default::default()
+        };
+        let repository = InMemoryRepository::default();
+        block_on(repository.save_transaction(&transaction(TransactionStatus::Authorized))).unwrap();
+
+        let found = block_on(recover_transactions(&gateway, &repository, &key())).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(*found[0].status(), TransactionStatus::Authorized);
+    }
+}
@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+use super::TransactionRepository;
+use crate::Error;
+use crate::types::{
+    Checkpoint, Subscription, SubscriptionId, Transaction, TransactionId, TransactionIdempotenceKey,
+};
+
+/// Repository keeping the records in memory.
+///
+/// Intended for tests and prototyping, as the records are lost on restart
+/// and lookups scan all records.
+#[derive(Debug, Default)]
+pub struct InMemoryRepository(Mutex<Records>);
+
+#[derive(Debug, Default)]
+struct Records {
+    transactions: Vec<Transaction>,
+    subscriptions: Vec<Subscription>,
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl InMemoryRepository {
+    fn with<T>(&self, f: impl FnOnce(&mut Records) -> T) -> T {
+        let mut records = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut records)
+    }
+}
+
+/// Replace the record matching the predicate, or append a new one.
+fn upsert<T: Clone>(records: &mut Vec<T>, record: &T, same: impl Fn(&T) -> bool) {
+    match records.iter_mut().find(|r| same(r)) {
+        Some(existing) => *existing = record.clone(),
+        None => records.push(record.clone()),
+    }
+}
+
+#[async_trait]
+impl TransactionRepository for InMemoryRepository {
+    async fn save_transaction(&self, transaction: &Transaction) -> Result<(), Error> {
+        self.with(|r| {
+            upsert(&mut r.transactions, transaction, |t| {
+                t.transaction_id == transaction.transaction_id
+            })
+        });
+        Ok(())
+    }
+
+    async fn transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Option<Transaction>, Error> {
+        Ok(self.with(|r| {
+            r.transactions
+                .iter()
+                .find(|t| &t.transaction_id == transaction_id)
+                .cloned()
+        }))
+    }
+
+    async fn transactions(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Vec<Transaction>, Error> {
+        Ok(self.with(|r| {
+            r.transactions
+                .iter()
+                .filter(|t| t.idempotence_key.as_ref() == idempotence_key.as_ref())
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn save_subscription(&self, subscription: &Subscription) -> Result<(), Error> {
+        self.with(|r| {
+            upsert(&mut r.subscriptions, subscription, |s| {
+                s.subscription_id == subscription.subscription_id
+            })
+        });
+        Ok(())
+    }
+
+    async fn subscription(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<Option<Subscription>, Error> {
+        Ok(self.with(|r| {
+            r.subscriptions
+                .iter()
+                .find(|s| &s.subscription_id == subscription_id)
+                .cloned()
+        }))
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &Checkpoint) -> Result<(), Error> {
+        self.with(|r| {
+            upsert(&mut r.checkpoints, checkpoint, |c| {
+                c.idempotence_key.as_ref() == checkpoint.idempotence_key.as_ref()
+            })
+        });
+        Ok(())
+    }
+
+    async fn checkpoint(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Option<Checkpoint>, Error> {
+        Ok(self.with(|r| {
+            r.checkpoints
+                .iter()
+                .find(|c| c.idempotence_key.as_ref() == idempotence_key.as_ref())
+                .cloned()
+        }))
+    }
+}
@@ -0,0 +1,629 @@
+use async_trait::async_trait;
+use iso_currency::Currency;
+use rusqlite::{Connection, OptionalExtension, Row, params};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use super::{Cipher, TransactionRepository};
+use crate::types::{
+    Checkpoint, DistributedValue, Recipients, Subscription, SubscriptionId, SubscriptionInterval,
+    Transaction, TransactionId, TransactionIdempotenceKey,
+};
+use crate::{AsUnsafeRef, Error, inputs};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS transactions (
+        fingerprint BLOB PRIMARY KEY,
+        transaction_id BLOB NOT NULL,
+        idempotence_key TEXT NOT NULL,
+        status TEXT NOT NULL,
+        decline_reason TEXT,
+        currency TEXT NOT NULL,
+        recipients TEXT,
+        merchant_initiated_type TEXT,
+        network_transaction_id BLOB
+    );
+    CREATE INDEX IF NOT EXISTS transactions_idempotence_key
+        ON transactions (idempotence_key);
+    CREATE TABLE IF NOT EXISTS subscriptions (
+        fingerprint BLOB PRIMARY KEY,
+        subscription_id BLOB NOT NULL,
+        status TEXT NOT NULL,
+        interval TEXT NOT NULL,
+        currency TEXT NOT NULL,
+        recipients TEXT,
+        created_at INTEGER NOT NULL,
+        next_billing_date INTEGER
+    );
+    CREATE TABLE IF NOT EXISTS checkpoints (
+        idempotence_key TEXT PRIMARY KEY,
+        transaction_id BLOB,
+        step TEXT NOT NULL,
+        recorded_at INTEGER NOT NULL
+    );
+";
+
+/// Repository keeping the records in a SQLite database.
+///
+/// The schema is created on opening the database if it doesn't exist yet.
+///
+/// # Data Protection
+/// Transaction and subscription IDs are stored encrypted by the [`Cipher`],
+/// and looked up by their fingerprints. Idempotence keys, statuses, currencies
+/// and recipients are public and stored as plain text.
+///
+/// # Blocking
+/// SQLite calls are synchronous and run on the caller's task.
+/// Use a file on a local disk (or an in-memory database) to keep them short.
+pub struct SqliteRepository<C> {
+    connection: Mutex<Connection>,
+    cipher: C,
+}
+
+impl<C: Cipher> SqliteRepository<C> {
+    /// Open (or create) the database at the path.
+    pub fn open(path: impl AsRef<Path>, cipher: C) -> Result<Self, Error> {
+        Self::new(Connection::open(path).map_err(storage)?, cipher)
+    }
+
+    /// Open the database in memory.
+    pub fn open_in_memory(cipher: C) -> Result<Self, Error> {
+        Self::new(Connection::open_in_memory().map_err(storage)?, cipher)
+    }
+
+    fn new(connection: Connection, cipher: C) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA).map_err(storage)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+            cipher,
+        })
+    }
+
+    fn with<T>(&self, f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> Result<T, Error> {
+        let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        f(&connection).map_err(storage)
+    }
+
+    fn decrypt_transaction(&self, row: TransactionRow) -> Result<Transaction, Error> {
+        let transaction_id = self.cipher.decrypt(&row.transaction_id)?;
+        let network_transaction_id = row
+            .network_transaction_id
+            .as_deref()
+            .map(|value| self.cipher.decrypt(value))
+            .transpose()?;
+        let recipients = row
+            .recipients
+            .as_deref()
+            .map(decode_recipients)
+            .transpose()?;
+        inputs::Transaction {
+            transaction_id: &transaction_id,
+            idempotence_key: &row.idempotence_key,
+            status: parse(&row.status)?,
+            decline_reason: row.decline_reason.as_deref().map(parse).transpose()?,
+            currency: parse_currency(&row.currency)?,
+            recipients: recipients.as_deref().map(borrow_recipients),
+            merchant_initiated_type: row
+                .merchant_initiated_type
+                .as_deref()
+                .map(parse)
+                .transpose()?,
+            network_transaction_id: network_transaction_id.as_ref().map(|id| id.as_str()),
+        }
+        .try_into()
+    }
+}
+
+#[async_trait]
+impl<C: Cipher> TransactionRepository for SqliteRepository<C> {
+    async fn save_transaction(&self, transaction: &Transaction) -> Result<(), Error> {
+        // SAFETY: the ID is exposed to the cipher only, and never stored in plain text.
+        let transaction_id = unsafe { transaction.transaction_id.as_ref() };
+        let fingerprint = self.cipher.fingerprint(transaction_id);
+        let encrypted = self.cipher.encrypt(transaction_id)?;
+        let network_transaction_id = transaction
+            .network_transaction_id
+            .as_ref()
+            // SAFETY: the ID is exposed to the cipher only, and never stored in plain text.
+            .map(|id| self.cipher.encrypt(unsafe { id.as_ref() }))
+            .transpose()?;
+
+        self.with(|connection| {
+            connection.execute(
+                "INSERT INTO transactions (fingerprint, transaction_id, idempotence_key, status, \
+                     decline_reason, currency, recipients, merchant_initiated_type, \
+                     network_transaction_id) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9) \
+                 ON CONFLICT (fingerprint) DO UPDATE SET \
+                     transaction_id = excluded.transaction_id, \
+                     idempotence_key = excluded.idempotence_key, \
+                     status = excluded.status, \
+                     decline_reason = excluded.decline_reason, \
+                     currency = excluded.currency, \
+                     recipients = excluded.recipients, \
+                     merchant_initiated_type = excluded.merchant_initiated_type, \
+                     network_transaction_id = excluded.network_transaction_id",
+                params![
+                    fingerprint,
+                    encrypted,
+                    transaction.idempotence_key.as_ref(),
+                    transaction.status.as_ref(),
+                    transaction.decline_reason.as_ref().map(AsRef::as_ref),
+                    transaction.currency.code(),
+                    transaction.recipients.as_ref().map(encode_recipients),
+                    transaction
+                        .merchant_initiated_type
+                        .as_ref()
+                        .map(AsRef::as_ref),
+                    network_transaction_id,
+                ],
+            )
+        })?;
+        Ok(())
+    }
+
+    async fn transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Option<Transaction>, Error> {
+        // SAFETY: the ID is exposed to the cipher only.
+        let fingerprint = self.cipher.fingerprint(unsafe { transaction_id.as_ref() });
+        let row = self.with(|connection| {
+            connection
+                .query_row(
+                    &format!(
+                        "SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE fingerprint = ?1"
+                    ),
+                    params![fingerprint],
+                    TransactionRow::read,
+                )
+                .optional()
+        })?;
+        row.map(|row| self.decrypt_transaction(row)).transpose()
+    }
+
+    async fn transactions(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Vec<Transaction>, Error> {
+        let rows = self.with(|connection| {
+            connection
+                .prepare(&format!(
+                    "SELECT {TRANSACTION_COLUMNS} FROM transactions \
+                     WHERE idempotence_key = ?1 ORDER BY rowid"
+                ))?
+                .query_map(params![idempotence_key.as_ref()], TransactionRow::read)?
+                .collect::<rusqlite::Result<Vec<_>>>()
+        })?;
+        rows.into_iter()
+            .map(|row| self.decrypt_transaction(row))
+            .collect()
+    }
+
+    async fn save_subscription(&self, subscription: &Subscription) -> Result<(), Error> {
+        // SAFETY: the ID is exposed to the cipher only, and never stored in plain text.
+        let subscription_id = unsafe { subscription.subscription_id.as_ref() };
+        let fingerprint = self.cipher.fingerprint(subscription_id);
+        let encrypted = self.cipher.encrypt(subscription_id)?;
+
+        self.with(|connection| {
+            connection.execute(
+                "INSERT INTO subscriptions (fingerprint, subscription_id, status, interval, \
+                     currency, recipients, created_at, next_billing_date) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) \
+                 ON CONFLICT (fingerprint) DO UPDATE SET \
+                     subscription_id = excluded.subscription_id, \
+                     status = excluded.status, \
+                     interval = excluded.interval, \
+                     currency = excluded.currency, \
+                     recipients = excluded.recipients, \
+                     created_at = excluded.created_at, \
+                     next_billing_date = excluded.next_billing_date",
+                params![
+                    fingerprint,
+                    encrypted,
+                    subscription.status.as_ref(),
+                    encode_interval(&subscription.interval),
+                    subscription.currency.code(),
+                    subscription.recipients.as_ref().map(encode_recipients),
+                    subscription.created_at,
+                    subscription.next_billing_date,
+                ],
+            )
+        })?;
+        Ok(())
+    }
+
+    async fn subscription(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<Option<Subscription>, Error> {
+        // SAFETY: the ID is exposed to the cipher only.
+        let fingerprint = self.cipher.fingerprint(unsafe { subscription_id.as_ref() });
+        let row = self.with(|connection| {
+            connection
+                .query_row(
+                    "SELECT subscription_id, status, interval, currency, recipients, \
+                         created_at, next_billing_date \
+                     FROM subscriptions WHERE fingerprint = ?1",
+                    params![fingerprint],
+                    SubscriptionRow::read,
+                )
+                .optional()
+        })?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let subscription_id = self.cipher.decrypt(&row.subscription_id)?;
+        let recipients = row
+            .recipients
+            .as_deref()
+            .map(decode_recipients)
+            .transpose()?;
+        inputs::Subscription {
+            subscription_id: &subscription_id,
+            status: parse(&row.status)?,
+            interval: decode_interval(&row.interval)?,
+            currency: parse_currency(&row.currency)?,
+            recipients: recipients.as_deref().map(borrow_recipients),
+            created_at: row.created_at,
+            next_billing_date: row.next_billing_date,
+        }
+        .try_into()
+        .map(Some)
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &Checkpoint) -> Result<(), Error> {
+        let encrypted = checkpoint
+            .transaction_id
+            .as_ref()
+            // SAFETY: the ID is exposed to the cipher only, and never stored in plain text.
+            .map(|id| self.cipher.encrypt(unsafe { id.as_ref() }))
+            .transpose()?;
+
+        self.with(|connection| {
+            connection.execute(
+                "INSERT INTO checkpoints (idempotence_key, transaction_id, step, recorded_at) \
+                 VALUES (?1, ?2, ?3, ?4) \
+                 ON CONFLICT (idempotence_key) DO UPDATE SET \
+                     transaction_id = excluded.transaction_id, \
+                     step = excluded.step, \
+                     recorded_at = excluded.recorded_at",
+                params![
+                    checkpoint.idempotence_key.as_ref(),
+                    encrypted,
+                    checkpoint.step,
+                    checkpoint.recorded_at,
+                ],
+            )
+        })?;
+        Ok(())
+    }
+
+    async fn checkpoint(
+        &self,
+        idempotence_key: &TransactionIdempotenceKey,
+    ) -> Result<Option<Checkpoint>, Error> {
+        let row = self.with(|connection| {
+            connection
+                .query_row(
+                    "SELECT transaction_id, step, recorded_at \
+                     FROM checkpoints WHERE idempotence_key = ?1",
+                    params![idempotence_key.as_ref()],
+                    |row| {
+                        Ok((
+                            row.get::<_, Option<Vec<u8>>>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, i64>(2)?,
+                        ))
+                    },
+                )
+                .optional()
+        })?;
+        let Some((transaction_id, step, recorded_at)) = row else {
+            return Ok(None);
+        };
+
+        let transaction_id = transaction_id
+            .map(|id| self.cipher.decrypt(&id))
+            .transpose()?;
+        inputs::Checkpoint {
+            idempotence_key: idempotence_key.as_ref(),
+            transaction_id: transaction_id.as_ref().map(|id| id.as_str()),
+            step: &step,
+            recorded_at,
+        }
+        .try_into()
+        .map(Some)
+    }
+}
+
+// --- Rows ---
+
+const TRANSACTION_COLUMNS: &str = "transaction_id, idempotence_key, status, decline_reason, \
+    currency, recipients, merchant_initiated_type, network_transaction_id";
+
+struct TransactionRow {
+    transaction_id: Vec<u8>,
+    idempotence_key: String,
+    status: String,
+    decline_reason: Option<String>,
+    currency: String,
+    recipients: Option<String>,
+    merchant_initiated_type: Option<String>,
+    network_transaction_id: Option<Vec<u8>>,
+}
+
+impl TransactionRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            transaction_id: row.get(0)?,
+            idempotence_key: row.get(1)?,
+            status: row.get(2)?,
+            decline_reason: row.get(3)?,
+            currency: row.get(4)?,
+            recipients: row.get(5)?,
+            merchant_initiated_type: row.get(6)?,
+            network_transaction_id: row.get(7)?,
+        })
+    }
+}
+
+struct SubscriptionRow {
+    subscription_id: Vec<u8>,
+    status: String,
+    interval: String,
+    currency: String,
+    recipients: Option<String>,
+    created_at: i64,
+    next_billing_date: Option<i64>,
+}
+
+impl SubscriptionRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            subscription_id: row.get(0)?,
+            status: row.get(1)?,
+            interval: row.get(2)?,
+            currency: row.get(3)?,
+            recipients: row.get(4)?,
+            created_at: row.get(5)?,
+            next_billing_date: row.get(6)?,
+        })
+    }
+}
+
+// --- Encoding ---
+
+fn storage(error: rusqlite::Error) -> Error {
+    Error::Storage(error.to_string())
+}
+
+fn corrupted(value: &str) -> Error {
+    Error::Storage(format!("unexpected stored value `{value}`"))
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, Error> {
+    value.parse().map_err(|_| corrupted(value))
+}
+
+fn parse_currency(value: &str) -> Result<Currency, Error> {
+    Currency::from_code(value).ok_or_else(|| corrupted(value))
+}
+
+fn encode_interval(interval: &SubscriptionInterval) -> String {
+    match interval {
+        SubscriptionInterval::Day(count) => format!("Day:{count}"),
+        SubscriptionInterval::Week(count) => format!("Week:{count}"),
+        SubscriptionInterval::Month(count) => format!("Month:{count}"),
+        SubscriptionInterval::Year(count) => format!("Year:{count}"),
+    }
+}
+
+fn decode_interval(value: &str) -> Result<inputs::SubscriptionInterval, Error> {
+    let (unit, count) = value.split_once(':').ok_or_else(|| corrupted(value))?;
+    let count = count.parse().map_err(|_| corrupted(value))?;
+    match unit {
+        "Day" => Ok(inputs::SubscriptionInterval::Day(count)),
+        "Week" => Ok(inputs::SubscriptionInterval::Week(count)),
+        "Month" => Ok(inputs::SubscriptionInterval::Month(count)),
+        "Year" => Ok(inputs::SubscriptionInterval::Year(count)),
+        _ => Err(corrupted(value)),
+    }
+}
+
+/// Encode recipients as lines of tab-separated ID, kind and value.
+///
+/// Recipient IDs are sanitized from control characters, so they can't break the format.
+fn encode_recipients(recipients: &Recipients) -> String {
+    let mut lines: Vec<String> = recipients
+        .iter()
+        .map(|(id, value)| match value {
+            DistributedValue::Amount(amount) => format!("{}\tAmount\t{amount}", id.as_ref()),
+            DistributedValue::Percent(percent) => format!("{}\tPercent\t{percent}", id.as_ref()),
+        })
+        .collect();
+    lines.sort();
+    lines.join("\n")
+}
+
+fn decode_recipients(value: &str) -> Result<Vec<(String, inputs::DistributedValue)>, Error> {
+    value
+        .lines()
+        .map(|line| {
+            let mut parts = line.split('\t');
+            let (Some(id), Some(kind), Some(number), None) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                return Err(corrupted(line));
+            };
+            let number = Decimal::from_str(number).map_err(|_| corrupted(line))?;
+            let value = match kind {
+                "Amount" => inputs::DistributedValue::Amount(number),
+                "Percent" => inputs::DistributedValue::Percent(number),
+                _ => return Err(corrupted(line)),
+            };
+            Ok((id.to_string(), value))
+        })
+        .collect()
+}
+
+fn borrow_recipients(recipients: &[(String, inputs::DistributedValue)]) -> inputs::Recipients<'_> {
+    recipients
+        .iter()
+        .map(|(id, value)| (id.as_str(), *value))
+        .collect::<HashMap<_, _>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeclineReason, MerchantInitiatedType, SubscriptionStatus, TransactionStatus};
+    use futures::executor::block_on;
+    use rust_decimal_macros::dec;
+    use zeroize::Zeroizing;
+
+    /// Reversible cipher for tests only (never use it in production).
+    struct ReversedCipher;
+
+    impl Cipher for ReversedCipher {
+        fn encrypt(&self, plaintext: &str) -> Result<Vec<u8>, Error> {
+            Ok(plaintext.bytes().rev().collect())
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> Result<Zeroizing<String>, Error> {
+            String::from_utf8(ciphertext.iter().rev().copied().collect())
+                .map(Zeroizing::new)
+                .map_err(|e| Error::Storage(e.to_string()))
+        }
+
+        fn fingerprint(&self, plaintext: &str) -> Vec<u8> {
+            plaintext.bytes().map(|b| b ^ 0x5a).collect()
+        }
+    }
+
+    fn repository() -> SqliteRepository<ReversedCipher> {
+        SqliteRepository::open_in_memory(ReversedCipher).unwrap()
+    }
+
+    fn transaction(status: TransactionStatus) -> Transaction {
+        let mut recipients = HashMap::new();
+        recipients.insert("seller_1", inputs::DistributedValue::Amount(dec!(10.50)));
+        recipients.insert("seller_2", inputs::DistributedValue::Percent(dec!(5)));
+
+        inputs::Transaction {
+            transaction_id: "txn_12345678",
+            idempotence_key: "order-42",
+            status,
+            decline_reason: (status == TransactionStatus::Declined)
+                .then_some(DeclineReason::InsufficientFunds),
+            currency: Currency::EUR,
+            recipients: Some(recipients),
+            merchant_initiated_type: Some(MerchantInitiatedType::Recurring),
+            network_transaction_id: Some("583112345678901"),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn stores_transactions() {
+        let repository = repository();
+        let saved = transaction(TransactionStatus::Declined);
+        block_on(repository.save_transaction(&saved)).unwrap();
+
+        let found = block_on(repository.transaction(&saved.transaction_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.transaction_id, saved.transaction_id);
+        assert_eq!(found.status, TransactionStatus::Declined);
+        assert_eq!(found.decline_reason, Some(DeclineReason::InsufficientFunds));
+        assert_eq!(found.currency, Currency::EUR);
+        assert_eq!(found.recipients.unwrap().len(), 2);
+        assert_eq!(found.network_transaction_id, saved.network_transaction_id);
+        assert_eq!(
+            found.merchant_initiated_type,
+            Some(MerchantInitiatedType::Recurring)
+        );
+    }
+
+    #[test]
+    fn replaces_transactions() {
+        let repository = repository();
+        block_on(repository.save_transaction(&transaction(TransactionStatus::Authorized))).unwrap();
+        block_on(repository.save_transaction(&transaction(TransactionStatus::Captured))).unwrap();
+
+        let key = "order-42".try_into().unwrap();
+        let found = block_on(repository.transactions(&key)).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].status, TransactionStatus::Captured);
+    }
+
+    #[test]
+    fn does_not_store_ids_in_plain_text() {
+        let repository = repository();
+        block_on(repository.save_transaction(&transaction(TransactionStatus::Captured))).unwrap();
+
+        let stored: Vec<u8> = repository
+            .with(|c| c.query_row("SELECT transaction_id FROM transactions", [], |r| r.get(0)))
+            .unwrap();
+        assert_ne!(stored, b"txn_12345678");
+    }
+
+    #[test]
+    fn stores_subscriptions() {
+        let repository = repository();
+        let saved: Subscription = inputs::Subscription {
+            subscription_id: "sub_12345678",
+            status: SubscriptionStatus::Active,
+            interval: inputs::SubscriptionInterval::Month(1),
+            currency: Currency::USD,
+            recipients: None,
+            created_at: 1_700_000_000,
+            next_billing_date: Some(1_702_592_000),
+        }
+        .try_into()
+        .unwrap();
+        block_on(repository.save_subscription(&saved)).unwrap();
+
+        let found = block_on(repository.subscription(&saved.subscription_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.status, SubscriptionStatus::Active);
+        assert_eq!(found.interval, SubscriptionInterval::Month(1));
+        assert_eq!(found.next_billing_date, Some(1_702_592_000));
+    }
+
+    #[test]
+    fn stores_checkpoints() {
+        let repository = repository();
+        let saved: Checkpoint = inputs::Checkpoint {
+            idempotence_key: "order-42",
+            transaction_id: Some("txn_12345678"),
+            step: "charged",
+            recorded_at: 1_700_000_000,
+        }
+        .try_into()
+        .unwrap();
+        block_on(repository.save_checkpoint(&saved)).unwrap();
+
+        let found = block_on(repository.checkpoint(&saved.idempotence_key))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.step(), "charged");
+        assert_eq!(found.transaction_id, saved.transaction_id);
+    }
+
+    #[test]
+    fn returns_none_for_unknown_records() {
+        let repository = repository();
+        let key = "order-43".try_into().unwrap();
+
+        assert!(block_on(repository.checkpoint(&key)).unwrap().is_none());
+        assert!(block_on(repository.transactions(&key)).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+//! Signed return URLs for redirect flows.
+//!
+//! When the customer is redirected to an external page (3DS challenge, bank login,
+//! wallet approval), the gateway sends them back to the merchant's return URL.
+//! The [`ReturnUrlSigner`] appends a signed `state` parameter to the return URL,
+//! so that the callback can be matched to the payment and the step of the orchestration
+//! that initiated the redirect, and can't be forged or replayed after it expired.
+//!
+//! ```skip
+//! let signer = ReturnUrlSigner::new(secret).with_confirmation_param("PaRes");
+//!
+//! // Before the redirect
+//! let return_url = signer.build(&base_url, "3ds", &idempotence_key, now)?;
+//!
+//! // In the callback handler
+//! let callback = signer.parse(request.query(), now)?;
+//! let checkpoint = repository.checkpoint(callback.idempotence_key()).await?;
+//! ```
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::Error;
+use crate::internal::{hex, unhex};
+use crate::types::{Confirmation, TransactionIdempotenceKey, Url};
+
+/// The name of the query parameter carrying the signed state.
+const STATE_PARAM: &str = "state";
+
+/// Builder and parser of return URLs with the signed state.
+///
+/// The state is `<signature>.<step>.<issued_at>.<idempotence_key>`,
+/// where the signature is the hex-encoded HMAC-SHA256 of the rest of the state.
+///
+/// # Data Protection
+/// The signing key is zeroized on drop and never shown in logs.
+#[derive(ZeroizeOnDrop)]
+pub struct ReturnUrlSigner {
+    key: Vec<u8>,
+    #[zeroize(skip)]
+    confirmation_param: &'static str,
+    #[zeroize(skip)]
+    max_age: Duration,
+}
+
+impl ReturnUrlSigner {
+    /// Build the signer with the secret key.
+    ///
+    /// By default, the confirmation is taken from the `confirmation` query parameter,
+    /// and the state expires in 1 hour.
+    pub fn new(key: &[u8]) -> Self {
+        Self {
+            key: key.to_vec(),
+            confirmation_param: "confirmation",
+            max_age: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Take the confirmation from another query parameter (like `PaRes` or `token`).
+    pub fn with_confirmation_param(mut self, name: &'static str) -> Self {
+        self.confirmation_param = name;
+        self
+    }
+
+    /// Reject states older than the duration.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Add the signed state to the base return URL.
+    ///
+    /// # Validation
+    /// * step: 1-64 characters, ASCII alphanumeric characters, dashes and underscores only,
+    /// * base URL: must not contain the `state` parameter already.
+    pub fn build(
+        &self,
+        base: &Url,
+        step: &str,
+        idempotence_key: &TransactionIdempotenceKey,
+        now: i64,
+    ) -> Result<Url, Error> {
+        let valid_step = !step.is_empty()
+            && step.len() <= 64
+            && step
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_step {
+            return Err(Error::InvalidInput(format!("invalid step {step:?}")));
+        }
+
+        let mut url = ::url::Url::parse(base.as_str())
+            .map_err(|e| Error::InvalidInput(format!("invalid return URL: {e}")))?;
+        if url.query_pairs().any(|(name, _)| name == STATE_PARAM) {
+            return Err(Error::InvalidInput(
+                "return URL already contains the state".into(),
+            ));
+        }
+
+        let payload = format!("{step}.{now}.{}", idempotence_key.as_ref());
+        let state = format!("{}.{payload}", hex(&self.sign(&payload)));
+        url.query_pairs_mut().append_pair(STATE_PARAM, &state);
+        url.as_str().try_into()
+    }
+
+    /// Verify the state in the query of the callback, and extract the confirmation.
+    ///
+    /// The query can be given with or without the leading `?`.
+    pub fn parse(&self, query: &str, now: i64) -> Result<ReturnCallback, Error> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut state = None;
+        let mut confirmation = None;
+        for (name, value) in ::url::form_urlencoded::parse(query.as_bytes()) {
+            if name == STATE_PARAM {
+                state = Some(value.into_owned());
+            } else if name == self.confirmation_param {
+                confirmation = Some(value.into_owned());
+            }
+        }
+
+        let state = state.ok_or_else(|| invalid_state("is missing"))?;
+        let (signature, payload) = state
+            .split_once('.')
+            .ok_or_else(|| invalid_state("is malformed"))?;
+        let signature = unhex(signature).ok_or_else(|| invalid_state("is malformed"))?;
+        self.mac(payload)
+            .verify_slice(&signature)
+            .map_err(|_| invalid_state("has invalid signature"))?;
+
+        let mut parts = payload.splitn(3, '.');
+        let (Some(step), Some(issued_at), Some(idempotence_key)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid_state("is malformed"));
+        };
+        let issued_at: i64 = issued_at
+            .parse()
+            .map_err(|_| invalid_state("is malformed"))?;
+        let max_age = i64::try_from(self.max_age.as_secs()).unwrap_or(i64::MAX);
+        if now < issued_at || now - issued_at > max_age {
+            return Err(invalid_state("has expired"));
+        }
+
+        Ok(ReturnCallback {
+            step: step.to_string(),
+            idempotence_key: idempotence_key.try_into()?,
+            confirmation: confirmation
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| value.as_str().try_into())
+                .transpose()?,
+        })
+    }
+
+    fn mac(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.mac(payload).finalize().into_bytes().to_vec()
+    }
+}
+
+/// The verified data of the customer's return from an external page.
+#[derive(Clone, Debug)]
+pub struct ReturnCallback {
+    step: String,
+    idempotence_key: TransactionIdempotenceKey,
+    confirmation: Option<Confirmation>,
+}
+
+impl ReturnCallback {
+    /// The step of the orchestration that initiated the redirect.
+    #[inline]
+    pub fn step(&self) -> &str {
+        &self.step
+    }
+
+    /// The idempotency key of the payment.
+    #[inline]
+    pub fn idempotence_key(&self) -> &TransactionIdempotenceKey {
+        &self.idempotence_key
+    }
+
+    /// The confirmation returned by the external page (if any).
+    #[inline]
+    pub fn confirmation(&self) -> Option<&Confirmation> {
+        self.confirmation.as_ref()
+    }
+}
+
+fn invalid_state(reason: &str) -> Error {
+    Error::InvalidInput(format!("the state of the return URL {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn signer() -> ReturnUrlSigner {
+        ReturnUrlSigner::new(b"secret").with_confirmation_param("PaRes")
+    }
+
+    fn base() -> Url {
+        "https://merchant.example.com/callback?lang=en"
+            .try_into()
+            .unwrap()
+    }
+
+    fn key() -> TransactionIdempotenceKey {
+        "order.42".try_into().unwrap()
+    }
+
+    fn query(url: &Url) -> String {
+        url.as_str().split_once('?').unwrap().1.to_string()
+    }
+
+    #[test]
+    fn builds_and_parses_return_url() {
+        let url = signer().build(&base(), "3ds", &key(), NOW).unwrap();
+        assert!(url.as_str().starts_with(base().as_str()));
+
+        let query = format!("?{}&PaRes=eJzVWFmTqkgW", query(&url));
+        let callback = signer().parse(&query, NOW + 60).unwrap();
+
+        assert_eq!(callback.step(), "3ds");
+        assert_eq!(callback.idempotence_key().as_ref(), "order.42");
+        unsafe {
+            assert_eq!(callback.confirmation().unwrap().as_ref(), "eJzVWFmTqkgW");
+        }
+    }
+
+    #[test]
+    fn accepts_missing_confirmation() {
+        let url = signer().build(&base(), "bank-login", &key(), NOW).unwrap();
+
+        let callback = signer().parse(&query(&url), NOW).unwrap();
+        assert!(callback.confirmation().is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_step() {
+        let result = signer().build(&base(), "3ds.retry", &key(), NOW);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_tampered_state() {
+        let url = signer().build(&base(), "3ds", &key(), NOW).unwrap();
+        let query = query(&url).replace("order.42", "order.43");
+
+        let result = signer().parse(&query, NOW);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_state_signed_with_another_key() {
+        let url = ReturnUrlSigner::new(b"another")
+            .build(&base(), "3ds", &key(), NOW)
+            .unwrap();
+
+        let result = signer().parse(&query(&url), NOW);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_expired_state() {
+        let signer = signer().with_max_age(Duration::from_secs(60));
+        let url = signer.build(&base(), "3ds", &key(), NOW).unwrap();
+
+        assert!(signer.parse(&query(&url), NOW + 60).is_ok());
+        let result = signer.parse(&query(&url), NOW + 61);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_missing_state() {
+        let result = signer().parse("PaRes=eJzVWFmTqkgW", NOW);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,264 @@
+//! Billing schedules of recurring subscriptions.
+//!
+//! The [`BillingSchedule`] computes the billing dates of a subscription from its anchor
+//! (the date of the first billing) and the [`SubscriptionInterval`], so that the upcoming
+//! charges can be shown to the customer before calling `RecurrentPayments::create_subscription`:
+//!
+//! ```skip
+//! let schedule = BillingSchedule::new(anchor, SubscriptionInterval::Month(1)).with_cycles(12);
+//!
+//! for timestamp in schedule.upcoming(&SystemClock, &customer_time_zone, 3) {
+//!     println!("You will be charged on {timestamp}");
+//! }
+//! ```
+//!
+//! Every billing date is computed from the anchor (not from the previous date),
+//! so that the dates clamped to the end of shorter months return to the anchor day:
+//! Jan 31 → Feb 28 → Mar 31 → Apr 30.
+
+use crate::calendar::BankingCalendar;
+use crate::clock::{Clock, TimeZone};
+use crate::types::{Date, SubscriptionInterval};
+
+/// The limit of the billing schedule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScheduleEnd {
+    /// No billings after the date (inclusive).
+    Date(Date),
+    /// The total number of billings (including the first one).
+    Cycles(u32),
+}
+
+/// Deterministic schedule of billing dates.
+///
+/// The dates are calendar dates in the customer's time zone.
+/// Without the end, the schedule runs until the year 2199.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BillingSchedule {
+    anchor: Date,
+    interval: SubscriptionInterval,
+    end: Option<ScheduleEnd>,
+}
+
+impl BillingSchedule {
+    /// Build the endless schedule starting from the anchor date.
+    pub fn new(anchor: Date, interval: SubscriptionInterval) -> Self {
+        Self {
+            anchor,
+            interval,
+            end: None,
+        }
+    }
+
+    /// Stop billing after the date (inclusive).
+    pub fn until(mut self, date: Date) -> Self {
+        self.end = Some(ScheduleEnd::Date(date));
+        self
+    }
+
+    /// Stop billing after the number of cycles (including the first one).
+    pub fn with_cycles(mut self, count: u32) -> Self {
+        self.end = Some(ScheduleEnd::Cycles(count));
+        self
+    }
+
+    /// The date of the first billing.
+    #[inline]
+    pub fn anchor(&self) -> Date {
+        self.anchor
+    }
+
+    /// The billing interval.
+    #[inline]
+    pub fn interval(&self) -> SubscriptionInterval {
+        self.interval
+    }
+
+    /// The limit of the schedule (None for endless schedules).
+    #[inline]
+    pub fn end(&self) -> Option<ScheduleEnd> {
+        self.end
+    }
+
+    /// The date of the billing cycle (0 for the anchor), or None after the end of the schedule.
+    pub fn billing_date(&self, cycle: u32) -> Option<Date> {
+        if let Some(ScheduleEnd::Cycles(count)) = self.end
+            && cycle >= count
+        {
+            return None;
+        }
+
+        let date = self.interval.checked_shift(self.anchor, i64::from(cycle))?;
+        match self.end {
+            Some(ScheduleEnd::Date(end)) if date > end => None,
+            _ => Some(date),
+        }
+    }
+
+    /// The first billing dates of the schedule (up to the count).
+    pub fn dates(&self, count: usize) -> Vec<Date> {
+        (0..)
+            .map_while(|cycle| self.billing_date(cycle))
+            .take(count)
+            .collect()
+    }
+
+    /// The first collection dates of the schedule (up to the count), moved to the business days
+    /// of the calendar (for direct debits and bank transfers settling on business days only).
+    ///
+    /// The dates stop at the billing without a business day up to the last supported date.
+    pub fn collection_dates(&self, calendar: &dyn BankingCalendar, count: usize) -> Vec<Date> {
+        self.dates(count)
+            .into_iter()
+            .map_while(|date| calendar.next_business_day(date))
+            .collect()
+    }
+
+    /// The billing dates after the date (exclusive, up to the count).
+    pub fn dates_after(&self, date: Date, count: usize) -> Vec<Date> {
+        (0..)
+            .map_while(|cycle| self.billing_date(cycle))
+            .skip_while(|billing_date| *billing_date <= date)
+            .take(count)
+            .collect()
+    }
+
+    /// The moments (Unix timestamps of the local midnights) of the upcoming billings
+    /// (starting from today in the time zone, up to the count).
+    pub fn upcoming(&self, clock: &dyn Clock, time_zone: &dyn TimeZone, count: usize) -> Vec<i64> {
+        let today = time_zone.date_at(clock.now());
+        (0..)
+            .map_while(|cycle| self.billing_date(cycle))
+            .skip_while(|billing_date| *billing_date < today)
+            .take(count)
+            .map(|billing_date| time_zone.midnight(billing_date))
+            .collect()
+    }
+
+    /// The moment (Unix timestamp of the local midnight) of the next billing
+    /// (today or later in the time zone).
+    pub fn next_billing(&self, clock: &dyn Clock, time_zone: &dyn TimeZone) -> Option<i64> {
+        self.upcoming(clock, time_zone, 1).pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{FixedClock, FixedOffset};
+    use crate::test_support::date;
+
+    #[test]
+    fn clamps_monthly_dates_to_month_end() {
+        let schedule = BillingSchedule::new(date(2024, 1, 31), SubscriptionInterval::Month(1));
+
+        assert_eq!(
+            schedule.dates(4),
+            [
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                date(2024, 4, 30)
+            ]
+        );
+    }
+
+    #[test]
+    fn moves_collection_dates_to_business_days() {
+        let schedule = BillingSchedule::new(date(2025, 3, 1), SubscriptionInterval::Month(1));
+
+        assert_eq!(
+            schedule.collection_dates(&crate::calendar::Target2, 3),
+            [date(2025, 3, 3), date(2025, 4, 1), date(2025, 5, 2)]
+        );
+    }
+
+    #[test]
+    fn computes_weekly_and_yearly_dates() {
+        let weekly = BillingSchedule::new(date(2024, 12, 24), SubscriptionInterval::Week(1));
+        assert_eq!(
+            weekly.dates(3),
+            [date(2024, 12, 24), date(2024, 12, 31), date(2025, 1, 7)]
+        );
+
+        let yearly = BillingSchedule::new(date(2024, 2, 29), SubscriptionInterval::Year(1));
+        assert_eq!(
+            yearly.dates(5)[1..],
+            [
+                date(2025, 2, 28),
+                date(2026, 2, 28),
+                date(2027, 2, 28),
+                date(2028, 2, 29)
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_after_cycles() {
+        let schedule =
+            BillingSchedule::new(date(2025, 1, 1), SubscriptionInterval::Month(3)).with_cycles(2);
+
+        assert_eq!(schedule.dates(10), [date(2025, 1, 1), date(2025, 4, 1)]);
+        assert_eq!(schedule.billing_date(2), None);
+    }
+
+    #[test]
+    fn stops_after_end_date() {
+        let schedule = BillingSchedule::new(date(2025, 1, 1), SubscriptionInterval::Day(10))
+            .until(date(2025, 1, 21));
+
+        assert_eq!(
+            schedule.dates(10),
+            [date(2025, 1, 1), date(2025, 1, 11), date(2025, 1, 21)]
+        );
+    }
+
+    #[test]
+    fn stops_at_last_supported_year() {
+        let schedule = BillingSchedule::new(date(2199, 6, 1), SubscriptionInterval::Year(1));
+
+        assert_eq!(schedule.dates(10), [date(2199, 6, 1)]);
+    }
+
+    #[test]
+    fn stops_on_overflowing_cycles() {
+        let daily = BillingSchedule::new(date(2025, 1, 1), SubscriptionInterval::Day(u32::MAX));
+        assert_eq!(daily.billing_date(u32::MAX), None);
+
+        let weekly = BillingSchedule::new(date(2199, 12, 25), SubscriptionInterval::Week(1));
+        assert_eq!(weekly.dates(10), [date(2199, 12, 25)]);
+
+        let yearly = BillingSchedule::new(date(2025, 1, 1), SubscriptionInterval::Year(u32::MAX));
+        assert_eq!(yearly.billing_date(u32::MAX), None);
+    }
+
+    #[test]
+    fn finds_dates_after_date() {
+        let schedule = BillingSchedule::new(date(2025, 1, 15), SubscriptionInterval::Month(1));
+
+        assert_eq!(
+            schedule.dates_after(date(2025, 3, 15), 2),
+            [date(2025, 4, 15), date(2025, 5, 15)]
+        );
+    }
+
+    #[test]
+    fn finds_upcoming_billings_in_time_zone() {
+        let schedule = BillingSchedule::new(date(2025, 1, 15), SubscriptionInterval::Month(1));
+        let tokyo = FixedOffset(9 * 3600);
+        // 2025-02-14 20:00 UTC is 2025-02-15 05:00 in Tokyo
+        let clock = FixedClock(date(2025, 2, 14).timestamp() + 20 * 3600);
+
+        assert_eq!(
+            schedule.upcoming(&clock, &tokyo, 2),
+            [
+                date(2025, 2, 15).timestamp() - 9 * 3600,
+                date(2025, 3, 15).timestamp() - 9 * 3600
+            ]
+        );
+        assert_eq!(
+            schedule.next_billing(&clock, &FixedOffset(0)),
+            Some(date(2025, 2, 15).timestamp())
+        );
+    }
+}
@@ -0,0 +1,1595 @@
+//! Subscriptions managed by the merchant on top of stored credentials.
+//!
+//! Gateways without native subscriptions can still bill customers on a schedule,
+//! when they store payment methods in a vault ([`StoreCredentials`]) and charge them
+//! without the customer ([`ImmediatePayments`]). The [`ManagedSubscriptions`] wrapper
+//! implements the subscription flows ([`RecurrentPayments`], [`PauseSubscriptions`],
+//! [`EditSubscriptionAmount`], [`EditSubscriptionInterval`] and [`EditSubscriptionRecipients`])
+//! for such gateways, keeps the subscriptions in the pluggable [`SubscriptionStore`],
+//! and charges the renewals when they are due, so that switching gateways
+//! doesn't lose subscription features:
+//!
+//! ```skip
+//! let gateway = ManagedSubscriptions::new(AdyenGateway::new(config), InMemorySubscriptionStore::default())
+//!     .with_dunning(DunningPolicy::default());
+//!
+//! let subscription = gateway.create_subscription(payment_with_vault_token, terms).await?;
+//!
+//! // Run periodically (like every hour)
+//! for (subscription_id, outcome) in gateway.process_due().await? {
+//!     notify_customer(subscription_id, outcome?.events());
+//! }
+//! ```
+//!
+//! The zero amount verifying the credential is charged first as the customer-initiated
+//! transaction storing it, so that subscriptions are refused before charging the customer
+//! when the gateway doesn't return its network transaction ID. The setup fee, the first
+//! billing (when there's no trial) and the renewals reference it as
+//! `StoredCredentialUsage::Subsequent`.
+//! Failed renewals are retried according to the [`DunningPolicy`].
+//! Proration credits which can't be refunded by the wrapper are carried
+//! to the next billing as the balance of the subscription.
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sha2::{Digest, Sha256};
+
+use crate::clock::{Clock, FixedOffset, SystemClock, TimeZone};
+use crate::dunning::{Dunning, DunningOutcome, DunningPolicy, DunningState};
+use crate::flows::{
+    EditSubscriptionAmount, EditSubscriptionInterval, EditSubscriptionRecipients,
+    ImmediatePayments, PauseSubscriptions, RecurrentPayments, StoreCredentials,
+};
+use crate::gateway::{authorize, secure};
+use crate::internal::hex;
+use crate::schedule::BillingSchedule;
+use crate::types::payments::PaymentMarker;
+use crate::types::{
+    AccountUpdate, Date, InternalPaymentMethod, NetworkTransactionId, NoInstallments,
+    ProrationPreview, Recipients, StoredCredentialUsage, Subscription, SubscriptionId,
+    SubscriptionInterval, SubscriptionTerms, Transaction, TransactionId, TransactionIdempotenceKey,
+    VaultPaymentMethod,
+};
+use crate::{
+    AsUnsafeRef, Error, Gateway, MerchantInitiatedType, ProrationPolicy, SubscriptionStatus,
+    TransactionStatus,
+};
+
+mod store;
+
+pub use store::{InMemorySubscriptionStore, SubscriptionStore};
+
+/// The state of a subscription managed by the merchant.
+///
+/// Persistent stores keep the fields as is, and rebuild the payment
+/// from the stored vault token.
+#[derive(Clone, Debug)]
+pub struct ManagedSubscription<P> {
+    /// The unique subscription ID (derived from the idempotence key of the creating payment).
+    pub subscription_id: SubscriptionId,
+    /// The current status of the subscription.
+    pub status: SubscriptionStatus,
+    /// The payment charged on every billing (with the vault token, the amount and the recipients).
+    pub payment: P,
+    /// The terms of the subscription (with the current interval).
+    pub terms: SubscriptionTerms,
+    /// Subscription creation timestamp (Unix timestamp).
+    pub created_at: i64,
+    /// The date of the first billing of the current interval.
+    pub anchor: Date,
+    /// The number of billings made since the anchor.
+    pub cycle: u32,
+    /// The moment of the next billing (Unix timestamp, None if the subscription is over).
+    pub next_billing_at: Option<i64>,
+    /// The network transaction ID of the initial customer-initiated transaction
+    /// referenced by the renewals (subscriptions without it are not charged
+    /// by the merchant, failing with `Error::NotSupported`).
+    pub initial_network_transaction_id: Option<NetworkTransactionId>,
+    /// The number of renewal charges attempted since the creation
+    /// (never reset, so that idempotence keys of the charges are not reused).
+    pub attempts: u32,
+    /// The progress of the dunning of the failed renewal.
+    pub dunning: Option<DunningState>,
+    /// The ID of the renewal transaction awaiting settlement (pending or processing),
+    /// see [`ManagedSubscriptions::renewal_settled`].
+    pub pending_transaction_id: Option<TransactionId>,
+    /// The prorated amount added to the next billing (negative for credits).
+    pub balance: Decimal,
+}
+
+impl<P> ManagedSubscription<P> {
+    /// Whether the subscription is due for billing, a retry or a dunning timeout
+    /// at the moment (Unix timestamp).
+    ///
+    /// Subscriptions with a renewal awaiting settlement are never due.
+    pub fn is_due(&self, now: i64) -> bool {
+        if self.pending_transaction_id.is_some() {
+            return false;
+        }
+        match (self.status, &self.dunning) {
+            (SubscriptionStatus::Active, _) => self.next_billing_at.is_some_and(|at| at <= now),
+            (SubscriptionStatus::PastDue, Some(state)) => {
+                state.next_retry_at.is_none_or(|at| at <= now)
+            }
+            _ => false,
+        }
+    }
+
+    /// The current billing period [start, end) from the previous billing date
+    /// of the schedule to the next one (None if the subscription has no next billing).
+    pub fn current_period(&self) -> Option<Range<Date>> {
+        self.next_billing_at?;
+        let cycle = i64::from(self.cycle);
+        let interval = self.terms.interval;
+        let start = interval.checked_shift(self.anchor, cycle - 1)?;
+        let end = interval.checked_shift(self.anchor, cycle)?;
+        Some(start..end)
+    }
+}
+
+#[allow(private_bounds)]
+impl<P: PaymentMarker> ManagedSubscription<P> {
+    /// The canonical subscription record.
+    pub fn subscription(&self) -> Subscription {
+        Subscription {
+            subscription_id: self.subscription_id.clone(),
+            status: self.status,
+            interval: self.terms.interval,
+            currency: self.payment.currency(),
+            recipients: self.payment.recipients().cloned(),
+            created_at: self.created_at,
+            next_billing_date: self.next_billing_at,
+        }
+    }
+}
+
+/// Gateway wrapper managing subscriptions on the merchant's side.
+///
+/// Delegates the pipeline, `ImmediatePayments` and `StoreCredentials` to the wrapped gateway.
+/// Billing dates are computed in the time zone (UTC by default).
+pub struct ManagedSubscriptions<G, S> {
+    inner: G,
+    store: S,
+    dunning: DunningPolicy,
+    clock: Box<dyn Clock>,
+    time_zone: Box<dyn TimeZone>,
+}
+
+impl<G: Gateway, S> ManagedSubscriptions<G, S> {
+    /// Wraps the gateway adapter with the store of subscriptions.
+    pub fn new(inner: G, store: S) -> Self {
+        Self {
+            inner,
+            store,
+            dunning: DunningPolicy::default(),
+            clock: Box::new(SystemClock),
+            time_zone: Box::new(FixedOffset(0)),
+        }
+    }
+
+    /// Retry failed renewals according to the policy.
+    pub fn with_dunning(mut self, policy: DunningPolicy) -> Self {
+        self.dunning = policy;
+        self
+    }
+
+    /// Take the current time from the clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Compute billing dates in the time zone.
+    pub fn with_time_zone(mut self, time_zone: impl TimeZone + 'static) -> Self {
+        self.time_zone = Box::new(time_zone);
+        self
+    }
+
+    /// The wrapped gateway adapter.
+    #[inline]
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// The store of subscriptions.
+    #[inline]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The policy of retrying failed renewals.
+    #[inline]
+    pub fn dunning(&self) -> &DunningPolicy {
+        &self.dunning
+    }
+}
+
+#[allow(private_bounds)]
+impl<G, S> ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments>,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    /// Charge the renewal of the subscription (or its retry), or exhaust the dunning
+    /// waiting for the credential update after the grace period.
+    ///
+    /// Pending renewals keep the subscription awaiting settlement (not due, with the cycle
+    /// and the balance unchanged) until [`renewal_settled`](Self::renewal_settled).
+    ///
+    /// Returns `Error::InvalidInput` for unknown subscriptions and subscriptions not due.
+    pub async fn renew(&self, subscription_id: &SubscriptionId) -> Result<DunningOutcome, Error> {
+        let mut record = self.find(subscription_id).await?;
+        let now = self.clock.now();
+        if !record.is_due(now) {
+            return Err(Error::InvalidInput(format!(
+                "Subscription {subscription_id:?} is not due"
+            )));
+        }
+
+        if let Some(state) = record.dunning
+            && state.next_retry_at.is_none()
+        {
+            return match self.dunning.on_timeout(state, now) {
+                Some(outcome) => {
+                    self.apply(&mut record, &outcome);
+                    self.store.save(&record).await?;
+                    Ok(outcome)
+                }
+                None => Err(Error::InvalidInput(format!(
+                    "Subscription {subscription_id:?} waits for the credential update"
+                ))),
+            };
+        }
+
+        let amount = record.payment.total_amount() + record.balance;
+        let outcome = if amount > Decimal::ZERO {
+            let attempt = record.attempts + 1;
+            let key = idempotence_key(subscription_id, &format!("{}-{attempt}", record.cycle))?;
+            let payment = record.payment.clone().with_amount(amount, key)?;
+            let initial_network_transaction_id = initial_network_transaction_id(&record)?;
+            let outcome = Dunning::new(&self.inner, &self.dunning, self.clock.as_ref())
+                .renew(
+                    payment,
+                    NoInstallments,
+                    initial_network_transaction_id,
+                    record.dunning,
+                )
+                .await?;
+            record.attempts = attempt;
+            outcome
+        } else {
+            self.dunning.on_success(record.dunning)
+        };
+
+        if outcome.is_pending() {
+            record.pending_transaction_id = outcome
+                .transaction()
+                .map(|transaction| transaction.transaction_id().clone());
+            self.store.save(&record).await?;
+            return Ok(outcome);
+        }
+        if outcome.status() == SubscriptionStatus::Active {
+            record.balance = amount.min(Decimal::ZERO);
+        }
+        self.apply(&mut record, &outcome);
+        self.store.save(&record).await?;
+        Ok(outcome)
+    }
+
+    /// Apply the final status of the renewal awaiting settlement to the subscription
+    /// (the transaction re-fetched from the gateway or received with its webhook).
+    ///
+    /// Returns `Error::InvalidInput` for unknown subscriptions, transactions other than
+    /// the pending renewal of the subscription and transactions still pending.
+    pub async fn renewal_settled(
+        &self,
+        subscription_id: &SubscriptionId,
+        transaction: &Transaction,
+    ) -> Result<DunningOutcome, Error> {
+        let mut record = self.find(subscription_id).await?;
+        if record.pending_transaction_id.as_ref() != Some(transaction.transaction_id()) {
+            return Err(Error::InvalidInput(format!(
+                "Transaction {:?} is not the pending renewal of subscription {subscription_id:?}",
+                transaction.transaction_id()
+            )));
+        }
+        let Some(outcome) = self
+            .dunning
+            .on_settled(record.dunning, transaction, self.clock.now())
+        else {
+            return Err(Error::InvalidInput(format!(
+                "Transaction {:?} is still pending",
+                transaction.transaction_id()
+            )));
+        };
+
+        record.pending_transaction_id = None;
+        // Only positive amounts are charged, so the settled renewal collected the whole balance
+        if outcome.status() == SubscriptionStatus::Active {
+            record.balance = Decimal::ZERO;
+        }
+        self.apply(&mut record, &outcome);
+        self.store.save(&record).await?;
+        Ok(outcome)
+    }
+
+    /// Renew all subscriptions due at the moment.
+    ///
+    /// Returns the outcome of every subscription, or the error of the store
+    /// failing to list them.
+    pub async fn process_due(
+        &self,
+    ) -> Result<Vec<(SubscriptionId, Result<DunningOutcome, Error>)>, Error> {
+        let mut outcomes = Vec::new();
+        for record in self.store.due(self.clock.now()).await? {
+            let outcome = self.renew(&record.subscription_id).await;
+            outcomes.push((record.subscription_id, outcome));
+        }
+        Ok(outcomes)
+    }
+
+    /// Apply the refresh of the stored credential (by the account updater or the customer)
+    /// to the subscription waiting for it: the retry becomes due immediately.
+    pub async fn credential_updated(
+        &self,
+        subscription_id: &SubscriptionId,
+        payment: G::Payment,
+    ) -> Result<DunningOutcome, Error> {
+        let mut record = self.find(subscription_id).await?;
+        let Some(state) = record.dunning else {
+            return Err(Error::InvalidInput(format!(
+                "Subscription {subscription_id:?} is not past due"
+            )));
+        };
+
+        let key = record.payment.idempotence_key().clone();
+        record.payment = payment.with_amount(record.payment.total_amount(), key)?;
+        let outcome = self.dunning.on_credential_updated(state, self.clock.now());
+        self.apply(&mut record, &outcome);
+        self.store.save(&record).await?;
+        Ok(outcome)
+    }
+
+    async fn find(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<ManagedSubscription<G::Payment>, Error> {
+        self.store.find(subscription_id).await?.ok_or_else(|| {
+            Error::InvalidInput(format!("Subscription {subscription_id:?} is not found"))
+        })
+    }
+
+    fn today(&self) -> Date {
+        self.time_zone.date_at(self.clock.now())
+    }
+
+    /// The moment of the billing cycle of the schedule (None after its end).
+    fn billing_at(&self, anchor: Date, interval: SubscriptionInterval, cycle: u32) -> Option<i64> {
+        BillingSchedule::new(anchor, interval)
+            .billing_date(cycle)
+            .map(|date| self.time_zone.midnight(date))
+    }
+
+    /// Apply the outcome of the dunning step to the subscription.
+    fn apply(&self, record: &mut ManagedSubscription<G::Payment>, outcome: &DunningOutcome) {
+        record.dunning = outcome.state().copied();
+        record.status = outcome.status();
+        match outcome.status() {
+            SubscriptionStatus::Active => {
+                record.cycle += 1;
+                record.next_billing_at =
+                    self.billing_at(record.anchor, record.terms.interval, record.cycle);
+                if record.next_billing_at.is_none() {
+                    record.status = SubscriptionStatus::Expired;
+                }
+            }
+            SubscriptionStatus::Canceled => record.next_billing_at = None,
+            _ => {}
+        }
+    }
+
+    /// Settle the proration preview according to its policy.
+    ///
+    /// Immediate debits are charged as unscheduled merchant-initiated transactions,
+    /// credits and deferred amounts are carried to the next billing.
+    async fn settle(
+        &self,
+        record: &mut ManagedSubscription<G::Payment>,
+        preview: &ProrationPreview,
+    ) -> Result<Option<Transaction>, Error> {
+        let due_now = preview.due_now();
+        if preview.policy() != ProrationPolicy::Immediate || due_now <= Decimal::ZERO {
+            record.balance += preview.total();
+            return Ok(None);
+        }
+
+        let key = idempotence_key(
+            &record.subscription_id,
+            &format!("proration-{}", self.clock.now()),
+        )?;
+        let payment = record.payment.clone().with_amount(due_now, key)?;
+        let usage = StoredCredentialUsage::Subsequent(initial_network_transaction_id(record)?);
+        let transaction = self
+            .inner
+            .charge(
+                payment,
+                NoInstallments,
+                Some(MerchantInitiatedType::UnscheduledCardOnFile),
+                Some(usage),
+            )
+            .await?;
+        declined(&record.subscription_id, "Proration", &transaction)?;
+        Ok(Some(transaction))
+    }
+}
+
+// --- Delegated flows ---
+
+#[allow(private_bounds)]
+impl<G, S> ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments>,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    /// Apply the outcome of the account updater to the subscription before its renewal.
+    ///
+    /// The replaced vault token is charged from the next billing on. When the subscription
+    /// waits for the refresh of the credential, the retry becomes due immediately
+    /// (the returned outcome). When the customer has to provide a new payment method
+    /// (closed account, the request to contact the cardholder), the active or past due
+    /// subscription is paused (the returned outcome). Outcomes without a match leave
+    /// the subscription unchanged.
+    pub async fn account_updated(
+        &self,
+        subscription_id: &SubscriptionId,
+        update: &AccountUpdate,
+    ) -> Result<Option<DunningOutcome>, Error> {
+        if update.requires_customer() {
+            let mut record = self.find(subscription_id).await?;
+            if !matches!(
+                record.status,
+                SubscriptionStatus::Active | SubscriptionStatus::PastDue
+            ) {
+                return Ok(None);
+            }
+            let outcome = self.dunning.on_customer_required();
+            self.apply(&mut record, &outcome);
+            self.store.save(&record).await?;
+            return Ok(Some(outcome));
+        }
+        if !update.is_refreshed() {
+            return Ok(None);
+        }
+
+        let mut record = self.find(subscription_id).await?;
+        if let Some(payment_method) = record.payment.payment_method().refreshed(update)? {
+            record.payment = record.payment.with_payment_method(payment_method);
+        }
+        let outcome = record
+            .dunning
+            .map(|state| self.dunning.on_credential_updated(state, self.clock.now()));
+        if let Some(outcome) = &outcome {
+            self.apply(&mut record, outcome);
+        }
+        self.store.save(&record).await?;
+        Ok(outcome)
+    }
+}
+
+#[async_trait]
+impl<G: Gateway, S: Send + Sync> Gateway for ManagedSubscriptions<G, S> {
+    type Payment = G::Payment;
+    type Installments = G::Installments;
+    type PaymentMethod = G::PaymentMethod;
+    type AuthorizedPaymentMethod = G::AuthorizedPaymentMethod;
+    type SecuredPaymentMethod = G::SecuredPaymentMethod;
+
+    async fn authorize(
+        &self,
+        request: authorize::Request<Self::PaymentMethod>,
+    ) -> Result<authorize::Response<Self::AuthorizedPaymentMethod>, Error> {
+        self.inner.authorize(request).await
+    }
+
+    async fn secure(
+        &self,
+        request: secure::Request<Self::AuthorizedPaymentMethod>,
+    ) -> Result<secure::Response<Self::SecuredPaymentMethod>, Error> {
+        self.inner.secure(request).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds, private_interfaces)]
+impl<G, S> ImmediatePayments for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: InternalPaymentMethod,
+    S: Send + Sync,
+{
+    async fn charge(
+        &self,
+        payment: Self::Payment,
+        installments: Self::Installments,
+        merchant_initiated_type: Option<MerchantInitiatedType>,
+        stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        self.inner
+            .charge(
+                payment,
+                installments,
+                merchant_initiated_type,
+                stored_credential_usage,
+            )
+            .await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds, private_interfaces)]
+impl<G, S> StoreCredentials for ManagedSubscriptions<G, S>
+where
+    G: StoreCredentials,
+    G::StoredPaymentMethod: Send,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod: VaultPaymentMethod + Send,
+    S: Send + Sync,
+{
+    type StoredPaymentMethod = G::StoredPaymentMethod;
+
+    async fn store(
+        &self,
+        payment_method: Self::StoredPaymentMethod,
+    ) -> Result<<<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod, Error> {
+        self.inner.store(payment_method).await
+    }
+
+    async fn unstore(
+        &self,
+        token: <<Self as Gateway>::Payment as PaymentMarker>::PaymentMethod,
+    ) -> Result<(), Error> {
+        self.inner.unstore(token).await
+    }
+}
+
+// --- Managed flows ---
+
+#[async_trait]
+#[allow(private_bounds, private_interfaces)]
+impl<G, S> RecurrentPayments for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments> + StoreCredentials,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    /// Verify the stored credential with the zero-amount initial customer-initiated
+    /// transaction, charge the setup fee and the first billing (when due today)
+    /// referencing it, and save the subscription.
+    ///
+    /// The subscription ID is derived from the idempotence key of the payment,
+    /// so that repeated calls return the same subscription without charging again.
+    /// Returns `Error::InvalidInput` when the verification or the initial payment
+    /// is declined, and `Error::NotSupported` (before charging anything) when
+    /// the gateway doesn't return the network transaction ID of the verification
+    /// (required to reference it by the renewals).
+    async fn create_subscription(
+        &self,
+        payment: Self::Payment,
+        terms: SubscriptionTerms,
+    ) -> Result<Subscription, Error> {
+        let subscription_id = subscription_id(payment.idempotence_key())?;
+        if let Some(existing) = self.store.find(&subscription_id).await? {
+            return Ok(existing.subscription());
+        }
+
+        let today = self.today();
+        let anchor = terms.first_billing_date(today)?;
+        let mut record = ManagedSubscription {
+            subscription_id,
+            status: SubscriptionStatus::Active,
+            terms,
+            created_at: self.clock.now(),
+            anchor,
+            cycle: 0,
+            next_billing_at: self.billing_at(anchor, terms.interval, 0),
+            initial_network_transaction_id: None,
+            attempts: 0,
+            dunning: None,
+            pending_transaction_id: None,
+            balance: Decimal::ZERO,
+            payment,
+        };
+
+        // The renewals can't be charged without the network transaction ID,
+        // so it's checked with the zero amount before charging the customer
+        let key = idempotence_key(&record.subscription_id, "verification")?;
+        let payment = record.payment.clone().with_amount(Decimal::ZERO, key)?;
+        let verification = self
+            .inner
+            .charge(
+                payment,
+                NoInstallments,
+                None,
+                Some(StoredCredentialUsage::Initial),
+            )
+            .await?;
+        declined(&record.subscription_id, "Verification", &verification)?;
+        let Some(network_transaction_id) = verification.network_transaction_id() else {
+            return Err(Error::NotSupported(format!(
+                "Verification of subscription {:?} has no network transaction ID to renew with",
+                record.subscription_id
+            )));
+        };
+        record.initial_network_transaction_id = Some(network_transaction_id.clone());
+
+        let billed_now = anchor == today;
+        let initial_amount = terms.setup_fee.unwrap_or_default()
+            + if billed_now {
+                record.payment.total_amount()
+            } else {
+                Decimal::ZERO
+            };
+        if initial_amount > Decimal::ZERO {
+            let key = idempotence_key(&record.subscription_id, "initial")?;
+            let payment = record.payment.clone().with_amount(initial_amount, key)?;
+            let usage = StoredCredentialUsage::Subsequent(network_transaction_id.clone());
+            let transaction = self
+                .inner
+                .charge(payment, NoInstallments, None, Some(usage))
+                .await?;
+            declined(&record.subscription_id, "Initial payment", &transaction)?;
+        }
+        if billed_now {
+            record.cycle = 1;
+            record.next_billing_at = self.billing_at(anchor, terms.interval, 1);
+        }
+
+        self.store.save(&record).await?;
+        Ok(record.subscription())
+    }
+
+    async fn cancel_subscription(&self, subscription_id: SubscriptionId) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        record.status = SubscriptionStatus::Canceled;
+        record.next_billing_at = None;
+        record.dunning = None;
+        self.store.save(&record).await
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: SubscriptionId,
+    ) -> Result<Subscription, Error> {
+        Ok(self.find(&subscription_id).await?.subscription())
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, S> PauseSubscriptions for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments> + StoreCredentials,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    /// Pause billing (including the retries of the failed renewal).
+    async fn pause_subscription(&self, subscription_id: SubscriptionId) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        match record.status {
+            SubscriptionStatus::Active | SubscriptionStatus::PastDue => {
+                record.status = SubscriptionStatus::Paused;
+                record.dunning = None;
+                self.store.save(&record).await
+            }
+            status => Err(Error::InvalidInput(format!(
+                "{status} subscription {subscription_id:?} can't be paused"
+            ))),
+        }
+    }
+
+    /// Resume billing. The missed billing (if any) is due immediately.
+    async fn resume_subscription(&self, subscription_id: SubscriptionId) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        if record.status != SubscriptionStatus::Paused {
+            return Err(Error::InvalidInput(format!(
+                "{} subscription {subscription_id:?} can't be resumed",
+                record.status
+            )));
+        }
+        record.status = SubscriptionStatus::Active;
+        self.store.save(&record).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, S> EditSubscriptionAmount for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments> + StoreCredentials,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    async fn preview_subscription_amount(
+        &self,
+        subscription_id: SubscriptionId,
+        total_amount: Decimal,
+        proration: ProrationPolicy,
+        date: Date,
+    ) -> Result<ProrationPreview, Error> {
+        let record = self.find(&subscription_id).await?;
+        ProrationPreview::amount_change(
+            &record.subscription(),
+            current_period(&record)?,
+            record.payment.total_amount(),
+            total_amount,
+            proration,
+            date,
+        )
+    }
+
+    async fn edit_subscription_amount(
+        &self,
+        subscription_id: SubscriptionId,
+        total_amount: Decimal,
+        proration: ProrationPolicy,
+    ) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        let preview = ProrationPreview::amount_change(
+            &record.subscription(),
+            current_period(&record)?,
+            record.payment.total_amount(),
+            total_amount,
+            proration,
+            self.today(),
+        )?;
+
+        let key = record.payment.idempotence_key().clone();
+        let payment = record.payment.clone().with_amount(total_amount, key)?;
+        self.settle(&mut record, &preview).await?;
+        record.payment = payment;
+        self.store.save(&record).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, S> EditSubscriptionInterval for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments> + StoreCredentials,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    async fn preview_subscription_interval(
+        &self,
+        subscription_id: SubscriptionId,
+        interval: SubscriptionInterval,
+        proration: ProrationPolicy,
+        date: Date,
+    ) -> Result<ProrationPreview, Error> {
+        let record = self.find(&subscription_id).await?;
+        ProrationPreview::interval_change(
+            &record.subscription(),
+            current_period(&record)?,
+            record.payment.total_amount(),
+            interval,
+            proration,
+            date,
+        )
+    }
+
+    /// Change the interval from the next billing (without proration),
+    /// or start the first period of the new interval today.
+    async fn edit_subscription_interval(
+        &self,
+        subscription_id: SubscriptionId,
+        interval: SubscriptionInterval,
+        proration: ProrationPolicy,
+    ) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        let today = self.today();
+        let preview = ProrationPreview::interval_change(
+            &record.subscription(),
+            current_period(&record)?,
+            record.payment.total_amount(),
+            interval,
+            proration,
+            today,
+        )?;
+
+        self.settle(&mut record, &preview).await?;
+        (record.anchor, record.cycle) = match proration {
+            ProrationPolicy::None => {
+                let next_billing = record.next_billing_at.map(|at| self.time_zone.date_at(at));
+                (next_billing.unwrap_or(today), 0)
+            }
+            ProrationPolicy::NextBilling | ProrationPolicy::Immediate => (today, 1),
+        };
+        record.terms.interval = interval;
+        record.next_billing_at = self.billing_at(record.anchor, interval, record.cycle);
+        self.store.save(&record).await
+    }
+}
+
+#[async_trait]
+#[allow(private_bounds)]
+impl<G, S> EditSubscriptionRecipients for ManagedSubscriptions<G, S>
+where
+    G: ImmediatePayments<Installments = NoInstallments> + StoreCredentials,
+    G::Payment: Clone + Sync,
+    <<G as Gateway>::Payment as PaymentMarker>::PaymentMethod:
+        InternalPaymentMethod + VaultPaymentMethod,
+    S: SubscriptionStore<G::Payment>,
+{
+    /// Split the next billings between the recipients
+    /// (returns `Error::NotSupported` for gateways without split payments).
+    async fn edit_subscription_recipients(
+        &self,
+        subscription_id: SubscriptionId,
+        recipients: Recipients,
+    ) -> Result<(), Error> {
+        let mut record = self.find(&subscription_id).await?;
+        record.payment = record.payment.with_recipients(recipients)?;
+        self.store.save(&record).await
+    }
+}
+
+/// The network transaction ID of the initial transaction referenced by the merchant-initiated
+/// charges of the subscription (card networks reject them without it).
+fn initial_network_transaction_id<P>(
+    record: &ManagedSubscription<P>,
+) -> Result<NetworkTransactionId, Error> {
+    record.initial_network_transaction_id.clone().ok_or_else(|| {
+        Error::NotSupported(format!(
+            "Subscription {:?} has no network transaction ID of the initial payment to charge with",
+            record.subscription_id
+        ))
+    })
+}
+
+/// The current billing period of the subscription to prorate.
+fn current_period<P>(record: &ManagedSubscription<P>) -> Result<Range<Date>, Error> {
+    record.current_period().ok_or_else(|| {
+        Error::InvalidInput(format!(
+            "Subscription {:?} has no next billing to prorate",
+            record.subscription_id
+        ))
+    })
+}
+
+/// Fails with `Error::InvalidInput` when the customer's charge of the subscription was declined.
+fn declined(
+    subscription_id: &SubscriptionId,
+    charge: &str,
+    transaction: &Transaction,
+) -> Result<(), Error> {
+    if !matches!(
+        transaction.status(),
+        TransactionStatus::Declined | TransactionStatus::Failed
+    ) {
+        return Ok(());
+    }
+    Err(Error::InvalidInput(format!(
+        "{charge} of subscription {subscription_id:?} was declined ({})",
+        transaction
+            .decline_reason()
+            .map_or("no reason".into(), |reason| reason.to_string())
+    )))
+}
+
+/// The subscription ID derived from the idempotence key of the creating payment.
+fn subscription_id(idempotence_key: &TransactionIdempotenceKey) -> Result<SubscriptionId, Error> {
+    let digest = Sha256::digest(idempotence_key.as_ref().as_bytes());
+    format!("sub_{}", &hex(&digest)[..32]).as_str().try_into()
+}
+
+/// The idempotence key of the subscription charge.
+fn idempotence_key(
+    subscription_id: &SubscriptionId,
+    suffix: &str,
+) -> Result<TransactionIdempotenceKey, Error> {
+    // SAFETY: the key is sent to the same gateway as the subscription ID
+    format!("{}-{suffix}", unsafe { subscription_id.as_ref() })
+        .as_str()
+        .try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dunning::{DunningAction, DunningEvent};
+    use crate::test_support::date;
+    use crate::types::{Payment, SEPA, Vault};
+    use crate::{DeclineReason, inputs};
+    use futures::executor::block_on;
+    use iso_currency::Currency;
+    use rust_decimal_macros::dec;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    type Charge = (
+        Decimal,
+        Option<MerchantInitiatedType>,
+        Option<StoredCredentialUsage>,
+    );
+
+    /// Gateway charging vault tokens with the statuses from the stack (captured by default).
+    #[derive(Default)]
+    struct VaultGateway {
+        statuses: Mutex<Vec<TransactionStatus>>,
+        charges: Mutex<Vec<Charge>>,
+        idempotence_keys: Mutex<Vec<String>>,
+        without_network_transaction_id: bool,
+    }
+
+    impl VaultGateway {
+        fn charges(&self) -> Vec<Charge> {
+            self.charges.lock().unwrap().clone()
+        }
+
+        fn idempotence_keys(&self) -> Vec<String> {
+            self.idempotence_keys.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl Gateway for VaultGateway {
+        type Payment = Payment<Vault>;
+        type Installments = NoInstallments;
+        type PaymentMethod = Vault;
+        type AuthorizedPaymentMethod = Vault;
+        type SecuredPaymentMethod = Vault;
+
+        async fn authorize(
+            &self,
+            request: authorize::Request<Vault>,
+        ) -> Result<authorize::Response<Vault>, Error> {
+            Ok(authorize::Response::Authorized {
+                payment_method: request.payment_method,
+                verified: true,
+                metadata: Default::default(),
+            })
+        }
+
+        async fn secure(
+            &self,
+            request: secure::Request<Vault>,
+        ) -> Result<secure::Response<Vault>, Error> {
+            Ok(secure::Response::Secured(request.payment_method))
+        }
+    }
+
+    #[async_trait]
+    impl ImmediatePayments for VaultGateway {
+        async fn charge(
+            &self,
+            payment: Payment<Vault>,
+            _installments: NoInstallments,
+            merchant_initiated_type: Option<MerchantInitiatedType>,
+            stored_credential_usage: Option<StoredCredentialUsage>,
+        ) -> Result<Transaction, Error> {
+            self.charges.lock().unwrap().push((
+                payment.total_amount(),
+                merchant_initiated_type,
+                stored_credential_usage,
+            ));
+            self.idempotence_keys
+                .lock()
+                .unwrap()
+                .push(payment.idempotence_key().as_ref().to_owned());
+            let status = self
+                .statuses
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or(TransactionStatus::Captured);
+            inputs::Transaction {
+                transaction_id: "txn_12345678",
+                idempotence_key: payment.idempotence_key().as_ref(),
+                status,
+                decline_reason: (status == TransactionStatus::Declined)
+                    .then_some(DeclineReason::InsufficientFunds),
+                currency: payment.currency(),
+                recipients: None,
+                merchant_initiated_type,
+                network_transaction_id: (!self.without_network_transaction_id)
+                    .then_some("583112345678901"),
+            }
+            .try_into()
+        }
+    }
+
+    #[async_trait]
+    impl StoreCredentials for VaultGateway {
+        type StoredPaymentMethod = SEPA;
+
+        async fn store(&self, _payment_method: SEPA) -> Result<Vault, Error> {
+            Err(Error::NotSupported("store".into()))
+        }
+
+        async fn unstore(&self, _token: Vault) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    /// Clock moved forward by tests.
+    #[derive(Clone)]
+    struct TestClock(Arc<AtomicI64>);
+
+    impl TestClock {
+        fn advance(&self, seconds: i64) {
+            self.0.fetch_add(seconds, Ordering::Relaxed);
+        }
+    }
+
+    impl Clock for TestClock {
+        fn now(&self) -> i64 {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    type Managed = ManagedSubscriptions<VaultGateway, InMemorySubscriptionStore<Payment<Vault>>>;
+
+    fn managed(statuses: Vec<TransactionStatus>) -> (Managed, TestClock) {
+        // 2025-01-15T10:00:00Z
+        let clock = TestClock(Arc::new(AtomicI64::new(
+            date(2025, 1, 15).timestamp() + 10 * 3600,
+        )));
+        let gateway = VaultGateway {
+            statuses: Mutex::new(statuses),
+            ..Default::default()
+        };
+        let managed = ManagedSubscriptions::new(gateway, InMemorySubscriptionStore::default())
+            .with_clock(clock.clone());
+        (managed, clock)
+    }
+
+    fn payment() -> Payment<Vault> {
+        inputs::Payment {
+            payment_method: "tok_1234567890ebadf",
+            currency: Currency::EUR,
+            total_amount: dec!(30),
+            base_amount: dec!(30),
+            idempotence_key: "signup-42",
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    fn terms(
+        trial: Option<inputs::SubscriptionInterval>,
+        setup_fee: Option<Decimal>,
+    ) -> SubscriptionTerms {
+        inputs::SubscriptionTerms {
+            interval: inputs::SubscriptionInterval::Month(1),
+            trial,
+            billing_anchor: None,
+            setup_fee,
+            proration: ProrationPolicy::NextBilling,
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn charges_first_billing_on_creation() {
+        let (managed, _) = managed(vec![]);
+
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, Some(dec!(5))))).unwrap();
+        assert_eq!(subscription.status(), &SubscriptionStatus::Active);
+        assert_eq!(
+            subscription.next_billing_date(),
+            Some(date(2025, 2, 15).timestamp())
+        );
+        assert_eq!(
+            managed.inner().charges(),
+            [
+                (dec!(0), None, Some(StoredCredentialUsage::Initial)),
+                (
+                    dec!(35),
+                    None,
+                    Some(StoredCredentialUsage::Subsequent(
+                        "583112345678901".try_into().unwrap()
+                    ))
+                )
+            ]
+        );
+
+        // Repeated creation returns the same subscription without charging
+        let repeated =
+            block_on(managed.create_subscription(payment(), terms(None, Some(dec!(5))))).unwrap();
+        assert_eq!(repeated.subscription_id(), subscription.subscription_id());
+        assert_eq!(managed.inner().charges().len(), 2);
+    }
+
+    #[test]
+    fn bills_after_trial() {
+        let (managed, clock) = managed(vec![]);
+        let trial = Some(inputs::SubscriptionInterval::Day(14));
+
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(trial, None))).unwrap();
+        assert_eq!(
+            managed.inner().charges(),
+            [(dec!(0), None, Some(StoredCredentialUsage::Initial))]
+        );
+        assert!(block_on(managed.process_due()).unwrap().is_empty());
+
+        clock.advance(14 * DAY);
+        let outcomes = block_on(managed.process_due()).unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(
+            managed.inner().charges()[1],
+            (
+                dec!(30),
+                Some(MerchantInitiatedType::Recurring),
+                Some(StoredCredentialUsage::Subsequent(
+                    "583112345678901".try_into().unwrap()
+                ))
+            )
+        );
+
+        let subscription =
+            block_on(managed.get_subscription(subscription.subscription_id().clone())).unwrap();
+        assert_eq!(
+            subscription.next_billing_date(),
+            Some(date(2025, 2, 28).timestamp())
+        );
+    }
+
+    #[test]
+    fn retries_declined_renewal() {
+        let (managed, clock) = managed(vec![
+            TransactionStatus::Captured,
+            TransactionStatus::Declined,
+            TransactionStatus::Captured,
+            TransactionStatus::Captured,
+        ]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(31 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        assert!(block_on(managed.renew(&subscription_id)).is_err());
+
+        clock.advance(3 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+
+        let charges = managed.inner().charges();
+        assert_eq!(charges.len(), 4);
+        assert!(matches!(
+            &charges[3],
+            (
+                _,
+                Some(MerchantInitiatedType::Recurring),
+                Some(StoredCredentialUsage::Subsequent(_))
+            )
+        ));
+        let subscription = block_on(managed.get_subscription(subscription_id)).unwrap();
+        assert_eq!(
+            subscription.next_billing_date(),
+            Some(date(2025, 3, 15).timestamp())
+        );
+    }
+
+    fn settled(status: TransactionStatus) -> Transaction {
+        inputs::Transaction {
+            transaction_id: "txn_12345678",
+            idempotence_key: "renewal",
+            status,
+            decline_reason: (status == TransactionStatus::Declined)
+                .then_some(DeclineReason::InsufficientFunds),
+            currency: Currency::EUR,
+            recipients: None,
+            merchant_initiated_type: Some(MerchantInitiatedType::Recurring),
+            network_transaction_id: Some("583112345678901"),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn awaits_settlement_of_pending_retry() {
+        let (managed, clock) = managed(vec![
+            TransactionStatus::Pending,
+            TransactionStatus::Declined,
+            TransactionStatus::Captured,
+            TransactionStatus::Captured,
+        ]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(31 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+
+        clock.advance(3 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert!(outcome.is_pending());
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+
+        // The pending retry is not charged again
+        clock.advance(3600);
+        assert!(block_on(managed.process_due()).unwrap().is_empty());
+        assert!(block_on(managed.renew(&subscription_id)).is_err());
+        assert_eq!(managed.inner().charges().len(), 4);
+
+        let still_pending = settled(TransactionStatus::Processing);
+        assert!(block_on(managed.renewal_settled(&subscription_id, &still_pending)).is_err());
+
+        let captured = settled(TransactionStatus::Captured);
+        let outcome = block_on(managed.renewal_settled(&subscription_id, &captured)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+        assert_eq!(outcome.events(), [DunningEvent::Recovered { attempts: 1 }]);
+        assert!(block_on(managed.renewal_settled(&subscription_id, &captured)).is_err());
+
+        let subscription = block_on(managed.get_subscription(subscription_id)).unwrap();
+        assert_eq!(subscription.status(), &SubscriptionStatus::Active);
+        assert_eq!(
+            subscription.next_billing_date(),
+            Some(date(2025, 3, 15).timestamp())
+        );
+    }
+
+    #[test]
+    fn keeps_cycle_of_pending_renewal() {
+        let (managed, clock) = managed(vec![
+            TransactionStatus::Pending,
+            TransactionStatus::Captured,
+            TransactionStatus::Captured,
+        ]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(31 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert!(outcome.is_pending());
+        let record = block_on(managed.store().find(&subscription_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.cycle, 1);
+        assert_eq!(record.next_billing_at, Some(date(2025, 2, 15).timestamp()));
+
+        let declined = settled(TransactionStatus::Declined);
+        let outcome = block_on(managed.renewal_settled(&subscription_id, &declined)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        let record = block_on(managed.store().find(&subscription_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.pending_transaction_id, None);
+        assert_eq!(record.cycle, 1);
+    }
+
+    #[test]
+    fn carries_proration_to_next_billing() {
+        let (managed, clock) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        // 2025-01-30: 16 of 31 days left
+        clock.advance(15 * DAY);
+        let preview = block_on(managed.preview_subscription_amount(
+            subscription_id.clone(),
+            dec!(61),
+            ProrationPolicy::NextBilling,
+            date(2025, 1, 30),
+        ))
+        .unwrap();
+        assert_eq!(preview.total(), dec!(16));
+
+        block_on(managed.edit_subscription_amount(
+            subscription_id.clone(),
+            dec!(61),
+            ProrationPolicy::NextBilling,
+        ))
+        .unwrap();
+        assert_eq!(managed.inner().charges().len(), 2);
+
+        clock.advance(16 * DAY);
+        block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(managed.inner().charges()[2].0, dec!(77));
+    }
+
+    #[test]
+    fn charges_interval_change_immediately() {
+        let (managed, clock) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(15 * DAY);
+        block_on(managed.edit_subscription_interval(
+            subscription_id.clone(),
+            SubscriptionInterval::Week(1),
+            ProrationPolicy::Immediate,
+        ))
+        .unwrap();
+        assert_eq!(
+            managed.inner().charges()[2],
+            (
+                dec!(14.52),
+                Some(MerchantInitiatedType::UnscheduledCardOnFile),
+                Some(StoredCredentialUsage::Subsequent(
+                    "583112345678901".try_into().unwrap()
+                ))
+            )
+        );
+
+        let subscription = block_on(managed.get_subscription(subscription_id)).unwrap();
+        assert_eq!(subscription.interval(), &SubscriptionInterval::Week(1));
+        assert_eq!(
+            subscription.next_billing_date(),
+            Some(date(2025, 2, 6).timestamp())
+        );
+    }
+
+    #[test]
+    fn prorates_month_end_anchor_by_schedule() {
+        let (managed, clock) = managed(vec![]);
+        clock.advance(16 * DAY);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        // Billed on Jan 31, Feb 28 and Mar 31
+        clock.advance(28 * DAY);
+        assert_eq!(block_on(managed.process_due()).unwrap().len(), 1);
+        clock.advance(31 * DAY);
+        assert_eq!(block_on(managed.process_due()).unwrap().len(), 1);
+
+        // 15 of 30 days remain from Apr 15 to Apr 30
+        let preview = block_on(managed.preview_subscription_amount(
+            subscription_id,
+            dec!(60),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 15),
+        ))
+        .unwrap();
+        assert_eq!(preview.lines()[0].period_end(), date(2025, 4, 30));
+        assert_eq!(preview.credit(), dec!(15));
+        assert_eq!(preview.debit(), dec!(30));
+    }
+
+    #[test]
+    fn pauses_resumes_and_cancels() {
+        let (managed, clock) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        block_on(managed.pause_subscription(subscription_id.clone())).unwrap();
+        clock.advance(31 * DAY);
+        assert!(block_on(managed.process_due()).unwrap().is_empty());
+
+        block_on(managed.resume_subscription(subscription_id.clone())).unwrap();
+        assert_eq!(block_on(managed.process_due()).unwrap().len(), 1);
+
+        block_on(managed.cancel_subscription(subscription_id.clone())).unwrap();
+        let subscription = block_on(managed.get_subscription(subscription_id.clone())).unwrap();
+        assert_eq!(subscription.status(), &SubscriptionStatus::Canceled);
+        assert_eq!(subscription.next_billing_date(), None);
+        assert!(block_on(managed.resume_subscription(subscription_id)).is_err());
+    }
+
+    #[test]
+    fn renews_after_resume_with_new_idempotence_key() {
+        let (managed, clock) = managed(vec![
+            TransactionStatus::Captured,
+            TransactionStatus::Declined,
+            TransactionStatus::Captured,
+            TransactionStatus::Captured,
+        ]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(31 * DAY);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+
+        block_on(managed.pause_subscription(subscription_id.clone())).unwrap();
+        block_on(managed.resume_subscription(subscription_id.clone())).unwrap();
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+
+        let keys = managed.inner().idempotence_keys();
+        assert_eq!(keys.len(), 4);
+        assert_ne!(keys[2], keys[3]);
+    }
+
+    #[test]
+    fn renews_through_compliant_gateway() {
+        let (managed, clock) = managed(vec![]);
+        let managed = ManagedSubscriptions::new(
+            crate::decorators::Compliant::new(managed.inner),
+            InMemorySubscriptionStore::default(),
+        )
+        .with_clock(clock.clone());
+        let trial = Some(inputs::SubscriptionInterval::Day(14));
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(trial, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        clock.advance(14 * DAY);
+        let outcomes = block_on(managed.process_due()).unwrap();
+        assert_eq!(
+            outcomes[0].1.as_ref().unwrap().status(),
+            SubscriptionStatus::Active
+        );
+
+        clock.advance(15 * DAY);
+        block_on(managed.edit_subscription_amount(
+            subscription_id,
+            dec!(60),
+            ProrationPolicy::Immediate,
+        ))
+        .unwrap();
+        assert_eq!(managed.inner().inner().charges().len(), 3);
+    }
+
+    #[test]
+    fn refuses_renewal_without_network_transaction_id() {
+        let (managed, clock) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+        let mut record = block_on(managed.store().find(&subscription_id))
+            .unwrap()
+            .unwrap();
+        record.initial_network_transaction_id = None;
+        block_on(managed.store().save(&record)).unwrap();
+
+        clock.advance(31 * DAY);
+        let result = block_on(managed.renew(&subscription_id));
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+        assert_eq!(managed.inner().charges().len(), 2);
+    }
+
+    #[test]
+    fn applies_account_updates() {
+        let (managed, clock) = managed(vec![
+            TransactionStatus::Captured,
+            TransactionStatus::Declined,
+            TransactionStatus::Captured,
+            TransactionStatus::Captured,
+        ]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        // Outcomes without a match leave the subscription unchanged
+        assert!(
+            block_on(managed.account_updated(&subscription_id, &AccountUpdate::NoMatch))
+                .unwrap()
+                .is_none()
+        );
+
+        // The replaced token is charged from the next billing on
+        let replaced: AccountUpdate = inputs::AccountUpdate::CredentialReplaced(
+            inputs::UpdatableCredential::Token("tok_0987654321fedcba"),
+        )
+        .try_into()
+        .unwrap();
+        assert!(
+            block_on(managed.account_updated(&subscription_id, &replaced))
+                .unwrap()
+                .is_none()
+        );
+        let record = block_on(managed.store().find(&subscription_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            unsafe { record.payment.payment_method().token.as_ref() },
+            "tok_0987654321fedcba"
+        );
+
+        // The past due subscription is retried immediately after the update
+        clock.advance(31 * DAY);
+        block_on(managed.renew(&subscription_id)).unwrap();
+        let outcome = block_on(managed.account_updated(&subscription_id, &replaced))
+            .unwrap()
+            .unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::PastDue);
+        let outcome = block_on(managed.renew(&subscription_id)).unwrap();
+        assert_eq!(outcome.status(), SubscriptionStatus::Active);
+    }
+
+    #[test]
+    fn pauses_subscription_requiring_customer() {
+        let (managed, clock) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+        let subscription_id = subscription.subscription_id().clone();
+
+        for update in [
+            AccountUpdate::AccountClosed,
+            AccountUpdate::ContactCardholder,
+        ] {
+            let outcome = block_on(managed.account_updated(&subscription_id, &update))
+                .unwrap()
+                .unwrap();
+            assert_eq!(outcome.status(), SubscriptionStatus::Paused);
+            assert_eq!(
+                outcome.events(),
+                [
+                    DunningEvent::CredentialUpdateRequired,
+                    DunningEvent::Exhausted {
+                        action: DunningAction::Pause
+                    }
+                ]
+            );
+
+            clock.advance(31 * DAY);
+            assert!(block_on(managed.process_due()).unwrap().is_empty());
+            // Paused subscriptions are left as is
+            assert!(
+                block_on(managed.account_updated(&subscription_id, &update))
+                    .unwrap()
+                    .is_none()
+            );
+            block_on(managed.resume_subscription(subscription_id.clone())).unwrap();
+            assert_eq!(block_on(managed.process_due()).unwrap().len(), 1);
+        }
+    }
+
+    #[test]
+    fn rejects_declined_initial_payment() {
+        let (managed, _) = managed(vec![TransactionStatus::Declined]);
+
+        let result = block_on(managed.create_subscription(payment(), terms(None, None)));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_initial_payment_without_network_transaction_id() {
+        let gateway = VaultGateway {
+            without_network_transaction_id: true,
+            ..Default::default()
+        };
+        let managed = ManagedSubscriptions::new(gateway, InMemorySubscriptionStore::default());
+
+        // Only the zero amount is charged before the refusal
+        let result = block_on(managed.create_subscription(payment(), terms(None, Some(dec!(5)))));
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+        assert_eq!(
+            managed.inner().charges(),
+            [(dec!(0), None, Some(StoredCredentialUsage::Initial))]
+        );
+        assert!(block_on(managed.store().due(i64::MAX)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_recipients_of_unsplit_payment() {
+        let (managed, _) = managed(vec![]);
+        let subscription =
+            block_on(managed.create_subscription(payment(), terms(None, None))).unwrap();
+
+        let result = block_on(managed.edit_subscription_recipients(
+            subscription.subscription_id().clone(),
+            Recipients::default(),
+        ));
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+use super::ManagedSubscription;
+use crate::Error;
+use crate::types::SubscriptionId;
+
+/// Storage of the subscriptions managed by the merchant.
+///
+/// Saving a subscription with the same ID replaces the previous one.
+#[async_trait]
+pub trait SubscriptionStore<P: Send + Sync>: Send + Sync {
+    /// Save (insert or replace) the subscription.
+    async fn save(&self, subscription: &ManagedSubscription<P>) -> Result<(), Error>;
+
+    /// Find the subscription by its ID.
+    async fn find(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<Option<ManagedSubscription<P>>, Error>;
+
+    /// Find the subscriptions due for billing, a retry or a dunning timeout
+    /// at the moment (Unix timestamp), see [`ManagedSubscription::is_due`].
+    async fn due(&self, now: i64) -> Result<Vec<ManagedSubscription<P>>, Error>;
+}
+
+/// Store keeping the subscriptions in memory.
+///
+/// Intended for tests and prototyping, as the subscriptions are lost on restart
+/// and lookups scan all records.
+#[derive(Debug)]
+pub struct InMemorySubscriptionStore<P>(Mutex<Vec<ManagedSubscription<P>>>);
+
+impl<P> Default for InMemorySubscriptionStore<P> {
+    fn default() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+}
+
+impl<P> InMemorySubscriptionStore<P> {
+    fn with<T>(&self, f: impl FnOnce(&mut Vec<ManagedSubscription<P>>) -> T) -> T {
+        let mut records = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut records)
+    }
+}
+
+#[async_trait]
+impl<P: Clone + Send + Sync> SubscriptionStore<P> for InMemorySubscriptionStore<P> {
+    async fn save(&self, subscription: &ManagedSubscription<P>) -> Result<(), Error> {
+        self.with(|records| {
+            match records
+                .iter_mut()
+                .find(|r| r.subscription_id == subscription.subscription_id)
+            {
+                Some(existing) => *existing = subscription.clone(),
+                None => records.push(subscription.clone()),
+            }
+        });
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        subscription_id: &SubscriptionId,
+    ) -> Result<Option<ManagedSubscription<P>>, Error> {
+        Ok(self.with(|records| {
+            records
+                .iter()
+                .find(|r| &r.subscription_id == subscription_id)
+                .cloned()
+        }))
+    }
+
+    async fn due(&self, now: i64) -> Result<Vec<ManagedSubscription<P>>, Error> {
+        Ok(self.with(|records| records.iter().filter(|r| r.is_due(now)).cloned().collect()))
+    }
+}
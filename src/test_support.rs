@@ -0,0 +1,137 @@
+//! Gateway stubs and fixtures shared by the tests of decorators, flows helpers and repositories.
+
+use async_trait::async_trait;
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+use std::sync::Mutex;
+
+use crate::flows::{CheckTransaction, ImmediatePayments};
+use crate::gateway::{authorize, secure};
+use crate::types::{
+    CreditCard, Date, NetworkTransactionId, NoInstallments, Payment, StoredCredentialUsage,
+    Transaction, TransactionId,
+};
+use crate::{DeclineReason, Error, Gateway, MerchantInitiatedType, TransactionStatus, inputs};
+
+pub(crate) const TRANSACTION_ID: &str = "txn_12345678";
+pub(crate) const NETWORK_TRANSACTION_ID: &str = "583112345678901";
+pub(crate) const IDEMPOTENCE_KEY: &str = "order-42";
+pub(crate) const PAN: &str = "4532015112830366";
+
+/// Gateway returning a transaction with the preconfigured status and decline reason,
+/// or failing with `Error::NotSupported` when no status is set.
+///
+/// Statuses from the `sequence` stack (popped from the end) take precedence over the `status`.
+/// The secure step rejects requested exemptions with the decline reason (if set).
+#[derive(Default)]
+pub(crate) struct MockGateway {
+    pub(crate) status: Option<TransactionStatus>,
+    pub(crate) decline_reason: Option<DeclineReason>,
+    pub(crate) sequence: Mutex<Vec<TransactionStatus>>,
+}
+
+impl MockGateway {
+    pub(crate) fn respond(&self) -> Result<Transaction, Error> {
+        let status = self
+            .sequence
+            .lock()
+            .unwrap()
+            .pop()
+            .or(self.status)
+            .ok_or_else(|| Error::NotSupported("mock failure".into()))?;
+
+        inputs::Transaction {
+            transaction_id: TRANSACTION_ID,
+            idempotence_key: IDEMPOTENCE_KEY,
+            status,
+            decline_reason: self.decline_reason,
+            currency: Currency::EUR,
+            recipients: None,
+            merchant_initiated_type: None,
+            network_transaction_id: Some(NETWORK_TRANSACTION_ID),
+        }
+        .try_into()
+    }
+}
+
+pub(crate) fn payment() -> Payment<CreditCard> {
+    inputs::Payment {
+        payment_method: inputs::CreditCard {
+            cvv: "123",
+            number: PAN,
+            card_expiry: inputs::CardExpiry {
+                month: 12,
+                year: 2030,
+            },
+            holder_name: "John Doe",
+        },
+        currency: Currency::EUR,
+        total_amount: Decimal::new(1999, 2),
+        base_amount: Decimal::new(1999, 2),
+        idempotence_key: IDEMPOTENCE_KEY,
+    }
+    .try_into()
+    .unwrap()
+}
+
+pub(crate) fn transaction_id() -> TransactionId {
+    TRANSACTION_ID.try_into().unwrap()
+}
+
+pub(crate) fn network_transaction_id() -> NetworkTransactionId {
+    NETWORK_TRANSACTION_ID.try_into().unwrap()
+}
+
+pub(crate) fn date(year: u16, month: u8, day: u8) -> Date {
+    inputs::Date { day, month, year }.try_into().unwrap()
+}
+
+#[async_trait]
+impl Gateway for MockGateway {
+    type Payment = Payment<CreditCard>;
+    type Installments = NoInstallments;
+    type PaymentMethod = CreditCard;
+    type AuthorizedPaymentMethod = CreditCard;
+    type SecuredPaymentMethod = CreditCard;
+
+    async fn authorize(
+        &self,
+        request: authorize::Request<CreditCard>,
+    ) -> Result<authorize::Response<CreditCard>, Error> {
+        Ok(authorize::Response::Authorized {
+            payment_method: request.payment_method,
+            verified: true,
+            metadata: Default::default(),
+        })
+    }
+
+    async fn secure(
+        &self,
+        request: secure::Request<CreditCard>,
+    ) -> Result<secure::Response<CreditCard>, Error> {
+        match (request.exemption, self.decline_reason) {
+            (Some(_), Some(reason)) => Ok(secure::Response::ExemptionRejected(reason)),
+            _ => Ok(secure::Response::Secured(request.payment_method)),
+        }
+    }
+}
+
+#[async_trait]
+impl ImmediatePayments for MockGateway {
+    async fn charge(
+        &self,
+        _payment: Payment<CreditCard>,
+        _installments: NoInstallments,
+        _merchant_initiated_type: Option<MerchantInitiatedType>,
+        _stored_credential_usage: Option<StoredCredentialUsage>,
+    ) -> Result<Transaction, Error> {
+        self.respond()
+    }
+}
+
+#[async_trait]
+impl CheckTransaction for MockGateway {
+    async fn status(&self, _transaction_id: TransactionId) -> Result<Transaction, Error> {
+        self.respond()
+    }
+}
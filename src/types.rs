@@ -9,21 +9,30 @@
 //! from the specific data formats required by external Payment Gateways (PAGs),
 //! upholding the core's role as a stable abstraction layer.
 mod account_number;
+mod account_update;
 mod address;
+mod app_info;
 mod authorization_code;
 mod bank_code;
 mod birth_date;
 mod browser_info;
+mod card_display;
 mod card_expiry;
 mod card_holder_name;
 mod cavv;
 mod charge_authorized;
+mod checkpoint;
 mod city;
 mod confirmation;
 mod country_code;
 mod credentials;
+mod creditor_id;
+mod cryptogram;
+mod customer;
 mod customer_id;
 mod cvv;
+mod date;
+mod device_channel;
 mod distributed_value;
 mod email_address;
 mod external_payment;
@@ -33,14 +42,19 @@ mod iban;
 mod installment_plan_id;
 mod installments;
 mod language;
+mod last_digits;
+mod mandate;
+mod mandate_reference;
 mod metadata;
 mod national_id;
+mod network_transaction_id;
 mod offer_id;
 pub(crate) mod payment_methods;
 pub mod payments;
 mod phone_number;
 mod postal_code;
 mod primary_account_number;
+mod proration;
 mod reason_for_refund;
 mod reason_text;
 mod recipient_id;
@@ -54,52 +68,71 @@ mod street_address;
 mod subscription;
 mod subscription_id;
 mod subscription_interval;
+mod subscription_terms;
 mod three_d_secure_token;
 mod token;
 mod total_refund;
 mod transaction;
+mod transaction_filter;
 mod transaction_id;
 mod transaction_idempotence_key;
+mod url;
 mod virtual_payment_address;
 
 pub use account_number::AccountNumber;
+pub use account_update::{AccountUpdate, UpdatableCredential};
 pub use address::Address;
+pub use app_info::AppInfo;
 pub use authorization_code::AuthorizationCode;
 pub use bank_code::BankCode;
 pub use birth_date::BirthDate;
 pub use browser_info::BrowserInfo;
+pub use card_display::CardDisplay;
 pub use card_expiry::CardExpiry;
 pub use card_holder_name::CardHolderName;
 pub use cavv::CAVV;
 pub use charge_authorized::CaptureAuthorized;
+pub use checkpoint::Checkpoint;
 pub use city::City;
 pub use confirmation::Confirmation;
 pub use country_code::CountryCode;
 pub use credentials::Credentials;
+pub use creditor_id::CreditorId;
+pub use cryptogram::Cryptogram;
+pub use customer::{Customer, CustomerDetails, CustomerPaymentMethod};
 pub use customer_id::CustomerId;
 pub use cvv::CVV;
+pub use date::Date;
+pub use device_channel::DeviceChannel;
 pub use distributed_value::DistributedValue;
 pub use email_address::EmailAddress;
 pub use external_payment::ExternalPayment;
-pub use external_payment_data::ExternalPaymentData;
+pub use external_payment_data::{BankTransfer, DeepLink, ExternalPaymentData, QRCode, Voucher};
 pub use full_name::FullName;
 pub use iban::IBAN;
 pub use installment_plan_id::InstallmentPlanId;
 pub use installments::*;
 pub use language::Language;
+pub use last_digits::LastDigits;
+pub use mandate::Mandate;
+pub use mandate_reference::MandateReference;
 pub use metadata::Metadata;
 pub use national_id::NationalId;
+pub use network_transaction_id::NetworkTransactionId;
 pub use offer_id::OfferId;
 pub use payment_methods::*;
 pub use payments::*;
 pub use phone_number::PhoneNumber;
 pub use postal_code::PostalCode;
 pub use primary_account_number::PrimaryAccountNumber;
+pub use proration::{ProrationLine, ProrationPreview};
 pub use reason_for_refund::ReasonForRefund;
 pub use reason_text::ReasonText;
 pub use recipient_id::RecipientId;
 pub use recipients::Recipients;
-pub use required_action::RequiredAction;
+pub use required_action::{
+    AwaitApproval, Otp, Redirect, RequiredAction, ThreeDSChallenge, ThreeDSMethod,
+};
 pub use reversal_reason::ReversalReason;
 pub use routing_number::RoutingNumber;
 pub use stored_credential_token::StoredCredentialToken;
@@ -108,10 +141,13 @@ pub use street_address::StreetAddress;
 pub use subscription::Subscription;
 pub use subscription_id::SubscriptionId;
 pub use subscription_interval::SubscriptionInterval;
+pub use subscription_terms::SubscriptionTerms;
 pub use three_d_secure_token::ThreeDSecureToken;
 pub use token::Token;
 pub use total_refund::TotalRefund;
 pub use transaction::Transaction;
+pub use transaction_filter::TransactionFilter;
 pub use transaction_id::TransactionId;
 pub use transaction_idempotence_key::TransactionIdempotenceKey;
+pub use url::Url;
 pub use virtual_payment_address::VirtualPaymentAddress;
@@ -0,0 +1,142 @@
+use std::convert::TryFrom;
+
+use crate::Error;
+use crate::types::{CardExpiry, StoredCredential, Token};
+
+/// Stored credential submitted to the account updater
+/// (see [`AccountUpdater`](crate::flows::AccountUpdater)).
+#[derive(Clone, Debug)]
+pub enum UpdatableCredential {
+    /// Gateway-specific stored credential (mandate, setup intent, network token).
+    StoredCredential(StoredCredential),
+    /// Token of the payment method stored in the gateway vault.
+    Token(Token),
+}
+
+impl<'a> TryFrom<crate::UpdatableCredential<'a>> for UpdatableCredential {
+    type Error = Error;
+
+    fn try_from(input: crate::UpdatableCredential<'a>) -> Result<Self, Self::Error> {
+        Ok(match input {
+            crate::UpdatableCredential::StoredCredential(credential) => {
+                Self::StoredCredential(credential.try_into()?)
+            }
+            crate::UpdatableCredential::Token(token) => Self::Token(token.try_into()?),
+        })
+    }
+}
+
+/// Outcome of the account updater for one stored credential.
+///
+/// Card networks (Visa Account Updater, Mastercard Automatic Billing Updater)
+/// report the changes of reissued cards, so that the stored credentials
+/// can be refreshed before the next merchant-initiated charge.
+#[derive(Clone, Debug)]
+pub enum AccountUpdate {
+    /// The card was reissued with the same number and a new expiry.
+    ExpiryUpdated(CardExpiry),
+    /// The card was reissued with a new number, stored under the new credential
+    /// (of the same kind as the submitted one).
+    CredentialReplaced(UpdatableCredential),
+    /// The account was closed by the issuer (the credential must not be charged anymore).
+    AccountClosed,
+    /// The issuer asks the merchant to contact the cardholder for new details.
+    ContactCardholder,
+    /// The issuer doesn't participate in the account updater, or has no changes.
+    NoMatch,
+}
+
+impl AccountUpdate {
+    /// Whether the credential was refreshed and can be charged again.
+    #[inline]
+    pub fn is_refreshed(&self) -> bool {
+        matches!(self, Self::ExpiryUpdated(_) | Self::CredentialReplaced(_))
+    }
+
+    /// Whether the customer has to provide a new payment method.
+    #[inline]
+    pub fn requires_customer(&self) -> bool {
+        matches!(self, Self::AccountClosed | Self::ContactCardholder)
+    }
+}
+
+impl<'a> TryFrom<crate::AccountUpdate<'a>> for AccountUpdate {
+    type Error = Error;
+
+    fn try_from(input: crate::AccountUpdate<'a>) -> Result<Self, Self::Error> {
+        Ok(match input {
+            crate::AccountUpdate::ExpiryUpdated(expiry) => Self::ExpiryUpdated(expiry.try_into()?),
+            crate::AccountUpdate::CredentialReplaced(credential) => {
+                Self::CredentialReplaced(credential.try_into()?)
+            }
+            crate::AccountUpdate::AccountClosed => Self::AccountClosed,
+            crate::AccountUpdate::ContactCardholder => Self::ContactCardholder,
+            crate::AccountUpdate::NoMatch => Self::NoMatch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+    use crate::inputs;
+
+    #[test]
+    fn converts_updated_expiry() {
+        let update =
+            AccountUpdate::try_from(inputs::AccountUpdate::ExpiryUpdated(inputs::CardExpiry {
+                month: 4,
+                year: 2031,
+            }))
+            .unwrap();
+
+        match &update {
+            AccountUpdate::ExpiryUpdated(expiry) => unsafe {
+                assert_eq!((expiry.month(), expiry.year()), (4, 2031));
+            },
+            _ => panic!("Expected ExpiryUpdated variant"),
+        }
+        assert!(update.is_refreshed());
+        assert!(!update.requires_customer());
+    }
+
+    #[test]
+    fn converts_replaced_credential() {
+        let update = AccountUpdate::try_from(inputs::AccountUpdate::CredentialReplaced(
+            inputs::UpdatableCredential::Token("tok_1234567890ebadf"),
+        ))
+        .unwrap();
+
+        match update {
+            AccountUpdate::CredentialReplaced(UpdatableCredential::Token(token)) => unsafe {
+                assert_eq!(token.as_ref(), "tok_1234567890ebadf");
+            },
+            _ => panic!("Expected CredentialReplaced variant"),
+        }
+    }
+
+    #[test]
+    fn flags_updates_requiring_customer() {
+        for input in [
+            inputs::AccountUpdate::AccountClosed,
+            inputs::AccountUpdate::ContactCardholder,
+        ] {
+            let update = AccountUpdate::try_from(input).unwrap();
+            assert!(update.requires_customer());
+            assert!(!update.is_refreshed());
+        }
+
+        let no_match = AccountUpdate::try_from(inputs::AccountUpdate::NoMatch).unwrap();
+        assert!(!no_match.requires_customer());
+        assert!(!no_match.is_refreshed());
+    }
+
+    #[test]
+    fn rejects_invalid_replacement() {
+        let result = AccountUpdate::try_from(inputs::AccountUpdate::CredentialReplaced(
+            inputs::UpdatableCredential::Token("short_token"),
+        ));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
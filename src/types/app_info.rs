@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::Error;
+use crate::enums::SdkUiType;
+use crate::internal::Validated;
+
+/// Mobile app information for 3D Secure authentication in the app channel.
+///
+/// # Sanitization
+/// * trims whitespaces,
+/// * normalizes UUIDs to the lowercase hyphenated form,
+/// * removes duplicated UI types.
+///
+/// # Validation
+/// * SDK app ID and SDK transaction ID: UUIDs,
+/// * encrypted data: 1-64000 characters, JWE compact serialization
+///   (5 base64url segments separated by dots, with non-empty header and ciphertext),
+/// * ephemeral public key: 2-1024 characters, JSON object,
+/// * SDK reference number: 1-32 characters, ASCII alphanumeric characters, dashes,
+///   underscores and dots only,
+/// * max timeout: at least 5 minutes,
+/// * UI types: at least one.
+///
+/// # Data Protection
+/// App information is NOT considered PII: the device data is encrypted by the SDK
+/// for the directory server, and the rest are identifiers of the app and the SDK.
+///
+/// Consequently, both `Debug` and getters are implemented without masking,
+/// except the (bulky) encrypted data shown by its length.
+#[derive(Clone)]
+pub struct AppInfo {
+    pub(crate) sdk_app_id: String,
+    pub(crate) sdk_encrypted_data: String,
+    pub(crate) sdk_ephemeral_public_key: String,
+    pub(crate) sdk_reference_number: String,
+    pub(crate) sdk_transaction_id: String,
+    pub(crate) max_timeout: u8,
+    pub(crate) ui_types: Vec<SdkUiType>,
+}
+
+impl AppInfo {
+    /// Unique identifier of the app installation generated by the SDK.
+    #[inline]
+    pub fn sdk_app_id(&self) -> &str {
+        &self.sdk_app_id
+    }
+
+    /// Device data encrypted by the SDK for the directory server (JWE).
+    #[inline]
+    pub fn sdk_encrypted_data(&self) -> &str {
+        &self.sdk_encrypted_data
+    }
+
+    /// Public key of the ephemeral key pair generated by the SDK (JWK).
+    #[inline]
+    pub fn sdk_ephemeral_public_key(&self) -> &str {
+        &self.sdk_ephemeral_public_key
+    }
+
+    /// Identifier of the SDK assigned by EMVCo.
+    #[inline]
+    pub fn sdk_reference_number(&self) -> &str {
+        &self.sdk_reference_number
+    }
+
+    /// Unique identifier of the transaction generated by the SDK.
+    #[inline]
+    pub fn sdk_transaction_id(&self) -> &str {
+        &self.sdk_transaction_id
+    }
+
+    /// Maximum time for the challenge in minutes.
+    #[inline]
+    pub fn max_timeout(&self) -> u8 {
+        self.max_timeout
+    }
+
+    /// Challenge UI types supported by the SDK.
+    #[inline]
+    pub fn ui_types(&self) -> &[SdkUiType] {
+        &self.ui_types
+    }
+}
+
+impl<'a> TryFrom<crate::AppInfo<'a>> for AppInfo {
+    type Error = Error;
+
+    fn try_from(input: crate::AppInfo<'a>) -> Result<Self, Self::Error> {
+        let mut ui_types: Vec<SdkUiType> = Vec::with_capacity(input.ui_types.len());
+        for ui_type in input.ui_types {
+            if !ui_types.contains(ui_type) {
+                ui_types.push(*ui_type);
+            }
+        }
+
+        Self {
+            sdk_app_id: uuid(input.sdk_app_id, "SDK app ID")?,
+            sdk_encrypted_data: input.sdk_encrypted_data.trim().to_string(),
+            sdk_ephemeral_public_key: input.sdk_ephemeral_public_key.trim().to_string(),
+            sdk_reference_number: input.sdk_reference_number.trim().to_string(),
+            sdk_transaction_id: uuid(input.sdk_transaction_id, "SDK transaction ID")?,
+            max_timeout: input.max_timeout,
+            ui_types,
+        }
+        .validate()
+    }
+}
+
+impl fmt::Debug for AppInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppInfo")
+            .field("sdk_app_id", &self.sdk_app_id)
+            .field(
+                "sdk_encrypted_data",
+                &format_args!("[{} characters]", self.sdk_encrypted_data.len()),
+            )
+            .field("sdk_ephemeral_public_key", &self.sdk_ephemeral_public_key)
+            .field("sdk_reference_number", &self.sdk_reference_number)
+            .field("sdk_transaction_id", &self.sdk_transaction_id)
+            .field("max_timeout", &self.max_timeout)
+            .field("ui_types", &self.ui_types)
+            .finish()
+    }
+}
+
+fn uuid(input: &str, name: &str) -> Result<String, Error> {
+    uuid::Uuid::parse_str(input.trim())
+        .map(|id| id.hyphenated().to_string())
+        .map_err(|_| Error::InvalidInput(format!("{name} must be a UUID")))
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for AppInfo {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.sdk_encrypted_data, 1, 64000)?;
+        self._validate_alphanumeric(&self.sdk_encrypted_data, "-_.")?;
+        let segments: Vec<&str> = self.sdk_encrypted_data.split('.').collect();
+        if segments.len() != 5 || segments[0].is_empty() || segments[3].is_empty() {
+            return Err(Error::InvalidInput(
+                "SDK encrypted data must be a JWE in compact serialization".into(),
+            ));
+        }
+
+        self._validate_length(&self.sdk_ephemeral_public_key, 2, 1024)?;
+        if !self.sdk_ephemeral_public_key.starts_with('{')
+            || !self.sdk_ephemeral_public_key.ends_with('}')
+        {
+            return Err(Error::InvalidInput(
+                "SDK ephemeral public key must be a JSON object".into(),
+            ));
+        }
+
+        self._validate_length(&self.sdk_reference_number, 1, 32)?;
+        self._validate_alphanumeric(&self.sdk_reference_number, "-_.")?;
+
+        if self.max_timeout < 5 {
+            return Err(Error::InvalidInput(
+                "SDK max timeout must be at least 5 minutes".into(),
+            ));
+        }
+        if self.ui_types.is_empty() {
+            return Err(Error::InvalidInput(
+                "at least one SDK UI type is required".into(),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENCRYPTED_DATA: &str = "eyJhbGciOiJSU0EtT0FFUC0yNTYifQ.a2V5.aXY.Y2lwaGVy.dGFn";
+
+    fn valid_input() -> crate::AppInfo<'static> {
+        crate::AppInfo {
+            sdk_app_id: " 9063B12C-FCDE-43C7-B28E-8D0AF5520E8A ",
+            sdk_encrypted_data: ENCRYPTED_DATA,
+            sdk_ephemeral_public_key: r#"{"kty":"EC","crv":"P-256","x":"f83O","y":"x_FE"}"#,
+            sdk_reference_number: "3DS_LOA_SDK_ABCD_020100_00001",
+            sdk_transaction_id: "b2385523a66c4907ac3c91848e8c0067",
+            max_timeout: 10,
+            ui_types: &[SdkUiType::Text, SdkUiType::OutOfBand, SdkUiType::Text],
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let info = AppInfo::try_from(valid_input()).unwrap();
+
+        assert_eq!(info.sdk_app_id(), "9063b12c-fcde-43c7-b28e-8d0af5520e8a");
+        assert_eq!(info.sdk_encrypted_data(), ENCRYPTED_DATA);
+        assert_eq!(info.sdk_reference_number(), "3DS_LOA_SDK_ABCD_020100_00001");
+        assert_eq!(
+            info.sdk_transaction_id(),
+            "b2385523-a66c-4907-ac3c-91848e8c0067"
+        );
+        assert_eq!(info.max_timeout(), 10);
+        assert_eq!(info.ui_types(), [SdkUiType::Text, SdkUiType::OutOfBand]);
+    }
+
+    #[test]
+    fn rejects_invalid_app_id() {
+        let mut input = valid_input();
+        input.sdk_app_id = "not-a-uuid";
+
+        let result = AppInfo::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_malformed_encrypted_data() {
+        for data in ["", "a.b.c.d", ".a.b.c.d", "a.b.c..e", "a.b c.d.e.f"] {
+            let mut input = valid_input();
+            input.sdk_encrypted_data = data;
+
+            let result = AppInfo::try_from(input);
+            assert!(
+                matches!(result, Err(Error::InvalidInput(_))),
+                "{data:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_ephemeral_public_key() {
+        let mut input = valid_input();
+        input.sdk_ephemeral_public_key = "f83O";
+
+        let result = AppInfo::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_short_max_timeout() {
+        let mut input = valid_input();
+        input.max_timeout = 4;
+
+        let result = AppInfo::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_empty_ui_types() {
+        let mut input = valid_input();
+        input.ui_types = &[];
+
+        let result = AppInfo::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn hides_encrypted_data_in_debug() {
+        let info = AppInfo::try_from(valid_input()).unwrap();
+        let debug = format!("{info:?}");
+
+        assert!(!debug.contains(ENCRYPTED_DATA));
+        assert!(debug.contains("9063b12c-fcde-43c7-b28e-8d0af5520e8a"));
+    }
+}
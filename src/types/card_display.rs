@@ -0,0 +1,38 @@
+use crate::CardNetwork;
+
+/// Truncated card number with the card network, safe to store and show on receipts
+///
+/// Built from the [`PrimaryAccountNumber`](crate::types::PrimaryAccountNumber)
+/// via its `display` method.
+///
+/// # Data Protection
+/// PCI DSS permits the first 6 and the last 4 digits of the PAN to be stored and displayed,
+/// as they can't be used to restore the card number.
+///
+/// Consequently, both `Debug` and getters are implemented without masking.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CardDisplay {
+    pub(crate) network: Option<CardNetwork>,
+    pub(crate) bin6: String,
+    pub(crate) last4: String,
+}
+
+impl CardDisplay {
+    /// The card network (None for unknown networks).
+    #[inline]
+    pub fn network(&self) -> Option<CardNetwork> {
+        self.network
+    }
+
+    /// The first 6 digits of the card number (bank identification number).
+    #[inline]
+    pub fn bin6(&self) -> &str {
+        &self.bin6
+    }
+
+    /// The last 4 digits of the card number.
+    #[inline]
+    pub fn last4(&self) -> &str {
+        &self.last4
+    }
+}
@@ -3,7 +3,9 @@ use std::convert::TryFrom;
 use zeroize_derive::ZeroizeOnDrop;
 
 use crate::Error;
+use crate::clock::Clock;
 use crate::internal::Validated;
+use crate::types::Date;
 
 /// Card expiration (month and year).
 ///
@@ -17,7 +19,11 @@ use crate::internal::Validated;
 ///
 /// # Validation
 /// * month: 1-12,
-/// * year: 1950-2050
+/// * year: 1950-2050,
+/// * not expired at the current date of the clock (only when constructed
+///   via `try_from_with_clock`)
+///
+/// The card is valid through the last day of the expiry month.
 #[derive(Clone, Debug, Eq, PartialEq, ZeroizeOnDrop)]
 pub struct CardExpiry {
     month: u8,
@@ -50,6 +56,52 @@ impl CardExpiry {
     pub unsafe fn year(&self) -> u16 {
         self.year
     }
+
+    /// Build the card expiry rejecting expired cards by the current date of the clock.
+    pub fn try_from_with_clock(input: crate::CardExpiry, clock: &dyn Clock) -> Result<Self, Error> {
+        let expiry = Self::try_from(input)?;
+        if expiry.is_expired_at(clock.today()) {
+            Err(Error::InvalidInput("the card has expired".into()))
+        } else {
+            Ok(expiry)
+        }
+    }
+
+    /// Whether the card has expired by the date.
+    ///
+    /// The result is not sensitive: it tells only whether the card can be charged at the date,
+    /// which the issuer reveals anyway by declining charges of expired cards.
+    #[inline]
+    pub fn is_expired_at(&self, date: Date) -> bool {
+        (date.year(), date.month()) > (self.year, self.month)
+    }
+
+    /// The number of full months from the date's month to the expiry month
+    /// (0 in the month of expiry, negative after the card has expired).
+    ///
+    /// # SAFETY
+    /// This method is unsafe because the expiry month and year can be derived
+    /// from the result and the date.
+    ///
+    /// Ensure that the result is not leaking into logs, error messages, etc.
+    #[inline]
+    pub unsafe fn months_until_expiry(&self, date: Date) -> i32 {
+        let months = |year: u16, month: u8| i32::from(year) * 12 + i32::from(month);
+        months(self.year, self.month) - months(date.year(), date.month())
+    }
+
+    /// The number of days from the date to the last day of the card validity
+    /// (negative after the card has expired).
+    ///
+    /// # SAFETY
+    /// This method is unsafe because the expiry month and year can be derived
+    /// from the result and the date.
+    ///
+    /// Ensure that the result is not leaking into logs, error messages, etc.
+    #[inline]
+    pub unsafe fn days_until_expiry(&self, date: Date) -> i64 {
+        date.days_until(&Date::last_day_of(self.year, self.month))
+    }
 }
 
 impl TryFrom<crate::CardExpiry> for CardExpiry {
@@ -206,6 +258,56 @@ mod tests {
         }
     }
 
+    mod expiration {
+        use super::*;
+        use crate::clock::FixedClock;
+        use crate::test_support::date;
+
+        fn expiry() -> CardExpiry {
+            CardExpiry::try_from(crate::CardExpiry {
+                month: 6,
+                year: 2025,
+            })
+            .unwrap()
+        }
+
+        #[test]
+        fn expires_after_the_last_day_of_month() {
+            assert!(!expiry().is_expired_at(date(2025, 6, 30)));
+            assert!(expiry().is_expired_at(date(2025, 7, 1)));
+        }
+
+        #[test]
+        fn counts_months_until_expiry() {
+            unsafe {
+                assert_eq!(expiry().months_until_expiry(date(2024, 12, 15)), 6);
+                assert_eq!(expiry().months_until_expiry(date(2025, 6, 30)), 0);
+                assert_eq!(expiry().months_until_expiry(date(2025, 8, 1)), -2);
+            }
+        }
+
+        #[test]
+        fn counts_days_until_expiry() {
+            unsafe {
+                assert_eq!(expiry().days_until_expiry(date(2025, 6, 1)), 29);
+                assert_eq!(expiry().days_until_expiry(date(2025, 7, 1)), -1);
+            }
+        }
+
+        #[test]
+        fn validates_against_clock() {
+            let input = || crate::CardExpiry {
+                month: 6,
+                year: 2025,
+            };
+            // 2025-06-30T12:00:00Z
+            assert!(CardExpiry::try_from_with_clock(input(), &FixedClock(1_751_284_800)).is_ok());
+            // 2025-07-01T12:00:00Z
+            let result = CardExpiry::try_from_with_clock(input(), &FixedClock(1_751_371_200));
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+    }
+
     mod comparison {
         use super::*;
 
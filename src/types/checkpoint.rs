@@ -0,0 +1,132 @@
+use std::convert::TryFrom;
+
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::{TransactionId, TransactionIdempotenceKey};
+
+/// Checkpoint of a payment orchestration
+///
+/// Records the last step completed by an orchestrator for the payment
+/// identified by its idempotence key, so that the orchestration can be resumed
+/// (or recovered) after a crash without repeating the calls to the gateway.
+///
+/// # Validation
+/// * step: 1-64 characters, ASCII alphanumeric characters, dashes, underscores and dots only
+///
+/// # Timestamps
+///
+/// All timestamps are Unix timestamps (seconds since epoch).
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    pub(crate) idempotence_key: TransactionIdempotenceKey,
+    pub(crate) transaction_id: Option<TransactionId>,
+    pub(crate) step: String,
+    pub(crate) recorded_at: i64,
+}
+
+impl Checkpoint {
+    /// The idempotency key of the orchestrated payment.
+    #[inline]
+    pub fn idempotence_key(&self) -> &TransactionIdempotenceKey {
+        &self.idempotence_key
+    }
+
+    /// The transaction ID returned by the gateway (None if the gateway was not called yet).
+    #[inline]
+    pub fn transaction_id(&self) -> Option<&TransactionId> {
+        self.transaction_id.as_ref()
+    }
+
+    /// The name of the last completed step defined by the orchestrator.
+    #[inline]
+    pub fn step(&self) -> &str {
+        &self.step
+    }
+
+    /// The moment the step was completed (Unix timestamp).
+    #[inline]
+    pub fn recorded_at(&self) -> i64 {
+        self.recorded_at
+    }
+}
+
+impl<'a> TryFrom<crate::Checkpoint<'a>> for Checkpoint {
+    type Error = Error;
+
+    fn try_from(input: crate::Checkpoint<'a>) -> Result<Self, Self::Error> {
+        Self {
+            idempotence_key: input.idempotence_key.try_into()?,
+            transaction_id: input.transaction_id.map(TryInto::try_into).transpose()?,
+            step: input.step.trim().to_string(),
+            recorded_at: input.recorded_at,
+        }
+        .validate()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for Checkpoint {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.step, 1, 64)?;
+        self._validate_alphanumeric(&self.step, "-_.")?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+
+    fn valid_input() -> crate::Checkpoint<'static> {
+        crate::Checkpoint {
+            idempotence_key: "order-42",
+            transaction_id: Some(" txn_12345678 "),
+            step: " charged\n",
+            recorded_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let checkpoint = Checkpoint::try_from(valid_input()).unwrap();
+
+        assert_eq!(checkpoint.idempotence_key().as_ref(), "order-42");
+        unsafe {
+            assert_eq!(
+                checkpoint.transaction_id().unwrap().as_ref(),
+                "txn_12345678"
+            );
+        }
+        assert_eq!(checkpoint.step(), "charged");
+        assert_eq!(checkpoint.recorded_at(), 1_700_000_000);
+    }
+
+    #[test]
+    fn accepts_missing_transaction_id() {
+        let mut input = valid_input();
+        input.transaction_id = None;
+
+        let checkpoint = Checkpoint::try_from(input).unwrap();
+        assert!(checkpoint.transaction_id().is_none());
+    }
+
+    #[test]
+    fn rejects_empty_step() {
+        let mut input = valid_input();
+        input.step = " ";
+
+        let result = Checkpoint::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_step() {
+        let mut input = valid_input();
+        input.step = "charged; drop table";
+
+        let result = Checkpoint::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,171 @@
+use std::convert::{AsRef, TryFrom};
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::Error;
+use crate::internal::{Validated, sanitized::*};
+
+/// SEPA Creditor Identifier (CI) of the merchant collecting direct debits
+///
+/// The identifier consists of the country code (ISO 3166-1 alpha-2), 2 check digits,
+/// the creditor business code (3 characters, usually `ZZZ`) and the national identifier
+/// of the creditor (like `DE98ZZZ09999999999`).
+///
+/// # Sanitization
+/// * removes whitespaces and all ASCII control characters like newlines, tabs, etc.,
+/// * converts letters to the upper case.
+///
+/// # Validation
+/// * length: 8-35 characters,
+/// * only alphanumeric characters are allowed,
+/// * starts with the country code followed by 2 digits,
+/// * check digits are valid by [ISO 7064](https://en.wikipedia.org/wiki/ISO/IEC_7064) MOD 97-10
+///   (computed over the national identifier and the country code, excluding the business code).
+///
+/// # Data Protection
+/// Creditor identifiers are public: they are printed on mandates, pre-notifications
+/// and bank statements of the debtors.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, ZeroizeOnDrop)]
+pub struct CreditorId(String);
+
+impl CreditorId {
+    /// The country code of the creditor (ISO 3166-1 alpha-2).
+    #[inline]
+    pub fn country_code(&self) -> &str {
+        self.0.get(0..2).unwrap_or_default()
+    }
+
+    /// The creditor business code (distinguishes business lines of the same creditor).
+    #[inline]
+    pub fn business_code(&self) -> &str {
+        self.0.get(4..7).unwrap_or_default()
+    }
+
+    /// The national identifier of the creditor.
+    #[inline]
+    pub fn national_id(&self) -> &str {
+        self.0.get(7..).unwrap_or_default()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CreditorId {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::sanitize(input).validate()
+    }
+}
+
+impl AsRef<str> for CreditorId {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Sanitized for CreditorId {
+    #[inline]
+    fn sanitize(input: &str) -> Self {
+        let mut output = Self(String::with_capacity(input.len()));
+        filter_characters(&mut output.0, input, "");
+        output.0.make_ascii_uppercase();
+        output
+    }
+}
+
+impl Validated for CreditorId {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.0, 8, 35)?;
+        self._validate_alphanumeric(&self.0, "")?;
+
+        let bytes = self.0.as_bytes();
+        if !bytes[..2].iter().all(u8::is_ascii_alphabetic)
+            || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        {
+            return Err(Error::InvalidInput(format!(
+                "{self:?} must start with the country code and 2 check digits"
+            )));
+        }
+
+        // ISO 7064 MOD 97-10 over the national identifier followed by the country code
+        // and the check digits, where letters are replaced by numbers (A = 10, ..., Z = 35).
+        let remainder = self.0[7..]
+            .chars()
+            .chain(self.0[..4].chars())
+            .filter_map(|c| c.to_digit(36))
+            .fold(0, |remainder, value| {
+                let shift = if value < 10 { 10 } else { 100 };
+                (remainder * shift + value) % 97
+            });
+        if remainder != 1 {
+            return Err(Error::InvalidInput(format!(
+                "{self:?} has invalid check digits"
+            )));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_ID_DE: &str = "DE98ZZZ09999999999";
+
+    mod construction {
+        use super::*;
+
+        #[test]
+        fn accepts_valid_creditor_ids() {
+            for input in [
+                VALID_ID_DE,
+                "FR72ZZZ123456",
+                "ES59ZZZX1234567L",
+                "IT66ZZZA1B2C3D4E5F6G7H8",
+            ] {
+                let result = CreditorId::try_from(input);
+                assert!(result.is_ok(), "{input:?} failed validation");
+            }
+        }
+
+        #[test]
+        fn normalizes_input() {
+            let id = CreditorId::try_from(" de98 zzz 0999 9999 999 \n\t").unwrap();
+            assert_eq!(id.as_ref(), VALID_ID_DE);
+        }
+
+        #[test]
+        fn splits_parts() {
+            let id = CreditorId::try_from(VALID_ID_DE).unwrap();
+            assert_eq!(id.country_code(), "DE");
+            assert_eq!(id.business_code(), "ZZZ");
+            assert_eq!(id.national_id(), "09999999999");
+        }
+
+        #[test]
+        fn ignores_business_code_in_check_digits() {
+            let result = CreditorId::try_from("DE98ABC09999999999");
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn rejects_invalid_check_digits() {
+            for input in ["DE97ZZZ09999999999", "DE98ZZZ09999999998"] {
+                let result = CreditorId::try_from(input);
+                assert!(matches!(result, Err(Error::InvalidInput(_))), "{input:?}");
+            }
+        }
+
+        #[test]
+        fn rejects_invalid_format() {
+            for input in ["DE98ZZZ", "9E98ZZZ09999999999", "DEX8ZZZ09999999999"] {
+                let result = CreditorId::try_from(input);
+                assert!(matches!(result, Err(Error::InvalidInput(_))), "{input:?}");
+            }
+        }
+    }
+}
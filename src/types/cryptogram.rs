@@ -0,0 +1,146 @@
+use std::convert::TryFrom;
+use std::fmt;
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::internal::{Masked, Validated, sanitized::*};
+use crate::{AsUnsafeRef, Error};
+
+/// One-time cryptogram of a network token (TAVV, or the online payment cryptogram of a wallet)
+///
+/// # Sanitization
+/// * trims whitespaces,
+/// * removes all ASCII control characters like newlines, tabs, etc.
+///
+/// # Validation
+/// * length: 16-64 characters,
+/// * Base64 characters only (ASCII alphanumeric characters, `+`, `/` and `=`)
+///
+/// # Data Protection
+/// The cryptogram authorizes a single payment with the token. Exposure could enable
+/// replay attacks, making it sensitive authentication data (SAD).
+///
+/// As such, it is:
+/// * fully masked in logs (via `Debug` implementation) to prevent any leaks,
+/// * not exposed publicly except for a part of a request or response
+///   via **unsafe** method `as_ref`.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct Cryptogram(String);
+
+impl<'a> TryFrom<&'a str> for Cryptogram {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::sanitize(input).validate()
+    }
+}
+
+impl AsUnsafeRef<str> for Cryptogram {
+    #[inline]
+    unsafe fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl fmt::Debug for Cryptogram {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.masked_debug(f)
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Sanitized for Cryptogram {
+    #[inline]
+    fn sanitize(input: &str) -> Self {
+        let mut output = Self(String::with_capacity(input.len()));
+        trim_whitespaces(&mut output.0, input);
+        output
+    }
+}
+
+impl Validated for Cryptogram {
+    #[inline]
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.0, 16, 64)?;
+        self._validate_alphanumeric(&self.0, "+/=")?;
+        Ok(self)
+    }
+}
+
+// SAFETY: The trait is safely implemented as it does NOT expose any part of the cryptogram,
+// fully protecting this sensitive authentication data from exposure in debug output.
+unsafe impl Masked for Cryptogram {
+    const TYPE_WRAPPER: &'static str = "Cryptogram";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_CRYPTOGRAM: &str = "AgAAAAAABk4DWZ4C28yUQAAAAAA=";
+
+    mod construction {
+        use super::*;
+
+        #[test]
+        fn accepts_valid_cryptogram() {
+            let cryptogram = Cryptogram::try_from(" AgAAAAAABk4DWZ4C28yUQAAAAAA=\n").unwrap();
+            assert_eq!(unsafe { cryptogram.as_ref() }, VALID_CRYPTOGRAM);
+        }
+
+        #[test]
+        fn rejects_too_short_cryptogram() {
+            let result = Cryptogram::try_from("AgAAAAAABk4=");
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+
+        #[test]
+        fn rejects_non_base64_characters() {
+            let result = Cryptogram::try_from("AgAAAAAABk4DWZ4C28yU-AAAAAA=");
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+    }
+
+    mod safety {
+        use super::*;
+
+        #[test]
+        fn masks_debug() {
+            let cryptogram = Cryptogram::try_from(VALID_CRYPTOGRAM).unwrap();
+            let debug_output = format!("{:?}", cryptogram);
+            assert!(debug_output.contains(r#"Cryptogram("***")"#));
+            assert!(!debug_output.contains("AgAA"));
+        }
+
+        #[test]
+        fn as_ref_is_unsafe() {
+            static_assertions::assert_not_impl_all!(Cryptogram: AsRef<str>);
+        }
+
+        #[test]
+        fn memory_is_not_leaked_after_drop() {
+            let ptr: *const u8;
+            let len: usize;
+            unsafe {
+                let cryptogram = Cryptogram::try_from(VALID_CRYPTOGRAM).unwrap();
+                let s = cryptogram.as_ref();
+                ptr = s.as_ptr();
+                len = s.len();
+            }
+
+            // SAFETY: This test verifies memory was zeroed after a drop.
+            // Reading potentially freed memory is unsafe and only valid in tests
+            // immediately after a drop, before any reallocation.
+            unsafe {
+                let slice = std::slice::from_raw_parts(ptr, len);
+                assert_ne!(
+                    slice,
+                    VALID_CRYPTOGRAM.as_bytes(),
+                    "Original cryptogram should not remain in memory after drop"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::convert::TryFrom;
+
+use crate::types::{
+    Address, CardExpiry, CustomerId, EmailAddress, FullName, LastDigits, PhoneNumber, Token,
+};
+use crate::{CardNetwork, Error};
+
+/// Billing details of the gateway customer.
+///
+/// When updating the customer, the details left empty (None) are not changed.
+///
+/// # Data Protection
+/// All details are PII, protected at the field level
+/// (see [`FullName`], [`EmailAddress`], [`PhoneNumber`] and [`Address`]).
+#[derive(Clone, Debug, Default)]
+pub struct CustomerDetails {
+    pub(crate) full_name: Option<FullName>,
+    pub(crate) email: Option<EmailAddress>,
+    pub(crate) phone: Option<PhoneNumber>,
+    pub(crate) billing_address: Option<Address>,
+}
+
+impl CustomerDetails {
+    /// Customer full name
+    #[inline]
+    pub fn full_name(&self) -> Option<&FullName> {
+        self.full_name.as_ref()
+    }
+
+    /// Customer email address
+    #[inline]
+    pub fn email(&self) -> Option<&EmailAddress> {
+        self.email.as_ref()
+    }
+
+    /// Customer phone number
+    #[inline]
+    pub fn phone(&self) -> Option<&PhoneNumber> {
+        self.phone.as_ref()
+    }
+
+    /// Customer billing address
+    #[inline]
+    pub fn billing_address(&self) -> Option<&Address> {
+        self.billing_address.as_ref()
+    }
+}
+
+impl<'a> TryFrom<crate::CustomerDetails<'a>> for CustomerDetails {
+    type Error = Error;
+
+    fn try_from(input: crate::CustomerDetails<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            full_name: input.full_name.map(TryFrom::try_from).transpose()?,
+            email: input.email.map(TryFrom::try_from).transpose()?,
+            phone: input.phone.map(TryFrom::try_from).transpose()?,
+            billing_address: input.billing_address.map(TryFrom::try_from).transpose()?,
+        })
+    }
+}
+
+/// Customer record returned by the customer management operations.
+#[derive(Clone, Debug)]
+pub struct Customer {
+    pub(crate) customer_id: CustomerId,
+    pub(crate) details: CustomerDetails,
+}
+
+impl Customer {
+    /// The customer ID assigned by the payment gateway.
+    #[inline]
+    pub fn customer_id(&self) -> &CustomerId {
+        &self.customer_id
+    }
+
+    /// The billing details of the customer.
+    #[inline]
+    pub fn details(&self) -> &CustomerDetails {
+        &self.details
+    }
+}
+
+impl<'a> TryFrom<crate::Customer<'a>> for Customer {
+    type Error = Error;
+
+    fn try_from(input: crate::Customer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            customer_id: input.customer_id.try_into()?,
+            details: input.details.try_into()?,
+        })
+    }
+}
+
+/// Payment method stored for the customer in the gateway vault.
+///
+/// # Data Protection
+/// Carries the display metadata only (network, last 4 digits and expiry),
+/// which PCI DSS permits to store and show to the customer.
+/// The vault token is masked in logs, as it can be used to charge the customer.
+#[derive(Clone, Debug)]
+pub struct CustomerPaymentMethod {
+    pub(crate) token: Token,
+    pub(crate) card_network: Option<CardNetwork>,
+    pub(crate) last4: Option<LastDigits>,
+    pub(crate) card_expiry: Option<CardExpiry>,
+    pub(crate) is_default: bool,
+}
+
+impl CustomerPaymentMethod {
+    /// The vault token of the payment method (to be charged via `Vault`).
+    #[inline]
+    pub fn token(&self) -> &Token {
+        &self.token
+    }
+
+    /// The card network (for cards only).
+    #[inline]
+    pub fn card_network(&self) -> Option<CardNetwork> {
+        self.card_network
+    }
+
+    /// The last 4 digits of the card or bank account number.
+    #[inline]
+    pub fn last4(&self) -> Option<&LastDigits> {
+        self.last4.as_ref()
+    }
+
+    /// The card expiry (for cards only).
+    #[inline]
+    pub fn card_expiry(&self) -> Option<&CardExpiry> {
+        self.card_expiry.as_ref()
+    }
+
+    /// Whether the payment method is the default one of the customer.
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+}
+
+impl<'a> TryFrom<crate::CustomerPaymentMethod<'a>> for CustomerPaymentMethod {
+    type Error = Error;
+
+    fn try_from(input: crate::CustomerPaymentMethod<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            token: input.token.try_into()?,
+            card_network: input.card_network,
+            last4: input.last4.map(TryFrom::try_from).transpose()?,
+            card_expiry: input.card_expiry.map(TryFrom::try_from).transpose()?,
+            is_default: input.is_default,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+    use crate::inputs;
+
+    fn valid_details() -> crate::CustomerDetails<'static> {
+        inputs::CustomerDetails {
+            full_name: Some(" john doe \n\t"),
+            email: Some(" user@example.com \n\t"),
+            phone: Some(" +1234567890 \n\t"),
+            billing_address: Some(inputs::Address {
+                country_code: " US \n\t",
+                postal_code: " 10001 \n\t",
+                city: " New York \n\t",
+                line: " 123 Main St \n\t",
+            }),
+        }
+    }
+
+    fn valid_payment_method() -> crate::CustomerPaymentMethod<'static> {
+        inputs::CustomerPaymentMethod {
+            token: "tok_1234567890ebadf",
+            card_network: Some(CardNetwork::Visa),
+            last4: Some(" 4242 "),
+            card_expiry: Some(inputs::CardExpiry {
+                month: 12,
+                year: 2030,
+            }),
+            is_default: true,
+        }
+    }
+
+    #[test]
+    fn constructs_customer_from_valid_input() {
+        let customer = Customer::try_from(inputs::Customer {
+            customer_id: " cus_123 \n\t",
+            details: valid_details(),
+        })
+        .unwrap();
+
+        let details = customer.details();
+        unsafe {
+            assert_eq!(customer.customer_id().as_ref(), "cus_123");
+            assert_eq!(
+                <EmailAddress as AsUnsafeRef<str>>::as_ref(details.email().unwrap()),
+                "user@example.com"
+            );
+            assert_eq!(
+                <PhoneNumber as AsUnsafeRef<str>>::as_ref(details.phone().unwrap()),
+                "+1234567890"
+            );
+        }
+        assert!(details.full_name().is_some());
+        assert_eq!(
+            details.billing_address().unwrap().city().as_ref(),
+            "New York"
+        );
+    }
+
+    #[test]
+    fn accepts_empty_details() {
+        let details = CustomerDetails::try_from(inputs::CustomerDetails {
+            full_name: None,
+            email: None,
+            phone: None,
+            billing_address: None,
+        })
+        .unwrap();
+
+        assert!(details.full_name().is_none());
+        assert!(details.email().is_none());
+        assert!(details.billing_address().is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_email() {
+        let mut input = valid_details();
+        input.email = Some("not an email");
+
+        let result = CustomerDetails::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn constructs_payment_method_from_valid_input() {
+        let method = CustomerPaymentMethod::try_from(valid_payment_method()).unwrap();
+
+        unsafe {
+            assert_eq!(method.token().as_ref(), "tok_1234567890ebadf");
+        }
+        assert_eq!(method.card_network(), Some(CardNetwork::Visa));
+        assert_eq!(method.last4().unwrap().as_ref(), "4242");
+        assert!(method.card_expiry().is_some());
+        assert!(method.is_default());
+    }
+
+    #[test]
+    fn rejects_invalid_last_digits() {
+        for last4 in ["424", "42424", "42a2"] {
+            let mut input = valid_payment_method();
+            input.last4 = Some(last4);
+
+            let result = CustomerPaymentMethod::try_from(input);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{last4}");
+        }
+    }
+}
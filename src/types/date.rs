@@ -0,0 +1,305 @@
+use std::convert::TryFrom;
+
+use crate::internal::{Validated, days_in_month};
+use crate::{Error, Weekday};
+
+/// Calendar date (in UTC)
+///
+/// # Validation
+/// * year: 1970-2199,
+/// * month: valid 1-12,
+/// * day: valid for the given month and year
+///
+/// # Data Protection
+/// This is a public value, as dates of operations (like billing or expiry checks)
+/// don't identify individuals on their own.
+///
+/// Consequently, both `Debug` and getters are implemented without masking.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    // The order of fields defines the order of dates
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The first and the last supported years.
+const MIN_YEAR: u16 = 1970;
+const MAX_YEAR: u16 = 2199;
+
+/// The days since the Unix epoch of the last supported date (2199-12-31).
+const MAX_DAYS: i64 = 84_005;
+
+impl Date {
+    /// The year.
+    #[inline]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month (1-12).
+    #[inline]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month.
+    #[inline]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The day of the week.
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was Thursday
+        match (self.days() + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// The date of the year, month and day (expected to be validated).
+    pub(crate) fn of(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// The last day of the month (the month and year are expected to be validated).
+    pub(crate) fn last_day_of(year: u16, month: u8) -> Self {
+        Self {
+            year,
+            month,
+            day: days_in_month(&year, &month),
+        }
+    }
+
+    /// The date of the Unix timestamp (in UTC).
+    ///
+    /// Timestamps before 1970 or after 2199 are clamped to the first (1970-01-01)
+    /// or the last (2199-12-31) supported date.
+    pub fn from_timestamp(timestamp: i64) -> Self {
+        Self::from_days(timestamp.div_euclid(SECONDS_PER_DAY))
+    }
+
+    /// The Unix timestamp of the beginning of the day (in UTC).
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.days() * SECONDS_PER_DAY
+    }
+
+    /// The last day of the month.
+    #[inline]
+    pub fn end_of_month(&self) -> Self {
+        Self::last_day_of(self.year, self.month)
+    }
+
+    /// The date shifted by the number of days (backwards for negative numbers).
+    ///
+    /// The result is clamped to the supported dates (1970-01-01 to 2199-12-31).
+    #[inline]
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.days().saturating_add(days))
+    }
+
+    /// The date shifted by the number of days (backwards for negative numbers),
+    /// or None if the result is outside of the supported dates.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        self.days()
+            .checked_add(days)
+            .filter(|days| (0..=MAX_DAYS).contains(days))
+            .map(Self::from_days)
+    }
+
+    /// The date shifted by the number of calendar months (backwards for negative numbers).
+    ///
+    /// The day is clamped to the last day of shorter months (Jan 31 + 1 month = Feb 28/29),
+    /// and the result is clamped to the supported dates (1970-01-01 to 2199-12-31).
+    pub fn add_months(&self, months: i64) -> Self {
+        self.checked_add_months(months)
+            .unwrap_or_else(|| Self::from_days(if months < 0 { 0 } else { MAX_DAYS }))
+    }
+
+    /// The date shifted by the number of calendar months (backwards for negative numbers),
+    /// or None if the result is outside of the supported dates.
+    ///
+    /// The day is clamped to the last day of shorter months (Jan 31 + 1 month = Feb 28/29).
+    pub fn checked_add_months(&self, months: i64) -> Option<Self> {
+        let total = (i64::from(self.year) * 12 + i64::from(self.month) - 1).checked_add(months)?;
+        let year = u16::try_from(total.div_euclid(12))
+            .ok()
+            .filter(|year| (MIN_YEAR..=MAX_YEAR).contains(year))?;
+        let month = (total.rem_euclid(12) + 1) as u8;
+        Some(Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(&year, &month)),
+        })
+    }
+
+    /// The number of days from this date to the other one (negative if the other is earlier).
+    #[inline]
+    pub fn days_until(&self, other: &Self) -> i64 {
+        other.days() - self.days()
+    }
+
+    /// Days since the Unix epoch (the algorithm by Howard Hinnant).
+    fn days(&self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date of the days since the Unix epoch (clamped to the supported dates).
+    fn from_days(days: i64) -> Self {
+        let days = days.clamp(0, MAX_DAYS) + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl TryFrom<crate::Date> for Date {
+    type Error = Error;
+
+    fn try_from(input: crate::Date) -> Result<Self, Self::Error> {
+        Self {
+            year: input.year,
+            month: input.month,
+            day: input.day,
+        }
+        .validate()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for Date {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_year(&self.year, MIN_YEAR, MAX_YEAR)?;
+        self._validate_day(&self.day, &self.month, &self.year)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::date;
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for (year, month, day) in [(2025, 2, 29), (2025, 13, 1), (2025, 4, 31), (1969, 1, 1)] {
+            let result = Date::try_from(crate::Date { day, month, year });
+            assert!(
+                matches!(result, Err(Error::InvalidInput(_))),
+                "{year}-{month}-{day}"
+            );
+        }
+    }
+
+    #[test]
+    fn converts_timestamps() {
+        assert_eq!(Date::from_timestamp(0), date(1970, 1, 1));
+        assert_eq!(Date::from_timestamp(1_709_251_199), date(2024, 2, 29));
+        assert_eq!(date(2024, 3, 1).timestamp(), 1_709_251_200);
+    }
+
+    #[test]
+    fn clamps_timestamps_to_supported_dates() {
+        assert_eq!(Date::from_timestamp(-1), date(1970, 1, 1));
+        assert_eq!(Date::from_timestamp(-1_000_000_000_000), date(1970, 1, 1));
+        assert_eq!(
+            Date::from_timestamp(1_000_000_000_000_000),
+            date(2199, 12, 31)
+        );
+        assert_eq!(Date::from_timestamp(i64::MIN), date(1970, 1, 1));
+        assert_eq!(Date::from_timestamp(i64::MAX), date(2199, 12, 31));
+        assert_eq!(date(2199, 12, 31).days(), MAX_DAYS);
+    }
+
+    #[test]
+    fn adds_days_across_months_and_years() {
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 12, 31).add_days(1), date(2025, 1, 1));
+        assert_eq!(date(2025, 3, 1).add_days(-1), date(2025, 2, 28));
+        assert_eq!(date(2025, 1, 1).days_until(&date(2025, 12, 31)), 364);
+    }
+
+    #[test]
+    fn adds_months_with_clamping_to_month_end() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2025, 1, 31).add_months(1), date(2025, 2, 28));
+        assert_eq!(date(2025, 1, 31).add_months(2), date(2025, 3, 31));
+        assert_eq!(date(2025, 11, 15).add_months(3), date(2026, 2, 15));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+        assert_eq!(date(2025, 3, 31).add_months(-1), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn keeps_shifted_dates_within_supported_range() {
+        assert_eq!(date(2025, 1, 1).add_days(i64::MAX), date(2199, 12, 31));
+        assert_eq!(date(2025, 1, 1).add_days(i64::MIN), date(1970, 1, 1));
+        assert_eq!(date(2025, 1, 31).add_months(i64::MAX), date(2199, 12, 31));
+        assert_eq!(date(2025, 1, 31).add_months(-1_000), date(1970, 1, 1));
+
+        assert_eq!(date(2025, 1, 1).checked_add_days(i64::MAX), None);
+        assert_eq!(date(1970, 1, 1).checked_add_days(-1), None);
+        assert_eq!(date(2199, 12, 31).checked_add_days(1), None);
+        assert_eq!(
+            date(2199, 12, 30).checked_add_days(1),
+            Some(date(2199, 12, 31))
+        );
+        assert_eq!(date(2025, 1, 1).checked_add_months(i64::MIN), None);
+        assert_eq!(date(2199, 12, 1).checked_add_months(1), None);
+        assert_eq!(date(1970, 1, 31).checked_add_months(-1), None);
+        assert_eq!(
+            date(2199, 1, 31).checked_add_months(1),
+            Some(date(2199, 2, 28))
+        );
+    }
+
+    #[test]
+    fn finds_end_of_month() {
+        assert_eq!(date(2024, 2, 10).end_of_month(), date(2024, 2, 29));
+        assert_eq!(date(2025, 4, 1).end_of_month(), date(2025, 4, 30));
+    }
+
+    #[test]
+    fn finds_weekdays() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2025, 1, 6).weekday(), Weekday::Monday);
+        assert_eq!(date(2024, 12, 29).weekday(), Weekday::Sunday);
+        assert!(date(2025, 3, 15).weekday().is_weekend());
+    }
+
+    #[test]
+    fn orders_chronologically() {
+        assert!(date(2024, 12, 31) < date(2025, 1, 1));
+        assert!(date(2025, 1, 2) > date(2025, 1, 1));
+    }
+}
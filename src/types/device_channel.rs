@@ -0,0 +1,108 @@
+use crate::Error;
+use crate::enums::ThreeRIIndicator;
+use crate::types::{AppInfo, BrowserInfo};
+
+/// Channel of the 3D Secure authentication with the channel-specific data.
+///
+/// # Data Protection
+/// Neither browser nor app information is considered PII
+/// (see [`BrowserInfo`] and [`AppInfo`]).
+#[derive(Clone, Debug)]
+pub enum DeviceChannel {
+    /// The cardholder is authenticated in the browser.
+    Browser(BrowserInfo),
+    /// The cardholder is authenticated in the mobile app via the 3DS SDK.
+    App(AppInfo),
+    /// The authentication is initiated by the merchant without the cardholder (3RI).
+    RequestorInitiated(ThreeRIIndicator),
+}
+
+impl DeviceChannel {
+    /// Whether the cardholder takes part in the authentication,
+    /// so that a challenge can be requested.
+    #[inline]
+    pub fn is_cardholder_present(&self) -> bool {
+        !matches!(self, Self::RequestorInitiated(_))
+    }
+}
+
+impl<'a> TryFrom<crate::DeviceChannel<'a>> for DeviceChannel {
+    type Error = Error;
+
+    fn try_from(input: crate::DeviceChannel<'a>) -> Result<Self, Self::Error> {
+        Ok(match input {
+            crate::DeviceChannel::Browser(info) => Self::Browser(info.try_into()?),
+            crate::DeviceChannel::App(info) => Self::App(info.try_into()?),
+            crate::DeviceChannel::RequestorInitiated { indicator } => {
+                Self::RequestorInitiated(indicator)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::SdkUiType;
+
+    fn app_input() -> crate::AppInfo<'static> {
+        crate::AppInfo {
+            sdk_app_id: "9063b12c-fcde-43c7-b28e-8d0af5520e8a",
+            sdk_encrypted_data: "eyJhbGciOiJSU0EtT0FFUC0yNTYifQ.a2V5.aXY.Y2lwaGVy.dGFn",
+            sdk_ephemeral_public_key: r#"{"kty":"EC","crv":"P-256"}"#,
+            sdk_reference_number: "3DS_LOA_SDK_ABCD_020100_00001",
+            sdk_transaction_id: "b2385523-a66c-4907-ac3c-91848e8c0067",
+            max_timeout: 5,
+            ui_types: &[SdkUiType::Text],
+        }
+    }
+
+    #[test]
+    fn constructs_browser_channel() {
+        let input = crate::DeviceChannel::Browser(crate::BrowserInfo {
+            accept_header: "text/html",
+            user_agent: "Mozilla/5.0",
+            language: None,
+            color_depth: None,
+            screen_height: None,
+            screen_width: None,
+            time_zone_offset: None,
+            java_enabled: None,
+            javascript_enabled: Some(true),
+        });
+
+        let channel = DeviceChannel::try_from(input).unwrap();
+        assert!(matches!(channel, DeviceChannel::Browser(_)));
+        assert!(channel.is_cardholder_present());
+    }
+
+    #[test]
+    fn constructs_app_channel() {
+        let channel = DeviceChannel::try_from(crate::DeviceChannel::App(app_input())).unwrap();
+
+        let DeviceChannel::App(info) = &channel else {
+            panic!("expected app channel");
+        };
+        assert_eq!(info.max_timeout(), 5);
+        assert!(channel.is_cardholder_present());
+    }
+
+    #[test]
+    fn constructs_requestor_initiated_channel() {
+        let input = crate::DeviceChannel::RequestorInitiated {
+            indicator: ThreeRIIndicator::Recurring,
+        };
+
+        let channel = DeviceChannel::try_from(input).unwrap();
+        assert!(!channel.is_cardholder_present());
+    }
+
+    #[test]
+    fn rejects_invalid_app_info() {
+        let mut input = app_input();
+        input.max_timeout = 1;
+
+        let result = DeviceChannel::try_from(crate::DeviceChannel::App(input));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
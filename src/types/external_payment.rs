@@ -56,11 +56,16 @@ mod tests {
                 transaction_id: " txn_12345678 \n\t",
                 idempotence_key: " idempotence-key-123 \n\t",
                 status: TransactionStatus::Captured,
+                decline_reason: None,
                 currency: Currency::USD,
                 recipients: None,
                 merchant_initiated_type: Some(MerchantInitiatedType::Recurring),
+                network_transaction_id: None,
+            },
+            payment_data: inputs::ExternalPaymentData::DeepLink {
+                url: "bankapp://pay?id=123",
+                fallback_url: None,
             },
-            payment_data: Default::default(),
         }
     }
 
@@ -1,22 +1,76 @@
+mod bank_transfer;
+mod deep_link;
+mod qr_code;
+mod voucher;
+
 use crate::Error;
 
+pub use bank_transfer::BankTransfer;
+pub use deep_link::DeepLink;
+pub use qr_code::QRCode;
+pub use voucher::Voucher;
+
 /// Payment completion data for external payment flows.
 ///
-/// Contains information needed to complete an external payment, such as
-/// * Redirect URL for customer authentication (BNPL, online banking)
+/// Contains the instructions for the customer to complete an external payment:
 /// * Voucher codes or reference numbers (cash voucher systems)
 /// * QR code data (mobile payments)
 /// * Bank transfer instructions (account number, reference code)
+/// * Deep link to the app of the payment provider
 ///
-/// The structure is currently empty as the specific fields depend on the payment method
-/// and gateway implementation. Gateway adapters should extend this with method-specific data.
+/// Redirects for customer authentication (BNPL, online banking)
+/// are provided as a `RequiredAction` instead.
 #[derive(Clone, Debug)]
-pub struct ExternalPaymentData {}
+pub enum ExternalPaymentData {
+    /// Voucher to be paid in cash.
+    Voucher(Voucher),
+    /// QR code to be scanned by a mobile app.
+    QRCode(QRCode),
+    /// Instructions for a bank transfer.
+    BankTransfer(BankTransfer),
+    /// Link opening the app of the payment provider.
+    DeepLink(DeepLink),
+}
 
 impl<'a> TryFrom<crate::ExternalPaymentData<'a>> for ExternalPaymentData {
     type Error = Error;
 
-    fn try_from(_input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
-        Ok(Self {})
+    fn try_from(input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::ExternalPaymentData::Voucher { .. } => Ok(Self::Voucher(input.try_into()?)),
+            crate::ExternalPaymentData::QRCode { .. } => Ok(Self::QRCode(input.try_into()?)),
+            crate::ExternalPaymentData::BankTransfer { .. } => {
+                Ok(Self::BankTransfer(input.try_into()?))
+            }
+            crate::ExternalPaymentData::DeepLink { .. } => Ok(Self::DeepLink(input.try_into()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructs_deep_link_variant() {
+        let input = crate::ExternalPaymentData::DeepLink {
+            url: "bankapp://pay?id=123",
+            fallback_url: None,
+        };
+
+        let data = ExternalPaymentData::try_from(input).unwrap();
+        assert!(matches!(data, ExternalPaymentData::DeepLink(_)));
+    }
+
+    #[test]
+    fn constructs_qr_code_variant() {
+        let input = crate::ExternalPaymentData::QRCode {
+            payload: "00020126580014br.gov.bcb.pix",
+            image: None,
+            expires_at: None,
+        };
+
+        let data = ExternalPaymentData::try_from(input).unwrap();
+        assert!(matches!(data, ExternalPaymentData::QRCode(_)));
     }
 }
@@ -0,0 +1,209 @@
+use rust_decimal::Decimal;
+
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::{AccountNumber, BankCode, IBAN};
+
+/// Instructions for a bank transfer made by the customer.
+///
+/// # Sanitization
+/// * trims whitespaces of the reference
+///
+/// # Validation
+/// * either IBAN or account number is required,
+/// * bank code is applicable to account numbers only,
+/// * reference: 1-140 characters, ASCII alphanumeric characters, spaces, dashes, slashes and dots only,
+/// * amount: must be positive.
+///
+/// # Data Protection
+/// Bank details of the beneficiary follow the rules of their types
+/// (masked in logs and exposed via **unsafe** methods only).
+/// The reference and the amount are shown to the customer, and are not masked.
+#[derive(Clone, Debug)]
+pub struct BankTransfer {
+    iban: Option<IBAN>,
+    account_number: Option<AccountNumber>,
+    bank_code: Option<BankCode>,
+    reference: String,
+    amount: Decimal,
+}
+
+impl BankTransfer {
+    /// The IBAN of the beneficiary.
+    #[inline]
+    pub fn iban(&self) -> Option<&IBAN> {
+        self.iban.as_ref()
+    }
+
+    /// The account number of the beneficiary (for non-SEPA transfers).
+    #[inline]
+    pub fn account_number(&self) -> Option<&AccountNumber> {
+        self.account_number.as_ref()
+    }
+
+    /// The bank code of the beneficiary (for non-SEPA transfers).
+    #[inline]
+    pub fn bank_code(&self) -> Option<&BankCode> {
+        self.bank_code.as_ref()
+    }
+
+    /// The reference to be provided by the customer to match the transfer.
+    #[inline]
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// The amount to be transferred.
+    #[inline]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
+
+impl<'a> TryFrom<crate::ExternalPaymentData<'a>> for BankTransfer {
+    type Error = Error;
+
+    fn try_from(input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::ExternalPaymentData::BankTransfer {
+                iban,
+                account_number,
+                bank_code,
+                reference,
+                amount,
+            } => Self {
+                iban: iban.map(TryFrom::try_from).transpose()?,
+                account_number: account_number.map(TryFrom::try_from).transpose()?,
+                bank_code: bank_code.map(TryFrom::try_from).transpose()?,
+                reference: reference.trim().to_string(),
+                amount,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid external payment data type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for BankTransfer {
+    fn validate(self) -> Result<Self, Error> {
+        match (&self.iban, &self.account_number, &self.bank_code) {
+            (Some(_), None, None) | (None, Some(_), _) => {}
+            (None, None, _) => {
+                return Err(Error::InvalidInput(format!(
+                    "{self:?} requires either IBAN or account number"
+                )));
+            }
+            (Some(_), _, _) => {
+                return Err(Error::InvalidInput(format!(
+                    "{self:?} can't combine IBAN with account number or bank code"
+                )));
+            }
+        }
+        self._validate_length(&self.reference, 1, 140)?;
+        self._validate_alphanumeric(&self.reference, " -/.")?;
+        if self.amount <= Decimal::ZERO {
+            return Err(Error::InvalidInput(format!(
+                "{self:?} amount must be positive"
+            )));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+    use rust_decimal_macros::dec;
+
+    fn input<'a>(
+        iban: Option<&'a str>,
+        account_number: Option<&'a str>,
+        reference: &'a str,
+        amount: Decimal,
+    ) -> crate::ExternalPaymentData<'a> {
+        crate::ExternalPaymentData::BankTransfer {
+            iban,
+            account_number,
+            bank_code: None,
+            reference,
+            amount,
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let transfer = BankTransfer::try_from(input(
+            Some("DE89370400440532013000"),
+            None,
+            " ORDER-42 ",
+            dec!(19.99),
+        ))
+        .unwrap();
+
+        unsafe {
+            assert_eq!(transfer.iban().unwrap().as_ref(), "DE89370400440532013000");
+        }
+        assert!(transfer.account_number().is_none());
+        assert_eq!(transfer.reference(), "ORDER-42");
+        assert_eq!(transfer.amount(), dec!(19.99));
+    }
+
+    #[test]
+    fn accepts_account_number_with_bank_code() {
+        let input = crate::ExternalPaymentData::BankTransfer {
+            iban: None,
+            account_number: Some("123456789"),
+            bank_code: Some("021000021"),
+            reference: "ORDER-42",
+            amount: dec!(19.99),
+        };
+
+        assert!(BankTransfer::try_from(input).is_ok());
+    }
+
+    #[test]
+    fn masks_bank_details() {
+        let transfer = BankTransfer::try_from(input(
+            Some("DE89370400440532013000"),
+            None,
+            "ORDER-42",
+            dec!(19.99),
+        ))
+        .unwrap();
+
+        assert!(!format!("{transfer:?}").contains("370400440532013"));
+    }
+
+    #[test]
+    fn rejects_missing_beneficiary() {
+        let result = BankTransfer::try_from(input(None, None, "ORDER-42", dec!(19.99)));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_reference() {
+        let result = BankTransfer::try_from(input(
+            Some("DE89370400440532013000"),
+            None,
+            "ORDER#42",
+            dec!(19.99),
+        ));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_non_positive_amount() {
+        let result = BankTransfer::try_from(input(
+            Some("DE89370400440532013000"),
+            None,
+            "ORDER-42",
+            dec!(0),
+        ));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
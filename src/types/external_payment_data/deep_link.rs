@@ -0,0 +1,170 @@
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::Url;
+
+/// Link opening the app of the payment provider.
+///
+/// # Sanitization
+/// * trims whitespaces of all values,
+/// * lowercases the scheme of the url (schemes are case-insensitive)
+///
+/// # Validation
+/// * url: URL with a custom scheme (alphanumeric characters, `+`, `-` and `.`) or `https`,
+///   except for script, data and insecure web schemes (like `javascript` or `http`),
+/// * fallback URL: validated as [`Url`].
+///
+/// # Data Protection
+/// The link contains no customer data, and is shown to the customer as is.
+///
+/// Consequently, its values are neither masked nor protected.
+#[derive(Clone, Debug)]
+pub struct DeepLink {
+    url: String,
+    fallback_url: Option<Url>,
+}
+
+impl DeepLink {
+    /// The app link (like `bankapp://pay?id=123` or a universal `https` link).
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The web page to open when the app is not installed.
+    #[inline]
+    pub fn fallback_url(&self) -> Option<&Url> {
+        self.fallback_url.as_ref()
+    }
+}
+
+impl<'a> TryFrom<crate::ExternalPaymentData<'a>> for DeepLink {
+    type Error = Error;
+
+    fn try_from(input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::ExternalPaymentData::DeepLink { url, fallback_url } => Self {
+                url: lowercase_scheme(url.trim()),
+                fallback_url: fallback_url.map(TryFrom::try_from).transpose()?,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid external payment data type",
+            ))),
+        }
+    }
+}
+
+/// Schemes executing scripts, embedding content or opening insecure pages,
+/// that must never be rendered as links to the customer.
+const DENIED_SCHEMES: [&str; 14] = [
+    "about",
+    "blob",
+    "data",
+    "file",
+    "filesystem",
+    "ftp",
+    "http",
+    "javascript",
+    "jar",
+    "livescript",
+    "vbscript",
+    "view-source",
+    "ws",
+    "wss",
+];
+
+/// The URL with the lowercased scheme (the rest of the URL is kept as is).
+fn lowercase_scheme(url: &str) -> String {
+    match url.split_once("://") {
+        Some((scheme, rest)) => format!("{}://{rest}", scheme.to_ascii_lowercase()),
+        None => url.to_string(),
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for DeepLink {
+    fn validate(self) -> Result<Self, Error> {
+        // Custom schemes are defined by the apps, so only their syntax can be checked
+        let scheme = self.url.split_once("://").map(|(scheme, _)| scheme);
+        match scheme {
+            Some(scheme)
+                if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && !DENIED_SCHEMES.contains(&scheme) =>
+            {
+                self._validate_alphanumeric(scheme, "+-.")?;
+                self._validate_url(&self.url, &[scheme])?;
+            }
+            _ => {
+                return Err(Error::InvalidInput(format!(
+                    "{self:?} URL has an invalid scheme"
+                )));
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let input = crate::ExternalPaymentData::DeepLink {
+            url: " bankapp://pay?id=123 ",
+            fallback_url: Some("https://gateway.example.com/pay/123"),
+        };
+
+        let link = DeepLink::try_from(input).unwrap();
+        assert_eq!(link.url(), "bankapp://pay?id=123");
+        assert_eq!(
+            link.fallback_url().map(Url::as_str),
+            Some("https://gateway.example.com/pay/123")
+        );
+    }
+
+    #[test]
+    fn lowercases_scheme() {
+        let input = crate::ExternalPaymentData::DeepLink {
+            url: "BankApp://pay?id=ABC",
+            fallback_url: None,
+        };
+
+        let link = DeepLink::try_from(input).unwrap();
+        assert_eq!(link.url(), "bankapp://pay?id=ABC");
+    }
+
+    #[test]
+    fn accepts_universal_link() {
+        let input = crate::ExternalPaymentData::DeepLink {
+            url: "https://pay.example.com/app/123",
+            fallback_url: None,
+        };
+
+        assert!(DeepLink::try_from(input).is_ok());
+    }
+
+    #[test]
+    fn rejects_dangerous_schemes() {
+        for url in [
+            "javascript://alert(1)",
+            "JavaScript://%0aalert(1)",
+            "vbscript://x",
+            "VBScript://x",
+            "Data://text/html,<script>alert(1)</script>",
+            "http://pay.example.com",
+            "HTTP://pay.example.com",
+            "ws://pay.example.com",
+            "pay?id=123",
+        ] {
+            let input = crate::ExternalPaymentData::DeepLink {
+                url,
+                fallback_url: None,
+            };
+
+            let result = DeepLink::try_from(input);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{url}");
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::Error;
+use crate::internal::Validated;
+
+/// QR code to be scanned by a mobile app (PIX, UPI, WeChat Pay, etc.).
+///
+/// # Sanitization
+/// * trims whitespaces of all values
+///
+/// # Validation
+/// * payload: 1-4096 characters without control characters,
+/// * image: `https` URL or `data:image/...` URI.
+///
+/// # Data Protection
+/// The QR code contains no customer data, and is shown to the customer as is.
+///
+/// Consequently, its values are neither masked nor protected.
+#[derive(Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct QRCode {
+    payload: String,
+    image: Option<String>,
+    expires_at: Option<i64>,
+}
+
+impl QRCode {
+    /// The content encoded in the QR code (can be copied instead of scanning).
+    #[inline]
+    pub fn payload(&self) -> &str {
+        &self.payload
+    }
+
+    /// The URL (or the `data:image/...` URI) of the QR code image.
+    #[inline]
+    pub fn image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+
+    /// The moment the QR code expires (Unix timestamp).
+    #[inline]
+    pub fn expires_at(&self) -> Option<i64> {
+        self.expires_at
+    }
+}
+
+impl<'a> TryFrom<crate::ExternalPaymentData<'a>> for QRCode {
+    type Error = Error;
+
+    fn try_from(input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::ExternalPaymentData::QRCode {
+                payload,
+                image,
+                expires_at,
+            } => Self {
+                payload: payload.trim().to_string(),
+                image: image.map(|image| image.trim().to_string()),
+                expires_at,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid external payment data type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for QRCode {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.payload, 1, 4096)?;
+        if self.payload.chars().any(char::is_control) {
+            return Err(Error::InvalidInput(format!(
+                "{self:?} payload contains control characters"
+            )));
+        }
+        match &self.image {
+            Some(image) if !image.starts_with("data:image/") => {
+                self._validate_url(image, &["https"])?
+            }
+            _ => {}
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &str = "00020126580014br.gov.bcb.pix0136123e4567-e12b-12d1-a456-4266554400005204000053039865802BR5913Fulano de Tal6008BRASILIA62070503***63041D3D";
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let input = crate::ExternalPaymentData::QRCode {
+            payload: PAYLOAD,
+            image: Some("data:image/png;base64,iVBORw0KGgo="),
+            expires_at: Some(1_767_225_600),
+        };
+
+        let qr_code = QRCode::try_from(input).unwrap();
+        assert_eq!(qr_code.payload(), PAYLOAD);
+        assert_eq!(qr_code.image(), Some("data:image/png;base64,iVBORw0KGgo="));
+        assert_eq!(qr_code.expires_at(), Some(1_767_225_600));
+    }
+
+    #[test]
+    fn accepts_image_url() {
+        let input = crate::ExternalPaymentData::QRCode {
+            payload: PAYLOAD,
+            image: Some("https://gateway.example.com/qr/123.png"),
+            expires_at: None,
+        };
+
+        assert!(QRCode::try_from(input).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_payload() {
+        let input = crate::ExternalPaymentData::QRCode {
+            payload: " ",
+            image: None,
+            expires_at: None,
+        };
+
+        let result = QRCode::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_image() {
+        let input = crate::ExternalPaymentData::QRCode {
+            payload: PAYLOAD,
+            image: Some("javascript:alert(1)"),
+            expires_at: None,
+        };
+
+        let result = QRCode::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
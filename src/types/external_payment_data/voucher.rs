@@ -0,0 +1,168 @@
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::Url;
+
+/// Voucher to be paid in cash (Boleto, OXXO, Konbini, etc.).
+///
+/// # Sanitization
+/// * trims whitespaces of all values
+///
+/// # Validation
+/// * barcode: 1-128 characters, ASCII alphanumeric characters only,
+/// * digitable line: 1-128 characters, digits, spaces and dots only,
+/// * printable URL: validated as [`Url`].
+///
+/// # Data Protection
+/// The voucher contains no customer data, and is shown to the customer as is.
+///
+/// Consequently, its values are neither masked nor protected.
+#[derive(Clone, Debug)]
+pub struct Voucher {
+    barcode: String,
+    digitable_line: Option<String>,
+    expires_at: Option<i64>,
+    printable_url: Option<Url>,
+}
+
+impl Voucher {
+    /// The barcode (or the reference number) of the voucher.
+    #[inline]
+    pub fn barcode(&self) -> &str {
+        &self.barcode
+    }
+
+    /// The human-readable line to type the voucher in a banking app.
+    #[inline]
+    pub fn digitable_line(&self) -> Option<&str> {
+        self.digitable_line.as_deref()
+    }
+
+    /// The moment the voucher expires (Unix timestamp).
+    #[inline]
+    pub fn expires_at(&self) -> Option<i64> {
+        self.expires_at
+    }
+
+    /// The URL of the printable voucher.
+    #[inline]
+    pub fn printable_url(&self) -> Option<&Url> {
+        self.printable_url.as_ref()
+    }
+}
+
+impl<'a> TryFrom<crate::ExternalPaymentData<'a>> for Voucher {
+    type Error = Error;
+
+    fn try_from(input: crate::ExternalPaymentData<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::ExternalPaymentData::Voucher {
+                barcode,
+                digitable_line,
+                expires_at,
+                printable_url,
+            } => Self {
+                barcode: barcode.trim().to_string(),
+                digitable_line: digitable_line.map(|line| line.trim().to_string()),
+                expires_at,
+                printable_url: printable_url.map(TryFrom::try_from).transpose()?,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid external payment data type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for Voucher {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.barcode, 1, 128)?;
+        self._validate_alphanumeric(&self.barcode, "")?;
+        if let Some(line) = &self.digitable_line {
+            self._validate_length(line, 1, 128)?;
+            self._validate_digits(line, " .")?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_input() -> crate::ExternalPaymentData<'static> {
+        crate::ExternalPaymentData::Voucher {
+            barcode: " 23793381286000000000000000000000000000000000 ",
+            digitable_line: Some("23793.38128 60000.000003 00000.000000 0 00000000000000"),
+            expires_at: Some(1_767_225_600),
+            printable_url: Some("https://gateway.example.com/boleto/123.pdf"),
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let voucher = Voucher::try_from(valid_input()).unwrap();
+
+        assert_eq!(
+            voucher.barcode(),
+            "23793381286000000000000000000000000000000000"
+        );
+        assert_eq!(
+            voucher.digitable_line(),
+            Some("23793.38128 60000.000003 00000.000000 0 00000000000000")
+        );
+        assert_eq!(voucher.expires_at(), Some(1_767_225_600));
+        assert_eq!(
+            voucher.printable_url().map(Url::as_str),
+            Some("https://gateway.example.com/boleto/123.pdf")
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_barcode() {
+        let crate::ExternalPaymentData::Voucher {
+            digitable_line,
+            expires_at,
+            printable_url,
+            ..
+        } = valid_input()
+        else {
+            unreachable!()
+        };
+        let input = crate::ExternalPaymentData::Voucher {
+            barcode: "2379-3381",
+            digitable_line,
+            expires_at,
+            printable_url,
+        };
+
+        let result = Voucher::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_insecure_printable_url() {
+        let input = crate::ExternalPaymentData::Voucher {
+            barcode: "23793381286",
+            digitable_line: None,
+            expires_at: None,
+            printable_url: Some("http://gateway.example.com/boleto/123.pdf"),
+        };
+
+        let result = Voucher::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_other_variants() {
+        let input = crate::ExternalPaymentData::DeepLink {
+            url: "bankapp://pay?id=123",
+            fallback_url: None,
+        };
+
+        let result = Voucher::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
@@ -20,7 +20,7 @@ pub use no_installments::NoInstallments;
 // --- Marker Traits ---
 
 /// Marker trait for types that can be used as installment payment options.
-pub(crate) trait InstallmentsMarker {}
+pub(crate) trait InstallmentsMarker: Send {}
 
 impl InstallmentsMarker for NoInstallments {}
 impl InstallmentsMarker for Installments {}
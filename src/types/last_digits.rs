@@ -0,0 +1,93 @@
+use std::convert::{AsRef, TryFrom};
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::Error;
+use crate::internal::{Validated, sanitized::*};
+
+/// The last 4 digits of the card or bank account number, shown to the customer
+/// to recognize the stored payment method
+///
+/// # Sanitization
+/// * trims whitespaces,
+/// * removes all ASCII control characters like newlines, tabs, etc.
+///
+/// # Validation
+/// * length: exactly 4 characters,
+/// * only ASCII digits are allowed
+///
+/// # Data Protection
+/// PCI DSS permits the last 4 digits of the PAN to be stored and displayed,
+/// as they can't be used to restore the number.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(Clone, Debug, Eq, PartialEq, ZeroizeOnDrop)]
+pub struct LastDigits(String);
+
+impl<'a> TryFrom<&'a str> for LastDigits {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::sanitize(input).validate()
+    }
+}
+
+impl AsRef<str> for LastDigits {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Sanitized for LastDigits {
+    #[inline]
+    fn sanitize(input: &str) -> Self {
+        let mut output = Self(String::with_capacity(input.len()));
+        trim_whitespaces(&mut output.0, input);
+        output
+    }
+}
+
+impl Validated for LastDigits {
+    #[inline]
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.0, 4, 4)?;
+        self._validate_digits(&self.0, "")?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_digits() {
+        for input in ["4242", "0000", " 1234 \n\t"] {
+            let result = LastDigits::try_from(input);
+            assert!(result.is_ok(), "{input:?} failed validation");
+        }
+    }
+
+    #[test]
+    fn removes_control_characters() {
+        let digits = LastDigits::try_from(" 4242 \n\t\r ").unwrap();
+        assert_eq!(digits.as_ref(), "4242");
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        for input in ["", "424", "42424", "42a2", "42 2"] {
+            let result = LastDigits::try_from(input);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn shows_digits_in_debug() {
+        let digits = LastDigits::try_from("4242").unwrap();
+        assert_eq!(format!("{digits:?}"), r#"LastDigits("4242")"#);
+    }
+}
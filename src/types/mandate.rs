@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use crate::types::{CreditorId, CustomerId, Date, MandateReference};
+use crate::{Error, MandateSequence, MandateStatus};
+
+/// Direct debit mandate (SEPA Direct Debit, ACH debit, Bacs, etc.)
+///
+/// The mandate authorizes the creditor to collect direct debits from the debtor's account.
+/// It is created by `Gateway::authorize` for bank debits, and managed via
+/// [`ManageMandates`](crate::flows::ManageMandates).
+#[derive(Clone, Debug)]
+pub struct Mandate {
+    pub(crate) mandate_reference: MandateReference,
+    pub(crate) signed_on: Date,
+    pub(crate) sequence: MandateSequence,
+    pub(crate) creditor_id: Option<CreditorId>,
+    pub(crate) customer_id: Option<CustomerId>,
+    pub(crate) status: MandateStatus,
+}
+
+impl Mandate {
+    /// The unique mandate reference assigned by the creditor.
+    #[inline]
+    pub fn mandate_reference(&self) -> &MandateReference {
+        &self.mandate_reference
+    }
+
+    /// The date the debtor signed the mandate.
+    #[inline]
+    pub fn signed_on(&self) -> Date {
+        self.signed_on
+    }
+
+    /// The sequence type of the next collection under the mandate.
+    #[inline]
+    pub fn sequence(&self) -> MandateSequence {
+        self.sequence
+    }
+
+    /// The SEPA creditor identifier (None for non-SEPA debits like ACH).
+    #[inline]
+    pub fn creditor_id(&self) -> Option<&CreditorId> {
+        self.creditor_id.as_ref()
+    }
+
+    /// The gateway customer the mandate belongs to.
+    #[inline]
+    pub fn customer_id(&self) -> Option<&CustomerId> {
+        self.customer_id.as_ref()
+    }
+
+    /// The current status of the mandate.
+    #[inline]
+    pub fn status(&self) -> MandateStatus {
+        self.status
+    }
+
+    /// Whether direct debits can be collected under the mandate.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+}
+
+impl<'a> TryFrom<crate::Mandate<'a>> for Mandate {
+    type Error = Error;
+
+    fn try_from(input: crate::Mandate<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mandate_reference: input.mandate_reference.try_into()?,
+            signed_on: input.signed_on.try_into()?,
+            sequence: input.sequence,
+            creditor_id: input.creditor_id.map(TryFrom::try_from).transpose()?,
+            customer_id: input.customer_id.map(TryFrom::try_from).transpose()?,
+            status: input.status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+    use crate::inputs;
+
+    fn valid_input() -> crate::Mandate<'static> {
+        inputs::Mandate {
+            mandate_reference: " MANDATE-2025/0042 \n\t",
+            signed_on: inputs::Date {
+                day: 15,
+                month: 1,
+                year: 2025,
+            },
+            sequence: MandateSequence::First,
+            creditor_id: Some("DE98 ZZZ0 9999 9999 99"),
+            customer_id: Some("cus_123"),
+            status: MandateStatus::Active,
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let mandate = Mandate::try_from(valid_input()).unwrap();
+
+        assert_eq!(mandate.mandate_reference().as_ref(), "MANDATE-2025/0042");
+        assert_eq!(
+            (mandate.signed_on().year(), mandate.signed_on().month()),
+            (2025, 1)
+        );
+        assert_eq!(mandate.sequence(), MandateSequence::First);
+        assert_eq!(
+            mandate.creditor_id().unwrap().as_ref(),
+            "DE98ZZZ09999999999"
+        );
+        assert_eq!(
+            unsafe { mandate.customer_id().unwrap().as_ref() },
+            "cus_123"
+        );
+        assert!(mandate.is_active());
+    }
+
+    #[test]
+    fn accepts_mandate_without_creditor_id() {
+        let mut input = valid_input();
+        input.creditor_id = None;
+
+        let mandate = Mandate::try_from(input).unwrap();
+        assert!(mandate.creditor_id().is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_creditor_id() {
+        let mut input = valid_input();
+        input.creditor_id = Some("DE97ZZZ09999999999");
+
+        let result = Mandate::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
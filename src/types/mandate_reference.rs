@@ -0,0 +1,86 @@
+use std::convert::{AsRef, TryFrom};
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::Error;
+use crate::internal::{Validated, sanitized::*};
+
+/// Unique mandate reference (UMR) assigned by the creditor to the direct debit mandate
+///
+/// # Sanitization
+/// * trims whitespaces,
+/// * removes all ASCII control characters like newlines, tabs, etc.
+///
+/// # Validation
+/// * length: 1-35 characters,
+/// * only alphanumeric characters, spaces and `/-?:().,'+` are allowed (the SEPA character set)
+///
+/// # Data Protection
+/// Mandate references are printed on pre-notifications and bank statements of the debtors,
+/// and can't be used to initiate debits without the creditor's account at the bank.
+///
+/// Consequently, both `Debug` and `AsRef` are implemented without masking.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, ZeroizeOnDrop)]
+pub struct MandateReference(String);
+
+impl<'a> TryFrom<&'a str> for MandateReference {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::sanitize(input).validate()
+    }
+}
+
+impl AsRef<str> for MandateReference {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Sanitized for MandateReference {
+    #[inline]
+    fn sanitize(input: &str) -> Self {
+        let mut output = Self(String::with_capacity(input.len()));
+        trim_whitespaces(&mut output.0, input);
+        output
+    }
+}
+
+impl Validated for MandateReference {
+    #[inline]
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.0, 1, 35)?;
+        self._validate_alphanumeric(&self.0, " /-?:().,'+")?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_references() {
+        for input in ["MANDATE-2025/0042", "1", "a".repeat(35).as_str()] {
+            let result = MandateReference::try_from(input);
+            assert!(result.is_ok(), "{input:?} failed validation");
+        }
+    }
+
+    #[test]
+    fn removes_control_characters() {
+        let reference = MandateReference::try_from(" MANDATE-42 \n\t").unwrap();
+        assert_eq!(reference.as_ref(), "MANDATE-42");
+    }
+
+    #[test]
+    fn rejects_invalid_references() {
+        for input in ["", "a".repeat(36).as_str(), "MANDATE_42", "MANDATE#42"] {
+            let result = MandateReference::try_from(input);
+            assert!(matches!(result, Err(Error::InvalidInput(_))), "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::convert::TryFrom;
+use std::fmt;
+use zeroize_derive::ZeroizeOnDrop;
+
+use crate::internal::{Masked, Validated, sanitized::*};
+use crate::{AsUnsafeRef, Error};
+
+/// Network transaction identifier assigned by the card scheme
+/// (Visa Transaction ID, Mastercard Trace ID / Banknet reference, etc.)
+///
+/// Unlike [`TransactionId`](crate::types::TransactionId) assigned by the payment gateway,
+/// the network transaction ID of the initial Customer Initiated Transaction
+/// is what card networks require to link the subsequent Merchant Initiated Transactions
+/// to the original agreement of the cardholder.
+///
+/// # Sanitization
+/// * trims whitespaces,
+/// * removes all ASCII control characters like newlines, tabs, etc.
+///
+/// # Validation
+/// * length: 9-64 characters,
+/// * only alphanumeric characters are allowed
+///
+/// # Data Protection
+/// While not classified as sensitive by PCI DSS, network transaction identifiers
+/// can be used to initiate merchant initiated charges on the stored credentials,
+/// requiring access control at the highest level.
+///
+/// As such, they are:
+/// * masked in logs (via `Debug` implementation) to display
+///   the first and last characters (both in the upper case) only,
+/// * not exposed publicly except for a part of a request or response
+///   via **unsafe** method `as_ref`.
+#[derive(Clone, PartialEq, Eq, ZeroizeOnDrop)]
+pub struct NetworkTransactionId(String);
+
+impl<'a> TryFrom<&'a str> for NetworkTransactionId {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::sanitize(input).validate()
+    }
+}
+
+impl fmt::Debug for NetworkTransactionId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.masked_debug(f)
+    }
+}
+
+impl AsUnsafeRef<str> for NetworkTransactionId {
+    #[inline]
+    unsafe fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Sanitized for NetworkTransactionId {
+    #[inline]
+    fn sanitize(input: &str) -> Self {
+        let mut output = Self(String::with_capacity(input.len()));
+        trim_whitespaces(&mut output.0, input);
+        output
+    }
+}
+
+impl Validated for NetworkTransactionId {
+    #[inline]
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.0, 9, 64)?;
+        self._validate_alphanumeric(&self.0, "")?;
+        Ok(self)
+    }
+}
+
+// SAFETY: The trait is safely implemented because exposing the first 1 and last 1 character:
+// 1. Neither causes out-of-bounds access to potentially INVALID (empty) data,
+//    due to fallbacks to the empty strings,
+// 2. Nor leaks the essential part of the sensitive VALID data which has at least 9 chars,
+//    while also hiding the real length and case of the network transaction ID.
+unsafe impl Masked for NetworkTransactionId {
+    const TYPE_WRAPPER: &'static str = "NetworkTransactionId";
+
+    #[inline]
+    fn first_chars(&self) -> String {
+        self.0.get(0..1).unwrap_or_default().to_uppercase()
+    }
+
+    #[inline]
+    fn last_chars(&self) -> String {
+        self.0
+            .get(self.0.len() - 1..)
+            .unwrap_or_default()
+            .to_uppercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_ID: &str = "583112345678901";
+
+    mod construction {
+        use super::*;
+
+        #[test]
+        fn accepts_valid_network_transaction_ids() {
+            for input in [VALID_ID, "MCC123456", "a".repeat(64).as_str()] {
+                let result = NetworkTransactionId::try_from(input);
+                assert!(result.is_ok(), "{input:?} failed validation");
+            }
+        }
+
+        #[test]
+        fn removes_control_characters() {
+            let input = " 583112345678901 \n\t\r ";
+            let id = NetworkTransactionId::try_from(input).unwrap();
+            let result = unsafe { id.as_ref() };
+            assert_eq!(result, VALID_ID);
+        }
+
+        #[test]
+        fn rejects_too_short_id() {
+            let result = NetworkTransactionId::try_from("12345678");
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+
+        #[test]
+        fn rejects_too_long_id() {
+            let input = "a".repeat(65);
+            let result = NetworkTransactionId::try_from(input.as_str());
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+
+        #[test]
+        fn rejects_gateway_style_id() {
+            let result = NetworkTransactionId::try_from("txn_12345678");
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+    }
+
+    mod safety {
+        use super::*;
+
+        #[test]
+        fn masks_debug() {
+            let id = NetworkTransactionId::try_from(VALID_ID).unwrap();
+            let debug_output = format!("{:?}", id);
+            assert!(debug_output.contains(r#"NetworkTransactionId("5***1")"#));
+        }
+    }
+}
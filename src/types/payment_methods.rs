@@ -7,11 +7,17 @@ mod cash_voucher;
 mod credit_card;
 mod crypto_payment;
 mod direct_carrier_billing;
+mod externally_authenticated;
 mod instant_payment;
+mod network_token;
 mod secured_payment;
 mod sepa;
 mod stored_credential;
 mod vault;
+mod wallet_card;
+
+use crate::types::AccountUpdate;
+use crate::{CardNetwork, Error};
 
 // --- Types ---
 
@@ -21,29 +27,65 @@ pub use cash_voucher::CashVoucher;
 pub use credit_card::CreditCard;
 pub use crypto_payment::CryptoPayment;
 pub use direct_carrier_billing::DirectCarrierBilling;
+pub use externally_authenticated::ExternallyAuthenticated;
 pub use instant_payment::InstantAccount;
+pub use network_token::NetworkToken;
 pub use secured_payment::SecuredPayment;
 pub use sepa::SEPA;
 pub use stored_credential::StoredCredential;
 pub use vault::Vault;
+pub use wallet_card::WalletCard;
 // --- Marker traits  ---
 
 /// Marker trait for types that can be used as payment methods.
-pub(crate) trait PaymentMethod {}
-impl PaymentMethod for BankPayment {}
-impl PaymentMethod for BNPL {}
-impl PaymentMethod for CashVoucher {}
-impl PaymentMethod for CreditCard {}
-impl PaymentMethod for CryptoPayment {}
-impl PaymentMethod for DirectCarrierBilling {}
-impl PaymentMethod for InstantAccount {}
-impl PaymentMethod for SEPA {}
-impl PaymentMethod for Vault {}
+pub(crate) trait PaymentMethod: Send {
+    /// The kind of the payment method used to label logs and metrics.
+    const KIND: &'static str;
+}
+impl PaymentMethod for BankPayment {
+    const KIND: &'static str = "bank_payment";
+}
+impl PaymentMethod for BNPL {
+    const KIND: &'static str = "bnpl";
+}
+impl PaymentMethod for CashVoucher {
+    const KIND: &'static str = "cash_voucher";
+}
+impl PaymentMethod for CreditCard {
+    const KIND: &'static str = "credit_card";
+}
+impl PaymentMethod for CryptoPayment {
+    const KIND: &'static str = "crypto_payment";
+}
+impl PaymentMethod for DirectCarrierBilling {
+    const KIND: &'static str = "direct_carrier_billing";
+}
+impl PaymentMethod for InstantAccount {
+    const KIND: &'static str = "instant_account";
+}
+impl PaymentMethod for NetworkToken {
+    const KIND: &'static str = "network_token";
+}
+impl PaymentMethod for SEPA {
+    const KIND: &'static str = "sepa";
+}
+impl PaymentMethod for Vault {
+    const KIND: &'static str = "vault";
+}
+impl PaymentMethod for WalletCard {
+    const KIND: &'static str = "wallet_card";
+}
 
 /// Marker trait for payment methods that can be used
 /// in the internal payment flows (`ThreeDSecure`, `ImmediatePayments`, `DeferredPayments`, `Token`).
 pub(crate) trait InternalPaymentMethod: PaymentMethod {}
 
+/// Marker trait for card payment methods, that can be authenticated via 3D Secure.
+pub(crate) trait CardPaymentMethod: InternalPaymentMethod {
+    /// The card network detected by the card (or token) number.
+    fn card_network(&self) -> Option<CardNetwork>;
+}
+
 /// Marker trait for payment methods that can be used
 /// in the external payment flows (`CashVoucher`, `BNPL`, `CreditCard`, `InstantAccount`, `SEPAAccount`, `Token`).
 pub(crate) trait ExternalPaymentMethod: PaymentMethod {}
@@ -52,4 +94,10 @@ pub(crate) trait ExternalPaymentMethod: PaymentMethod {}
 pub(crate) trait StorablePaymentMethod: InternalPaymentMethod {}
 
 /// Marker trait for Vault payment methods
-pub(crate) trait VaultPaymentMethod: InternalPaymentMethod {}
+pub(crate) trait VaultPaymentMethod: InternalPaymentMethod {
+    /// The payment method refreshed by the account updater
+    /// (None if the update keeps the stored credential as is).
+    fn refreshed(&self, update: &AccountUpdate) -> Result<Option<Self>, Error>
+    where
+        Self: Sized;
+}
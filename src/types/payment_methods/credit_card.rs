@@ -1,7 +1,11 @@
 use std::convert::TryFrom;
 
-use crate::Error;
-use crate::types::{CVV, CardExpiry, CardHolderName, InternalPaymentMethod, PrimaryAccountNumber};
+use crate::clock::Clock;
+use crate::types::{
+    CVV, CardDisplay, CardExpiry, CardHolderName, CardPaymentMethod, InternalPaymentMethod,
+    PrimaryAccountNumber,
+};
+use crate::{AsUnsafeRef, CardNetwork, Error};
 
 /// Credit or Debit Card
 ///
@@ -143,29 +147,69 @@ impl CreditCard {
     pub fn holder_name(&self) -> &CardHolderName {
         &self.holder_name
     }
+
+    /// Card network detected by the card number (None for unknown networks)
+    #[inline]
+    pub fn network(&self) -> Option<CardNetwork> {
+        self.number.network()
+    }
+
+    /// Truncated card number safe to store and show on receipts
+    #[inline]
+    pub fn display(&self) -> CardDisplay {
+        self.number.display()
+    }
+
+    /// Build the card rejecting expired cards by the current date of the clock
+    pub fn try_from_with_clock(
+        input: crate::CreditCard<'_>,
+        clock: &dyn Clock,
+    ) -> Result<Self, Error> {
+        let card = Self::try_from(input)?;
+        if card.card_expiry.is_expired_at(clock.today()) {
+            Err(Error::InvalidInput("the card has expired".into()))
+        } else {
+            Ok(card)
+        }
+    }
 }
 
 // Marker implementations
 
 impl InternalPaymentMethod for CreditCard {}
 
+impl CardPaymentMethod for CreditCard {
+    #[inline]
+    fn card_network(&self) -> Option<CardNetwork> {
+        self.network()
+    }
+}
+
 impl<'a> TryFrom<crate::CreditCard<'a>> for CreditCard {
     type Error = Error;
 
     fn try_from(value: crate::CreditCard<'a>) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let card = Self {
             cvv: value.cvv.try_into()?,
             number: value.number.try_into()?,
             card_expiry: value.card_expiry.try_into()?,
             holder_name: value.holder_name.try_into()?,
-        })
+        };
+
+        // SAFETY: only the length of the CVV is read
+        let cvv_length = unsafe { card.cvv.as_ref() }.len();
+        match card.network() {
+            Some(network) if network.cvv_length() != cvv_length => Err(Error::InvalidInput(
+                format!("{network} cards require {}-digit CVV", network.cvv_length()),
+            )),
+            _ => Ok(card),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::AsUnsafeRef;
     use crate::inputs;
 
     fn valid_input() -> crate::CreditCard<'static> {
@@ -203,6 +247,44 @@ mod tests {
         assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
+    #[test]
+    fn rejects_cvv_length_invalid_for_network() {
+        let mut input = valid_input();
+        input.cvv = "1234";
+
+        let result = CreditCard::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+        let mut input = valid_input();
+        input.number = "374245455400126";
+        assert!(matches!(
+            CreditCard::try_from(input),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn accepts_four_digit_cvv_for_amex() {
+        let mut input = valid_input();
+        input.number = "374245455400126";
+        input.cvv = "1234";
+
+        let card = CreditCard::try_from(input).unwrap();
+        assert_eq!(card.network(), Some(CardNetwork::Amex));
+        assert_eq!(card.display().last4(), "0126");
+    }
+
+    #[test]
+    fn rejects_expired_card_by_clock() {
+        use crate::clock::FixedClock;
+
+        // 2031-01-01T00:00:00Z
+        let result = CreditCard::try_from_with_clock(valid_input(), &FixedClock(1_924_992_000));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+        // 2030-12-31T00:00:00Z
+        assert!(CreditCard::try_from_with_clock(valid_input(), &FixedClock(1_924_905_600)).is_ok());
+    }
+
     #[test]
     fn rejects_invalid_pan() {
         let mut input = valid_input();
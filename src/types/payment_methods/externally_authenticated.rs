@@ -0,0 +1,151 @@
+use crate::Error;
+use crate::types::{CardPaymentMethod, InternalPaymentMethod, PaymentMethod, SecuredPayment};
+
+/// Payment Method Authenticated Outside the Gateway
+///
+/// ## Overview
+///
+/// Wraps a card payment method (`CreditCard` or `NetworkToken`) together with the result
+/// of 3D Secure authentication performed by the merchant's own 3DS server
+/// (standalone MPI). The gateway only authorizes the payment with the provided
+/// CAVV, ECI, DS transaction ID and protocol version.
+///
+/// ## Flow
+///
+/// 1. **Authentication**: The merchant's 3DS server authenticates the cardholder
+/// 2. **Import**: The result is validated via `ExternalAuthentication` (ECI per card network,
+///    the network must match the one detected by the card number)
+/// 3. **Payment**: `Payment<ExternallyAuthenticated<CreditCard>>` is passed directly
+///    to `ImmediatePayments::charge` or `DeferredPayments::authorize`,
+///    skipping `Gateway::secure` (a passthrough for this payment method)
+///
+/// ```skip
+/// let payment: Payment<ExternallyAuthenticated<CreditCard>> = inputs::Payment {
+///     payment_method: inputs::ExternallyAuthenticated {
+///         payment_method: inputs::CreditCard { ... },
+///         authentication: inputs::ExternalAuthentication { ... },
+///     },
+///     ...
+/// }.try_into()?;
+///
+/// let transaction = gateway.charge(payment, NoInstallments, None, None).await?;
+/// ```
+///
+/// ## Security Considerations
+///
+/// The wrapped payment method keeps its own data protection guarantees,
+/// the authentication values are protected as in `SecuredPayment`.
+#[derive(Clone, Debug)]
+#[allow(private_bounds)]
+pub struct ExternallyAuthenticated<P: CardPaymentMethod> {
+    payment_method: P,
+    authentication: SecuredPayment,
+}
+
+#[allow(private_bounds)]
+impl<P: CardPaymentMethod> ExternallyAuthenticated<P> {
+    /// The authenticated payment method.
+    #[inline]
+    pub fn payment_method(&self) -> &P {
+        &self.payment_method
+    }
+
+    /// The result of the external 3D Secure authentication.
+    #[inline]
+    pub fn authentication(&self) -> &SecuredPayment {
+        &self.authentication
+    }
+}
+
+impl<P: CardPaymentMethod> PaymentMethod for ExternallyAuthenticated<P> {
+    const KIND: &'static str = P::KIND;
+}
+
+impl<P: CardPaymentMethod> InternalPaymentMethod for ExternallyAuthenticated<P> {}
+
+impl<'a, M, P> TryFrom<crate::ExternallyAuthenticated<'a, M>> for ExternallyAuthenticated<P>
+where
+    P: CardPaymentMethod + TryFrom<M, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(input: crate::ExternallyAuthenticated<'a, M>) -> Result<Self, Self::Error> {
+        let payment_method: P = input.payment_method.try_into()?;
+        let network = input.authentication.network;
+        if payment_method.card_network() != Some(network) {
+            return Err(Error::InvalidInput(format!(
+                "Card was authenticated as {network}, but belongs to another network"
+            )));
+        }
+        Ok(Self {
+            payment_method,
+            authentication: input.authentication.try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CreditCard;
+    use crate::{CardNetwork, ECI, ThreeDSVersion, inputs};
+    use uuid::Uuid;
+
+    fn valid_input() -> inputs::ExternallyAuthenticated<'static, inputs::CreditCard<'static>> {
+        inputs::ExternallyAuthenticated {
+            payment_method: inputs::CreditCard {
+                cvv: "123",
+                number: "4532015112830366",
+                card_expiry: inputs::CardExpiry {
+                    month: 12,
+                    year: 2030,
+                },
+                holder_name: "John Doe",
+            },
+            authentication: inputs::ExternalAuthentication {
+                network: CardNetwork::Visa,
+                cavv: Some("AAABBBCCCdddeeefff111222333"),
+                eci: "05",
+                ds_transaction_id: Uuid::parse_str("64d76f6d-e512-4aba-ae29-f7af0dc7db09").unwrap(),
+                version: ThreeDSVersion::V2_3_0,
+            },
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let method = ExternallyAuthenticated::<CreditCard>::try_from(valid_input()).unwrap();
+
+        assert_eq!(method.authentication().eci(), Some(ECI::FullyAuthenticated));
+        assert_eq!(
+            method.authentication().version(),
+            Some(ThreeDSVersion::V2_3_0)
+        );
+    }
+
+    #[test]
+    fn inherits_kind_of_payment_method() {
+        assert_eq!(
+            <ExternallyAuthenticated<CreditCard> as PaymentMethod>::KIND,
+            "credit_card"
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_network() {
+        let mut input = valid_input();
+        input.payment_method.number = "5555555555554444";
+
+        let result = ExternallyAuthenticated::<CreditCard>::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_authentication() {
+        let mut input = valid_input();
+        input.authentication.eci = "02";
+
+        let result = ExternallyAuthenticated::<CreditCard>::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
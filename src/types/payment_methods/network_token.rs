@@ -0,0 +1,201 @@
+use std::convert::TryFrom;
+
+use crate::types::{
+    CardDisplay, CardExpiry, CardPaymentMethod, Cryptogram, InternalPaymentMethod,
+    PrimaryAccountNumber,
+};
+use crate::{CardNetwork, ECI, Error, Wallet};
+
+/// Network Token
+///
+/// ## Overview
+///
+/// Card payment with a network token instead of the card number. The token (DPAN)
+/// is issued by the card network for a specific device or merchant, and every payment
+/// is authorized with a fresh one-time cryptogram generated for the token.
+///
+/// ## When to Use
+///
+/// - **Wallet payments**: Decrypted Apple Pay, Google Pay and Samsung Pay payloads
+/// - **Merchant network tokens**: Tokens provisioned via Visa Token Service or Mastercard MDES
+///
+/// ## Authentication Model
+///
+/// > Authentication is **proven by the cryptogram**!
+///
+/// The wallet authenticates the cardholder on the device (biometrics, passcode),
+/// and the cryptogram binds the authentication to the payment. Wallet payments
+/// usually satisfy SCA without a separate 3DS step.
+///
+/// ## Standards
+///
+/// - **[EMV Payment Tokenisation](https://www.emvco.com/emv-technologies/payment-tokenisation/)**: Token specification
+///
+/// ## Security Considerations
+///
+/// - **Token number**: Protected as `PrimaryAccountNumber` (masked, zeroized)
+/// - **Cryptogram**: Single use, masked in logs and zeroized on a drop, never stored
+///
+/// # Validation
+/// * number: valid PAN of a known card network,
+/// * ECI: valid code for the network of the token.
+#[derive(Clone, Debug)]
+pub struct NetworkToken {
+    pub(crate) number: PrimaryAccountNumber,
+    pub(crate) network: CardNetwork,
+    pub(crate) token_expiry: CardExpiry,
+    pub(crate) cryptogram: Cryptogram,
+    pub(crate) eci: Option<ECI>,
+    pub(crate) wallet: Option<Wallet>,
+}
+
+impl NetworkToken {
+    /// The token number (device or merchant PAN)
+    #[inline]
+    pub fn number(&self) -> &PrimaryAccountNumber {
+        &self.number
+    }
+
+    /// The expiry of the token
+    #[inline]
+    pub fn token_expiry(&self) -> &CardExpiry {
+        &self.token_expiry
+    }
+
+    /// The one-time cryptogram
+    #[inline]
+    pub fn cryptogram(&self) -> &Cryptogram {
+        &self.cryptogram
+    }
+
+    /// Electronic Commerce Indicator returned with the cryptogram
+    #[inline]
+    pub fn eci(&self) -> Option<ECI> {
+        self.eci
+    }
+
+    /// The wallet which provisioned the token (None for merchant network tokens)
+    #[inline]
+    pub fn wallet(&self) -> Option<Wallet> {
+        self.wallet
+    }
+
+    /// The card network of the token
+    #[inline]
+    pub fn network(&self) -> CardNetwork {
+        self.network
+    }
+
+    /// Truncated token number safe to store and show on receipts
+    #[inline]
+    pub fn display(&self) -> CardDisplay {
+        self.number.display()
+    }
+}
+
+// Marker implementations
+
+impl InternalPaymentMethod for NetworkToken {}
+
+impl CardPaymentMethod for NetworkToken {
+    #[inline]
+    fn card_network(&self) -> Option<CardNetwork> {
+        Some(self.network())
+    }
+}
+
+impl<'a> TryFrom<crate::NetworkToken<'a>> for NetworkToken {
+    type Error = Error;
+
+    fn try_from(input: crate::NetworkToken<'a>) -> Result<Self, Self::Error> {
+        let number = PrimaryAccountNumber::try_from(input.number)?;
+        let network = number
+            .network()
+            .ok_or_else(|| Error::InvalidInput("network of the token is unknown".into()))?;
+
+        Ok(Self {
+            number,
+            network,
+            token_expiry: input.token_expiry.try_into()?,
+            cryptogram: input.cryptogram.try_into()?,
+            eci: input
+                .eci
+                .map(|code| ECI::from_code(code, network))
+                .transpose()?,
+            wallet: input.wallet,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs;
+    use crate::types::Payment;
+    use iso_currency::Currency;
+    use rust_decimal::Decimal;
+
+    fn valid_input() -> inputs::NetworkToken<'static> {
+        inputs::NetworkToken {
+            number: "4895370012003478",
+            token_expiry: inputs::CardExpiry {
+                month: 12,
+                year: 2030,
+            },
+            cryptogram: "AgAAAAAABk4DWZ4C28yUQAAAAAA=",
+            eci: Some("07"),
+            wallet: Some(Wallet::ApplePay),
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let token = NetworkToken::try_from(valid_input()).unwrap();
+
+        assert_eq!(token.network(), CardNetwork::Visa);
+        assert_eq!(token.eci(), Some(ECI::NoAuthentication));
+        assert_eq!(token.wallet(), Some(Wallet::ApplePay));
+        assert_eq!(token.display().last4(), "3478");
+    }
+
+    #[test]
+    fn rejects_eci_of_another_network() {
+        let mut input = valid_input();
+        input.eci = Some("02");
+
+        let result = NetworkToken::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_cryptogram() {
+        let mut input = valid_input();
+        input.cryptogram = "short";
+
+        let result = NetworkToken::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn masks_sensitive_data_in_debug() {
+        let token = NetworkToken::try_from(valid_input()).unwrap();
+        let debug = format!("{token:?}");
+
+        assert!(!debug.contains("4895370012003478"));
+        assert!(!debug.contains("AgAAAAAABk4"));
+    }
+
+    #[test]
+    fn used_as_payment_method() {
+        let payment = Payment::<NetworkToken>::try_from(inputs::Payment {
+            payment_method: valid_input(),
+            currency: Currency::EUR,
+            total_amount: Decimal::new(1999, 2),
+            base_amount: Decimal::new(1999, 2),
+            idempotence_key: "order-42",
+        })
+        .unwrap();
+
+        assert_eq!(payment.payment_method().network(), CardNetwork::Visa);
+    }
+}
@@ -1,7 +1,7 @@
 use uuid::Uuid;
 
 use crate::Error;
-use crate::enums::{ECI, ThreeDSVersion};
+use crate::enums::{ECI, ExemptionOutcome, ScaExemption, ThreeDSVersion};
 use crate::types::{CAVV, ThreeDSecureToken};
 
 /// Result of 3D Secure authentication.
@@ -17,6 +17,10 @@ use crate::types::{CAVV, ThreeDSecureToken};
 ///
 /// This unified design allows callers to handle 3DS results uniformly regardless
 /// of the underlying gateway implementation.
+///
+/// # Validation
+/// * exemption outcome: requires the exemption unless `NotApplicable`,
+/// * rejected exemption: the decline reason must be soft.
 #[derive(Clone, Debug)]
 pub struct SecuredPayment {
     pub(crate) token: Option<ThreeDSecureToken>,
@@ -24,6 +28,8 @@ pub struct SecuredPayment {
     pub(crate) eci: Option<ECI>,
     pub(crate) ds_transaction_id: Option<Uuid>,
     pub(crate) version: Option<ThreeDSVersion>,
+    pub(crate) exemption: Option<ScaExemption>,
+    pub(crate) exemption_outcome: Option<ExemptionOutcome>,
 }
 
 impl SecuredPayment {
@@ -56,18 +62,76 @@ impl SecuredPayment {
     pub fn version(&self) -> Option<ThreeDSVersion> {
         self.version
     }
+
+    /// SCA exemption requested in the secure step.
+    #[inline]
+    pub fn exemption(&self) -> Option<ScaExemption> {
+        self.exemption
+    }
+
+    /// Outcome of the requested SCA exemption.
+    #[inline]
+    pub fn exemption_outcome(&self) -> Option<ExemptionOutcome> {
+        self.exemption_outcome
+    }
 }
 
 impl TryFrom<crate::inputs::SecuredPayment<'_>> for SecuredPayment {
     type Error = Error;
 
     fn try_from(input: crate::inputs::SecuredPayment<'_>) -> Result<Self, Self::Error> {
+        match (input.exemption, input.exemption_outcome) {
+            (None, Some(ExemptionOutcome::Granted | ExemptionOutcome::Rejected(_))) => {
+                return Err(Error::InvalidInput(
+                    "exemption outcome requires the requested exemption".into(),
+                ));
+            }
+            (_, Some(ExemptionOutcome::Rejected(reason))) if !reason.is_soft() => {
+                return Err(Error::InvalidInput(format!(
+                    "exemption can't be rejected with a hard decline ({reason})"
+                )));
+            }
+            _ => {}
+        }
+
         Ok(Self {
             token: input.token.map(ThreeDSecureToken::try_from).transpose()?,
             cavv: input.cavv.map(CAVV::try_from).transpose()?,
             eci: input.eci,
             ds_transaction_id: input.ds_transaction_id,
             version: input.version,
+            exemption: input.exemption,
+            exemption_outcome: input.exemption_outcome,
+        })
+    }
+}
+
+impl TryFrom<crate::inputs::ExternalAuthentication<'_>> for SecuredPayment {
+    type Error = Error;
+
+    /// Import the result of the authentication by the merchant's own 3DS server.
+    ///
+    /// # Validation
+    /// * ECI: valid code for the card network,
+    /// * CAVV: required unless the ECI reports no authentication.
+    fn try_from(input: crate::inputs::ExternalAuthentication<'_>) -> Result<Self, Self::Error> {
+        let eci = ECI::from_code(input.eci, input.network)?;
+        let cavv = input.cavv.map(CAVV::try_from).transpose()?;
+        if cavv.is_none() && eci != ECI::NoAuthentication {
+            return Err(Error::InvalidInput(format!(
+                "CAVV is required for ECI {}",
+                input.eci.trim()
+            )));
+        }
+
+        Ok(Self {
+            token: None,
+            cavv,
+            eci: Some(eci),
+            ds_transaction_id: Some(input.ds_transaction_id),
+            version: Some(input.version),
+            exemption: None,
+            exemption_outcome: None,
         })
     }
 }
@@ -76,6 +140,7 @@ impl TryFrom<crate::inputs::SecuredPayment<'_>> for SecuredPayment {
 mod tests {
     use super::*;
     use crate::inputs;
+    use crate::{CardNetwork, DeclineReason};
 
     fn valid_input() -> inputs::SecuredPayment<'static> {
         inputs::SecuredPayment {
@@ -86,6 +151,10 @@ mod tests {
                 Uuid::parse_str("64d76f6d-e512-4aba-ae29-f7af0dc7db09").unwrap(),
             ),
             version: Some(ThreeDSVersion::V2_2_0),
+            exemption: Some(ScaExemption::TransactionRiskAnalysis),
+            exemption_outcome: Some(ExemptionOutcome::Rejected(
+                DeclineReason::AuthenticationRequired,
+            )),
         }
     }
 
@@ -99,6 +168,16 @@ mod tests {
         assert_eq!(result.eci(), Some(ECI::FullyAuthenticated));
         assert!(result.ds_transaction_id().is_some());
         assert_eq!(result.version(), Some(ThreeDSVersion::V2_2_0));
+        assert_eq!(
+            result.exemption(),
+            Some(ScaExemption::TransactionRiskAnalysis)
+        );
+        assert_eq!(
+            result.exemption_outcome(),
+            Some(ExemptionOutcome::Rejected(
+                DeclineReason::AuthenticationRequired
+            ))
+        );
     }
 
     #[test]
@@ -109,6 +188,8 @@ mod tests {
             eci: None,
             ds_transaction_id: None,
             version: None,
+            exemption: None,
+            exemption_outcome: None,
         };
         let result = SecuredPayment::try_from(input).unwrap();
 
@@ -117,6 +198,35 @@ mod tests {
         assert!(result.eci().is_none());
         assert!(result.ds_transaction_id().is_none());
         assert!(result.version().is_none());
+        assert!(result.exemption().is_none());
+        assert!(result.exemption_outcome().is_none());
+    }
+
+    #[test]
+    fn rejects_outcome_without_exemption() {
+        let mut input = valid_input();
+        input.exemption = None;
+
+        let result = SecuredPayment::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn accepts_not_applicable_outcome_without_exemption() {
+        let mut input = valid_input();
+        input.exemption = None;
+        input.exemption_outcome = Some(ExemptionOutcome::NotApplicable);
+
+        assert!(SecuredPayment::try_from(input).is_ok());
+    }
+
+    #[test]
+    fn rejects_exemption_rejected_with_hard_decline() {
+        let mut input = valid_input();
+        input.exemption_outcome = Some(ExemptionOutcome::Rejected(DeclineReason::SuspectedFraud));
+
+        let result = SecuredPayment::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -136,4 +246,47 @@ mod tests {
         let result = SecuredPayment::try_from(input);
         assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
+
+    fn external_input() -> inputs::ExternalAuthentication<'static> {
+        inputs::ExternalAuthentication {
+            network: CardNetwork::Mastercard,
+            cavv: Some("AAABBBCCCdddeeefff111222333"),
+            eci: "02",
+            ds_transaction_id: Uuid::parse_str("64d76f6d-e512-4aba-ae29-f7af0dc7db09").unwrap(),
+            version: ThreeDSVersion::V2_3_1,
+        }
+    }
+
+    #[test]
+    fn imported_from_external_authentication() {
+        let result = SecuredPayment::try_from(external_input()).unwrap();
+
+        assert!(result.token().is_none());
+        assert!(result.cavv().is_some());
+        assert_eq!(result.eci(), Some(ECI::FullyAuthenticated));
+        assert_eq!(result.version(), Some(ThreeDSVersion::V2_3_1));
+    }
+
+    #[test]
+    fn rejects_external_eci_of_another_network() {
+        let mut input = external_input();
+        input.eci = "05";
+
+        let result = SecuredPayment::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn requires_cavv_for_external_authentication() {
+        let mut input = external_input();
+        input.cavv = None;
+
+        let result = SecuredPayment::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+        let mut input = external_input();
+        input.cavv = None;
+        input.eci = "00";
+        assert!(SecuredPayment::try_from(input).is_ok());
+    }
 }
@@ -1,11 +1,13 @@
 use crate::Error;
-use crate::types::{CustomerId, StoredCredentialToken};
+use crate::clock::Clock;
+use crate::types::{CardExpiry, CustomerId, Date, StoredCredentialToken};
 
 /// Stored payment credential for recurring payments (mandates, tokens, setup intents)
 #[derive(Clone, Debug)]
 pub struct StoredCredential {
     token: StoredCredentialToken,
     customer_id: Option<CustomerId>,
+    card_expiry: Option<CardExpiry>,
 }
 
 impl StoredCredential {
@@ -26,6 +28,35 @@ impl StoredCredential {
     pub fn customer_id(&self) -> Option<&CustomerId> {
         self.customer_id.as_ref()
     }
+
+    /// Expiry of the stored card (None for non-card credentials or when unknown)
+    #[inline]
+    pub fn card_expiry(&self) -> Option<&CardExpiry> {
+        self.card_expiry.as_ref()
+    }
+
+    /// Whether the stored card is still valid at the date, but expires within the number of days.
+    ///
+    /// Always `false` for credentials without the card expiry.
+    pub fn expires_within(&self, days: u32, date: Date) -> bool {
+        self.card_expiry.as_ref().is_some_and(|expiry| {
+            // SAFETY: the number of days is compared here, and never exposed.
+            (0..=i64::from(days)).contains(&unsafe { expiry.days_until_expiry(date) })
+        })
+    }
+
+    /// Select the credentials of cards expiring within the number of days from today
+    /// (like to remind customers to update their cards).
+    pub fn expiring_within<'a, I>(credentials: I, days: u32, clock: &dyn Clock) -> Vec<&'a Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let today = clock.today();
+        credentials
+            .into_iter()
+            .filter(|credential| credential.expires_within(days, today))
+            .collect()
+    }
 }
 
 impl<'a> TryFrom<crate::StoredCredential<'a>> for StoredCredential {
@@ -35,6 +66,60 @@ impl<'a> TryFrom<crate::StoredCredential<'a>> for StoredCredential {
         Ok(Self {
             token: input.token.try_into()?,
             customer_id: input.customer_id.map(TryInto::try_into).transpose()?,
+            card_expiry: input.card_expiry.map(TryInto::try_into).transpose()?,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::{AsUnsafeRef, inputs};
+
+    // 2025-06-01T00:00:00Z
+    const NOW: i64 = 1_748_736_000;
+
+    fn credential(token: &str, expiry: Option<(u8, u16)>) -> StoredCredential {
+        inputs::StoredCredential {
+            token,
+            customer_id: None,
+            card_expiry: expiry.map(|(month, year)| inputs::CardExpiry { month, year }),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_card_expiry() {
+        let result = StoredCredential::try_from(inputs::StoredCredential {
+            token: "tok_1234567890",
+            customer_id: None,
+            card_expiry: Some(inputs::CardExpiry {
+                month: 13,
+                year: 2030,
+            }),
+        });
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn finds_credentials_expiring_within_days() {
+        let credentials = [
+            credential("tok_expired_card", Some((5, 2025))),
+            credential("tok_expiring_card", Some((6, 2025))),
+            credential("tok_next_month_card", Some((7, 2025))),
+            credential("tok_sepa_mandate", None),
+        ];
+
+        let expiring = StoredCredential::expiring_within(&credentials, 30, &FixedClock(NOW));
+        let tokens: Vec<_> = expiring
+            .iter()
+            .map(|c| unsafe { c.token().as_ref() })
+            .collect();
+        assert_eq!(tokens, ["tok_expiring_card"]);
+
+        let expiring = StoredCredential::expiring_within(&credentials, 60, &FixedClock(NOW));
+        assert_eq!(expiring.len(), 2);
+    }
+}
@@ -1,7 +1,9 @@
 use std::convert::TryFrom;
 
 use crate::Error;
-use crate::types::{InternalPaymentMethod, Token, VaultPaymentMethod};
+use crate::types::{
+    AccountUpdate, InternalPaymentMethod, Token, UpdatableCredential, VaultPaymentMethod,
+};
 
 /// Vault Token Payment Method
 ///
@@ -88,7 +90,21 @@ pub struct Vault {
 // Marker implementations
 
 impl InternalPaymentMethod for Vault {}
-impl VaultPaymentMethod for Vault {}
+impl VaultPaymentMethod for Vault {
+    fn refreshed(&self, update: &AccountUpdate) -> Result<Option<Self>, Error> {
+        match update {
+            AccountUpdate::CredentialReplaced(UpdatableCredential::Token(token)) => {
+                Ok(Some(Self {
+                    token: token.clone(),
+                }))
+            }
+            AccountUpdate::CredentialReplaced(UpdatableCredential::StoredCredential(_)) => Err(
+                Error::InvalidInput("Vault token can't be replaced by a stored credential".into()),
+            ),
+            _ => Ok(None),
+        }
+    }
+}
 
 impl<'a> TryFrom<&'a str> for Vault {
     type Error = Error;
@@ -114,6 +130,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn refreshed_by_replaced_token_only() {
+        let vault = Vault::try_from("tok_1234567890ebadf").unwrap();
+        let replaced = AccountUpdate::CredentialReplaced(UpdatableCredential::Token(
+            "tok_0987654321fedcba".try_into().unwrap(),
+        ));
+
+        let refreshed = vault.refreshed(&replaced).unwrap().unwrap();
+        unsafe {
+            assert_eq!(refreshed.token.as_ref(), "tok_0987654321fedcba");
+        }
+        assert!(vault.refreshed(&AccountUpdate::NoMatch).unwrap().is_none());
+    }
+
     #[test]
     fn rejects_invalid_token() {
         let result = Vault::try_from("short_token"); // Less than 16 characters
@@ -0,0 +1,126 @@
+use std::convert::TryFrom;
+
+use crate::types::{CardDisplay, CardExpiry, InternalPaymentMethod, PrimaryAccountNumber};
+use crate::{CardNetwork, Error, Wallet};
+
+/// Wallet Card
+///
+/// ## Overview
+///
+/// Funding card returned by a digital wallet without a cryptogram, like Google Pay
+/// payloads with the `PAN_ONLY` authentication method. The wallet only stores the card
+/// the customer entered before, and doesn't prove the possession of the card.
+///
+/// ## When to Use
+///
+/// - **Wallet payments**: Decrypted Google Pay payloads with cards not tokenized on the device
+///
+/// ## Authentication Model
+///
+/// > Authentication is **not provided by the wallet**!
+///
+/// There is neither CVV nor cryptogram, so the payment should be authenticated
+/// via 3D Secure (the `secure` step) where SCA is required.
+///
+/// ## Security Considerations
+///
+/// - **Card number**: Protected as `PrimaryAccountNumber` (masked, zeroized)
+///
+/// # Validation
+/// * number: valid PAN.
+#[derive(Clone, Debug)]
+pub struct WalletCard {
+    pub(crate) number: PrimaryAccountNumber,
+    pub(crate) card_expiry: CardExpiry,
+    pub(crate) wallet: Wallet,
+}
+
+impl WalletCard {
+    /// Primary Account Number (PAN)
+    #[inline]
+    pub fn number(&self) -> &PrimaryAccountNumber {
+        &self.number
+    }
+
+    /// Card expiration date (month and year)
+    #[inline]
+    pub fn card_expiry(&self) -> &CardExpiry {
+        &self.card_expiry
+    }
+
+    /// The wallet which returned the card
+    #[inline]
+    pub fn wallet(&self) -> Wallet {
+        self.wallet
+    }
+
+    /// Card network detected by the card number (None for unknown networks)
+    #[inline]
+    pub fn network(&self) -> Option<CardNetwork> {
+        self.number.network()
+    }
+
+    /// Truncated card number safe to store and show on receipts
+    #[inline]
+    pub fn display(&self) -> CardDisplay {
+        self.number.display()
+    }
+}
+
+// Marker implementations
+
+impl InternalPaymentMethod for WalletCard {}
+
+impl<'a> TryFrom<crate::WalletCard<'a>> for WalletCard {
+    type Error = Error;
+
+    fn try_from(input: crate::WalletCard<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            number: input.number.try_into()?,
+            card_expiry: input.card_expiry.try_into()?,
+            wallet: input.wallet,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs;
+
+    fn valid_input() -> inputs::WalletCard<'static> {
+        inputs::WalletCard {
+            number: "4111111111111111",
+            card_expiry: inputs::CardExpiry {
+                month: 12,
+                year: 2030,
+            },
+            wallet: Wallet::GooglePay,
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let card = WalletCard::try_from(valid_input()).unwrap();
+
+        assert_eq!(card.network(), Some(CardNetwork::Visa));
+        assert_eq!(card.wallet(), Wallet::GooglePay);
+        assert_eq!(card.display().last4(), "1111");
+    }
+
+    #[test]
+    fn rejects_invalid_number() {
+        let mut input = valid_input();
+        input.number = "4111111111111112";
+
+        let result = WalletCard::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn masks_number_in_debug() {
+        let card = WalletCard::try_from(valid_input()).unwrap();
+
+        assert!(!format!("{card:?}").contains("4111111111111111"));
+    }
+}
@@ -1,5 +1,11 @@
 //! Payment types for different distribution models.
 
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+
+use super::{Recipients, TransactionIdempotenceKey};
+use crate::Error;
+
 #[allow(clippy::module_inception)]
 mod payment;
 mod split_payment;
@@ -12,14 +18,174 @@ pub use split_payment::SplitPayment;
 // --- Marker Traits ---
 
 /// Marker trait for payment types.
-pub(crate) trait PaymentMarker {
+///
+/// Exposes the non-sensitive attributes shared by all payment types,
+/// so that generic code (like decorators) can read them without knowing
+/// the concrete distribution model.
+pub(crate) trait PaymentMarker: Send {
     type PaymentMethod: super::PaymentMethod;
+
+    fn currency(&self) -> Currency;
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    fn total_amount(&self) -> Decimal;
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    fn idempotence_key(&self) -> &TransactionIdempotenceKey;
+    fn recipients(&self) -> Option<&Recipients>;
+    fn payment_method(&self) -> &Self::PaymentMethod;
+
+    /// The same payment for another amount (like a renewal of a subscription).
+    ///
+    /// Recipients keep their shares, and the platform receives the rest.
+    fn with_amount(
+        self,
+        total_amount: Decimal,
+        idempotence_key: TransactionIdempotenceKey,
+    ) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// The same payment charged from another payment method
+    /// (like a refreshed stored credential).
+    fn with_payment_method(self, payment_method: Self::PaymentMethod) -> Self
+    where
+        Self: Sized;
+
+    /// The same payment split between other recipients.
+    fn with_recipients(self, recipients: Recipients) -> Result<Self, Error>
+    where
+        Self: Sized;
 }
 
 impl<P: super::PaymentMethod> PaymentMarker for Payment<P> {
     type PaymentMethod = P;
+
+    #[inline]
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[inline]
+    fn total_amount(&self) -> Decimal {
+        self.total_amount
+    }
+
+    #[inline]
+    fn idempotence_key(&self) -> &TransactionIdempotenceKey {
+        &self.idempotence_key
+    }
+
+    #[inline]
+    fn recipients(&self) -> Option<&Recipients> {
+        None
+    }
+
+    #[inline]
+    fn payment_method(&self) -> &P {
+        &self.payment_method
+    }
+
+    fn with_amount(
+        self,
+        total_amount: Decimal,
+        idempotence_key: TransactionIdempotenceKey,
+    ) -> Result<Self, Error> {
+        let base_amount = total_amount - (self.total_amount - self.base_amount);
+        if base_amount < Decimal::ZERO {
+            return Err(Error::InvalidInput(format!(
+                "Amount {total_amount} is less than the share of recipients"
+            )));
+        }
+        Ok(Self {
+            total_amount,
+            base_amount,
+            idempotence_key,
+            ..self
+        })
+    }
+
+    fn with_payment_method(self, payment_method: P) -> Self {
+        Self {
+            payment_method,
+            ..self
+        }
+    }
+
+    fn with_recipients(self, _recipients: Recipients) -> Result<Self, Error> {
+        Err(Error::NotSupported(
+            "Payment can't be split between recipients".into(),
+        ))
+    }
 }
 
 impl<P: super::PaymentMethod> PaymentMarker for SplitPayment<P> {
     type PaymentMethod = P;
+
+    #[inline]
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[inline]
+    fn total_amount(&self) -> Decimal {
+        self.total_amount
+    }
+
+    #[inline]
+    fn idempotence_key(&self) -> &TransactionIdempotenceKey {
+        &self.idempotence_key
+    }
+
+    #[inline]
+    fn recipients(&self) -> Option<&Recipients> {
+        self.recipients.as_ref()
+    }
+
+    #[inline]
+    fn payment_method(&self) -> &P {
+        &self.payment_method
+    }
+
+    fn with_amount(
+        self,
+        total_amount: Decimal,
+        idempotence_key: TransactionIdempotenceKey,
+    ) -> Result<Self, Error> {
+        let shares = match &self.recipients {
+            Some(recipients) => recipients.calculate_total(total_amount)?,
+            None => self.total_amount - self.base_amount,
+        };
+        if shares > total_amount {
+            return Err(Error::InvalidInput(format!(
+                "Amount {total_amount} is less than the share of recipients"
+            )));
+        }
+        Ok(Self {
+            total_amount,
+            base_amount: total_amount - shares,
+            idempotence_key,
+            ..self
+        })
+    }
+
+    fn with_payment_method(self, payment_method: P) -> Self {
+        Self {
+            payment_method,
+            ..self
+        }
+    }
+
+    fn with_recipients(self, recipients: Recipients) -> Result<Self, Error> {
+        let shares = recipients.calculate_total(self.total_amount)?;
+        if shares > self.total_amount {
+            return Err(Error::InvalidInput(format!(
+                "Recipients' shares {shares} exceed the amount {}",
+                self.total_amount
+            )));
+        }
+        Ok(Self {
+            base_amount: self.total_amount - shares,
+            recipients: Some(recipients),
+            ..self
+        })
+    }
 }
@@ -3,7 +3,8 @@ use std::fmt;
 use zeroize_derive::ZeroizeOnDrop;
 
 use crate::internal::{Masked, Validated, sanitized::*};
-use crate::{AsUnsafeRef, Error};
+use crate::types::CardDisplay;
+use crate::{AsUnsafeRef, CardNetwork, Error};
 
 /// Primary account number (PAN) from a payment card
 ///
@@ -15,7 +16,8 @@ use crate::{AsUnsafeRef, Error};
 /// * length: 13-19 characters,
 /// * only digits are allowed,
 /// * cannot start with 0,
-/// * must pass the Luhn check (Mod 10)
+/// * must pass the Luhn check (Mod 10),
+/// * length must be valid for the detected card network (if any)
 ///
 /// # Data Protection
 /// PCI DSS classifies PAN as sensitive authentication data (SAD) that provides
@@ -25,9 +27,30 @@ use crate::{AsUnsafeRef, Error};
 /// * fully masked in logs (via `Debug` implementation) to prevent any leaks,
 /// * not exposed publicly except for a part of a request or response
 ///   via **unsafe** method `with_exposed_secret`.
+///
+/// The card network and the truncated [`CardDisplay`] (BIN and last 4 digits)
+/// are available safely, as PCI DSS permits them to be stored and shown.
 #[derive(Clone, ZeroizeOnDrop)]
 pub struct PrimaryAccountNumber(String);
 
+impl PrimaryAccountNumber {
+    /// The card network detected by the IIN prefix (None for unknown networks).
+    #[inline]
+    pub fn network(&self) -> Option<CardNetwork> {
+        CardNetwork::detect(&self.0)
+    }
+
+    /// The truncated representation of the card number safe to store and show.
+    pub fn display(&self) -> CardDisplay {
+        let len = self.0.len();
+        CardDisplay {
+            network: self.network(),
+            bin6: self.0.get(..6).unwrap_or_default().to_string(),
+            last4: self.0.get(len - 4..).unwrap_or_default().to_string(),
+        }
+    }
+}
+
 impl<'a> TryFrom<&'a str> for PrimaryAccountNumber {
     type Error = Error;
 
@@ -73,6 +96,13 @@ impl Validated for PrimaryAccountNumber {
             Err(Error::InvalidInput(format!(
                 "{self:?} failed the Luhn check"
             )))
+        } else if let Some(network) = self
+            .network()
+            .filter(|network| !network.number_lengths().contains(&self.0.len()))
+        {
+            Err(Error::InvalidInput(format!(
+                "{self:?} length is invalid for {network} cards"
+            )))
         } else {
             Ok(self)
         }
@@ -183,6 +213,18 @@ mod tests {
             }
         }
 
+        #[test]
+        fn rejects_invalid_length_for_network() {
+            let input = "3742454554001269"; // Amex with 16 digits
+            let result = PrimaryAccountNumber::try_from(input);
+
+            if let Err(Error::InvalidInput(msg)) = result {
+                assert!(msg.contains("Amex"));
+            } else {
+                panic!("Expected InvalidInput error, got {result:?}");
+            }
+        }
+
         #[test]
         fn rejects_number_failing_luhn_check() {
             let input = "4532015112830367"; // Changed last digit - fails Luhn
@@ -196,6 +238,26 @@ mod tests {
         }
     }
 
+    mod metadata {
+        use super::*;
+
+        #[test]
+        fn detects_network() {
+            let pan = PrimaryAccountNumber::try_from(VALID_AMEX_15).unwrap();
+            assert_eq!(pan.network(), Some(CardNetwork::Amex));
+        }
+
+        #[test]
+        fn builds_display() {
+            let pan = PrimaryAccountNumber::try_from(VALID_MASTERCARD_16).unwrap();
+            let display = pan.display();
+
+            assert_eq!(display.network(), Some(CardNetwork::Mastercard));
+            assert_eq!(display.bin6(), "542523");
+            assert_eq!(display.last4(), "9903");
+        }
+    }
+
     mod safety {
         use super::*;
 
@@ -0,0 +1,376 @@
+use std::ops::Range;
+
+use iso_currency::Currency;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::types::{Date, Subscription, SubscriptionInterval};
+use crate::{Error, ProrationKind, ProrationPolicy};
+
+/// A line of the proration preview
+///
+/// Credits the unused time of the previous terms, or debits the remaining time
+/// on the new terms within the period (the end date is exclusive).
+///
+/// # Data Protection
+/// Proration lines are shown to the customer, and are not masked.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProrationLine {
+    pub(crate) kind: ProrationKind,
+    pub(crate) amount: Decimal,
+    pub(crate) period_start: Date,
+    pub(crate) period_end: Date,
+}
+
+impl ProrationLine {
+    /// Whether the line is a credit or a debit
+    #[inline]
+    pub fn kind(&self) -> ProrationKind {
+        self.kind
+    }
+
+    /// The prorated amount (always non-negative)
+    #[inline]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// The first day of the prorated period
+    #[inline]
+    pub fn period_start(&self) -> Date {
+        self.period_start
+    }
+
+    /// The day after the prorated period
+    #[inline]
+    pub fn period_end(&self) -> Date {
+        self.period_end
+    }
+}
+
+/// Computed preview of the proration of a subscription change
+///
+/// Returned by the `preview_*` methods of the `EditSubscriptionAmount`
+/// and `EditSubscriptionInterval` traits before the change is committed,
+/// so that the customer can confirm the credit and the debit.
+///
+/// The amounts are prorated by days left in the current billing period,
+/// and rounded to the minor units of the currency (half away from zero).
+/// The current period ends on the next billing date, and starts on the previous
+/// billing date of the schedule (not one interval before the next billing,
+/// as dates clamped to the end of shorter months would shorten the period).
+///
+/// # Data Protection
+/// Proration previews are shown to the customer, and are not masked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProrationPreview {
+    pub(crate) policy: ProrationPolicy,
+    pub(crate) currency: Currency,
+    pub(crate) lines: Vec<ProrationLine>,
+}
+
+impl ProrationPreview {
+    /// Preview the change of the subscription amount on the date.
+    ///
+    /// Credits the current amount and debits the new amount for the rest
+    /// of the current billing period.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the date is outside the current billing period.
+    pub fn amount_change(
+        subscription: &Subscription,
+        period: Range<Date>,
+        current_amount: Decimal,
+        new_amount: Decimal,
+        policy: ProrationPolicy,
+        date: Date,
+    ) -> Result<Self, Error> {
+        let mut preview = Self::new(subscription, policy);
+        let (start, end) = current_period(period, date)?;
+        if policy != ProrationPolicy::None {
+            preview.prorate(ProrationKind::Credit, current_amount, start, date, end);
+            preview.prorate(ProrationKind::Debit, new_amount, start, date, end);
+        }
+        Ok(preview)
+    }
+
+    /// Preview the change of the subscription interval on the date.
+    ///
+    /// Credits the amount for the rest of the current billing period,
+    /// and debits the full amount for the first period of the new interval
+    /// (starting on the date).
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the date is outside the current billing period.
+    pub fn interval_change(
+        subscription: &Subscription,
+        period: Range<Date>,
+        amount: Decimal,
+        new_interval: SubscriptionInterval,
+        policy: ProrationPolicy,
+        date: Date,
+    ) -> Result<Self, Error> {
+        let mut preview = Self::new(subscription, policy);
+        let (start, end) = current_period(period, date)?;
+        if policy != ProrationPolicy::None {
+            preview.prorate(ProrationKind::Credit, amount, start, date, end);
+            let new_end = new_interval.shift(date, 1);
+            preview.prorate(ProrationKind::Debit, amount, date, date, new_end);
+        }
+        Ok(preview)
+    }
+
+    /// The policy applied to the change
+    #[inline]
+    pub fn policy(&self) -> ProrationPolicy {
+        self.policy
+    }
+
+    /// Currency of the subscription billing
+    #[inline]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Credit and debit lines (empty for the `ProrationPolicy::None`)
+    #[inline]
+    pub fn lines(&self) -> &[ProrationLine] {
+        &self.lines
+    }
+
+    /// The sum of the credit lines
+    pub fn credit(&self) -> Decimal {
+        self.sum(ProrationKind::Credit)
+    }
+
+    /// The sum of the debit lines
+    pub fn debit(&self) -> Decimal {
+        self.sum(ProrationKind::Debit)
+    }
+
+    /// The net amount of the change (negative when the customer is owed money)
+    pub fn total(&self) -> Decimal {
+        self.debit() - self.credit()
+    }
+
+    /// The amount settled immediately (zero unless the policy is `ProrationPolicy::Immediate`)
+    pub fn due_now(&self) -> Decimal {
+        match self.policy {
+            ProrationPolicy::Immediate => self.total(),
+            ProrationPolicy::None | ProrationPolicy::NextBilling => Decimal::ZERO,
+        }
+    }
+
+    fn new(subscription: &Subscription, policy: ProrationPolicy) -> Self {
+        Self {
+            policy,
+            currency: subscription.currency(),
+            lines: Vec::new(),
+        }
+    }
+
+    fn sum(&self, kind: ProrationKind) -> Decimal {
+        self.lines
+            .iter()
+            .filter(|line| line.kind == kind)
+            .map(|line| line.amount)
+            .sum()
+    }
+
+    /// Add the amount of the period [start, end) prorated to the days in [date, end).
+    fn prorate(
+        &mut self,
+        kind: ProrationKind,
+        amount: Decimal,
+        start: Date,
+        date: Date,
+        end: Date,
+    ) {
+        let total_days = start.days_until(&end);
+        let remaining_days = date.days_until(&end);
+        let exponent = self.currency.exponent().unwrap_or(0);
+        let amount = (amount * Decimal::from(remaining_days) / Decimal::from(total_days))
+            .round_dp_with_strategy(u32::from(exponent), RoundingStrategy::MidpointAwayFromZero);
+        self.lines.push(ProrationLine {
+            kind,
+            amount,
+            period_start: date,
+            period_end: end,
+        });
+    }
+}
+
+/// The current billing period [start, end) containing the date.
+fn current_period(period: Range<Date>, date: Date) -> Result<(Date, Date), Error> {
+    if !period.contains(&date) {
+        return Err(Error::InvalidInput(format!(
+            "Date {date:?} is outside the current billing period {period:?}"
+        )));
+    }
+    Ok((period.start, period.end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SubscriptionStatus;
+    use crate::test_support::date;
+    use rust_decimal_macros::dec;
+
+    fn april() -> Range<Date> {
+        date(2025, 4, 1)..date(2025, 5, 1)
+    }
+
+    fn subscription(currency: Currency) -> Subscription {
+        crate::Subscription {
+            subscription_id: "sub_123",
+            status: SubscriptionStatus::Active,
+            interval: crate::SubscriptionInterval::Month(1),
+            currency,
+            recipients: None,
+            created_at: date(2025, 1, 1).timestamp(),
+            next_billing_date: Some(date(2025, 5, 1).timestamp()),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn prorates_amount_change_by_remaining_days() {
+        // April has 30 days, 20 of them remain from April 11
+        let subscription = subscription(Currency::USD);
+        let preview = ProrationPreview::amount_change(
+            &subscription,
+            april(),
+            dec!(30),
+            dec!(60),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 11),
+        )
+        .unwrap();
+
+        assert_eq!(preview.lines().len(), 2);
+        assert_eq!(preview.lines()[0].kind(), ProrationKind::Credit);
+        assert_eq!(preview.lines()[0].period_start(), date(2025, 4, 11));
+        assert_eq!(preview.lines()[0].period_end(), date(2025, 5, 1));
+        assert_eq!(preview.credit(), dec!(20));
+        assert_eq!(preview.debit(), dec!(40));
+        assert_eq!(preview.total(), dec!(20));
+        assert_eq!(preview.due_now(), dec!(20));
+    }
+
+    #[test]
+    fn defers_proration_to_next_billing() {
+        let subscription = subscription(Currency::USD);
+        let preview = ProrationPreview::amount_change(
+            &subscription,
+            april(),
+            dec!(60),
+            dec!(30),
+            ProrationPolicy::NextBilling,
+            date(2025, 4, 11),
+        )
+        .unwrap();
+
+        assert_eq!(preview.total(), dec!(-20));
+        assert_eq!(preview.due_now(), dec!(0));
+    }
+
+    #[test]
+    fn skips_lines_without_proration() {
+        let subscription = subscription(Currency::USD);
+        let preview = ProrationPreview::amount_change(
+            &subscription,
+            april(),
+            dec!(30),
+            dec!(60),
+            ProrationPolicy::None,
+            date(2025, 4, 11),
+        )
+        .unwrap();
+
+        assert!(preview.lines().is_empty());
+        assert_eq!(preview.total(), dec!(0));
+    }
+
+    #[test]
+    fn rounds_to_currency_minor_units() {
+        // 10 of 30 days remain
+        let usd = ProrationPreview::amount_change(
+            &subscription(Currency::USD),
+            april(),
+            dec!(10),
+            dec!(20),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 21),
+        )
+        .unwrap();
+        assert_eq!(usd.credit(), dec!(3.33));
+        assert_eq!(usd.debit(), dec!(6.67));
+
+        let jpy = ProrationPreview::amount_change(
+            &subscription(Currency::JPY),
+            april(),
+            dec!(1000),
+            dec!(2000),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 21),
+        )
+        .unwrap();
+        assert_eq!(jpy.credit(), dec!(333));
+        assert_eq!(jpy.debit(), dec!(667));
+    }
+
+    #[test]
+    fn prorates_interval_change() {
+        let subscription = subscription(Currency::EUR);
+        let preview = ProrationPreview::interval_change(
+            &subscription,
+            april(),
+            dec!(30),
+            SubscriptionInterval::Year(1),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 16),
+        )
+        .unwrap();
+
+        assert_eq!(preview.credit(), dec!(15));
+        assert_eq!(preview.debit(), dec!(30));
+        assert_eq!(preview.lines()[1].period_start(), date(2025, 4, 16));
+        assert_eq!(preview.lines()[1].period_end(), date(2026, 4, 16));
+    }
+
+    #[test]
+    fn rejects_date_outside_current_period() {
+        let subscription = subscription(Currency::USD);
+
+        for date in [date(2025, 3, 31), date(2025, 5, 1)] {
+            let result = ProrationPreview::amount_change(
+                &subscription,
+                april(),
+                dec!(30),
+                dec!(60),
+                ProrationPolicy::Immediate,
+                date,
+            );
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn prorates_period_ending_on_month_end_anchor() {
+        // Billed on the 31st: Mar 31 to Apr 30 lasts 30 days, 15 of them remain from Apr 15
+        let subscription = subscription(Currency::USD);
+        let preview = ProrationPreview::amount_change(
+            &subscription,
+            date(2025, 3, 31)..date(2025, 4, 30),
+            dec!(30),
+            dec!(30),
+            ProrationPolicy::Immediate,
+            date(2025, 4, 15),
+        )
+        .unwrap();
+
+        assert_eq!(preview.credit(), dec!(15));
+        assert_eq!(preview.debit(), dec!(15));
+    }
+}
@@ -1,22 +1,81 @@
+mod await_approval;
+mod otp;
 mod redirect;
+mod three_ds_challenge;
+mod three_ds_method;
 
 use crate::Error;
+use crate::types::{DeepLink, QRCode, Voucher};
 
+pub use await_approval::AwaitApproval;
+pub use otp::Otp;
 pub use redirect::Redirect;
+pub use three_ds_challenge::ThreeDSChallenge;
+pub use three_ds_method::ThreeDSMethod;
 
-// TODO: Add more variants (Iframe, QRCode, Voucher, BankTransfer, etc.)
 /// Customer action required to complete the authorization.
 pub enum RequiredAction {
     /// Redirect the customer to an external URL for approval.
     Redirect(Redirect),
+    /// Collect the device fingerprint via the 3DS method iframe.
+    ThreeDSMethod(ThreeDSMethod),
+    /// Show the 3DS challenge of the ACS.
+    ThreeDSChallenge(ThreeDSChallenge),
+    /// Show the QR code to be scanned by the customer's mobile app.
+    QRCode(QRCode),
+    /// Ask the customer for the one-time password.
+    Otp(Otp),
+    /// Switch the customer to the app of the payment provider.
+    DeepLink(DeepLink),
+    /// Show the voucher to be paid by the customer.
+    Voucher(Voucher),
+    /// Wait for the customer to approve the payment in the app.
+    AwaitApproval(AwaitApproval),
 }
 
 impl<'a> TryFrom<crate::RequiredAction<'a>> for RequiredAction {
     type Error = Error;
 
     fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
+        use crate::ExternalPaymentData as Data;
+        use crate::RequiredAction as Action;
+
         match input {
-            crate::RequiredAction::Redirect { .. } => Ok(Self::Redirect(input.try_into()?)),
+            Action::Redirect { .. } => Ok(Self::Redirect(input.try_into()?)),
+            Action::ThreeDSMethod { .. } => Ok(Self::ThreeDSMethod(input.try_into()?)),
+            Action::ThreeDSChallenge { .. } => Ok(Self::ThreeDSChallenge(input.try_into()?)),
+            Action::Otp { .. } => Ok(Self::Otp(input.try_into()?)),
+            Action::AwaitApproval { .. } => Ok(Self::AwaitApproval(input.try_into()?)),
+            // The data shown to the customer is the same as for external payments
+            Action::QRCode {
+                payload,
+                image,
+                expires_at,
+            } => Ok(Self::QRCode(
+                Data::QRCode {
+                    payload,
+                    image,
+                    expires_at,
+                }
+                .try_into()?,
+            )),
+            Action::DeepLink { url, fallback_url } => Ok(Self::DeepLink(
+                Data::DeepLink { url, fallback_url }.try_into()?,
+            )),
+            Action::Voucher {
+                barcode,
+                digitable_line,
+                expires_at,
+                printable_url,
+            } => Ok(Self::Voucher(
+                Data::Voucher {
+                    barcode,
+                    digitable_line,
+                    expires_at,
+                    printable_url,
+                }
+                .try_into()?,
+            )),
         }
     }
 }
@@ -45,6 +104,54 @@ mod tests {
                     "https://merchant.example.com/callback"
                 );
             }
+            _ => panic!("unexpected variant"),
         }
     }
+
+    #[test]
+    fn constructs_qr_code_variant() {
+        let input = crate::RequiredAction::QRCode {
+            payload: "upi://pay?pa=merchant@bank&am=19.99",
+            image: None,
+            expires_at: Some(1_767_225_600),
+        };
+
+        let action = RequiredAction::try_from(input).unwrap();
+        assert!(matches!(action, RequiredAction::QRCode(_)));
+    }
+
+    #[test]
+    fn constructs_deep_link_variant() {
+        let input = crate::RequiredAction::DeepLink {
+            url: "bankapp://approve?id=123",
+            fallback_url: None,
+        };
+
+        let action = RequiredAction::try_from(input).unwrap();
+        assert!(matches!(action, RequiredAction::DeepLink(_)));
+    }
+
+    #[test]
+    fn constructs_voucher_variant() {
+        let input = crate::RequiredAction::Voucher {
+            barcode: "23793381286",
+            digitable_line: None,
+            expires_at: None,
+            printable_url: None,
+        };
+
+        let action = RequiredAction::try_from(input).unwrap();
+        assert!(matches!(action, RequiredAction::Voucher(_)));
+    }
+
+    #[test]
+    fn validates_reused_variants() {
+        let input = crate::RequiredAction::DeepLink {
+            url: "javascript://alert(1)",
+            fallback_url: None,
+        };
+
+        let result = RequiredAction::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
 }
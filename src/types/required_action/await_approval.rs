@@ -0,0 +1,94 @@
+use crate::Error;
+use crate::internal::Validated;
+
+/// Data for the action of waiting for the customer's approval in an app.
+///
+/// The customer receives a push notification from the app of the payment provider
+/// (or the bank) and approves the payment there. The client shows a waiting screen,
+/// and checks the status of the transaction until it is approved, declined or expired.
+///
+/// # Validation
+/// * app name: 1-64 characters without control characters.
+#[derive(Clone, Debug)]
+pub struct AwaitApproval {
+    app_name: Option<String>,
+    expires_at: Option<i64>,
+}
+
+impl AwaitApproval {
+    /// The name of the app to approve the payment in.
+    #[inline]
+    pub fn app_name(&self) -> Option<&str> {
+        self.app_name.as_deref()
+    }
+
+    /// The moment the approval expires (Unix timestamp).
+    #[inline]
+    pub fn expires_at(&self) -> Option<i64> {
+        self.expires_at
+    }
+}
+
+impl<'a> TryFrom<crate::RequiredAction<'a>> for AwaitApproval {
+    type Error = Error;
+
+    fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::RequiredAction::AwaitApproval {
+                app_name,
+                expires_at,
+            } => Self {
+                app_name: app_name.map(|name| name.trim().to_string()),
+                expires_at,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid required action type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for AwaitApproval {
+    fn validate(self) -> Result<Self, Error> {
+        if let Some(name) = &self.app_name {
+            self._validate_length(name, 1, 64)?;
+            if name.chars().any(char::is_control) {
+                return Err(Error::InvalidInput(format!(
+                    "{self:?} app name contains control characters"
+                )));
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructs_from_required_action() {
+        let input = crate::RequiredAction::AwaitApproval {
+            app_name: Some(" MobilePay "),
+            expires_at: Some(1_767_225_600),
+        };
+
+        let approval = AwaitApproval::try_from(input).unwrap();
+        assert_eq!(approval.app_name(), Some("MobilePay"));
+        assert_eq!(approval.expires_at(), Some(1_767_225_600));
+    }
+
+    #[test]
+    fn rejects_empty_app_name() {
+        let input = crate::RequiredAction::AwaitApproval {
+            app_name: Some(" "),
+            expires_at: None,
+        };
+
+        let result = AwaitApproval::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
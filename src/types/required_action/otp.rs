@@ -0,0 +1,118 @@
+use crate::Error;
+use crate::internal::Validated;
+
+/// Data for the one-time password action.
+///
+/// The client asks the customer for the password sent by the issuer
+/// or the payment provider (via SMS, email or a banking app),
+/// and confirms the authorization with it.
+///
+/// # Validation
+/// * length: 4-12 characters,
+/// * destination hint: 1-64 characters without control characters.
+///
+/// # Data Protection
+/// The destination hint is masked by the gateway (like `+44*****123`),
+/// and is shown to the customer as is.
+#[derive(Clone, Debug)]
+pub struct Otp {
+    length: Option<u8>,
+    destination_hint: Option<String>,
+    expires_at: Option<i64>,
+}
+
+impl Otp {
+    /// The number of characters of the password (if known).
+    #[inline]
+    pub fn length(&self) -> Option<u8> {
+        self.length
+    }
+
+    /// The masked destination the password was sent to.
+    #[inline]
+    pub fn destination_hint(&self) -> Option<&str> {
+        self.destination_hint.as_deref()
+    }
+
+    /// The moment the password expires (Unix timestamp).
+    #[inline]
+    pub fn expires_at(&self) -> Option<i64> {
+        self.expires_at
+    }
+}
+
+impl<'a> TryFrom<crate::RequiredAction<'a>> for Otp {
+    type Error = Error;
+
+    fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::RequiredAction::Otp {
+                length,
+                destination_hint,
+                expires_at,
+            } => Self {
+                length,
+                destination_hint: destination_hint.map(|hint| hint.trim().to_string()),
+                expires_at,
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid required action type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for Otp {
+    fn validate(self) -> Result<Self, Error> {
+        if let Some(length) = self.length
+            && !(4..=12).contains(&length)
+        {
+            return Err(Error::InvalidInput(format!(
+                "{self:?} length is out of range (4-12)"
+            )));
+        }
+        if let Some(hint) = &self.destination_hint {
+            self._validate_length(hint, 1, 64)?;
+            if hint.chars().any(char::is_control) {
+                return Err(Error::InvalidInput(format!(
+                    "{self:?} destination hint contains control characters"
+                )));
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructs_from_required_action() {
+        let input = crate::RequiredAction::Otp {
+            length: Some(6),
+            destination_hint: Some(" +44*****123 "),
+            expires_at: Some(1_767_225_600),
+        };
+
+        let otp = Otp::try_from(input).unwrap();
+        assert_eq!(otp.length(), Some(6));
+        assert_eq!(otp.destination_hint(), Some("+44*****123"));
+        assert_eq!(otp.expires_at(), Some(1_767_225_600));
+    }
+
+    #[test]
+    fn rejects_invalid_length() {
+        let input = crate::RequiredAction::Otp {
+            length: Some(2),
+            destination_hint: None,
+            expires_at: None,
+        };
+
+        let result = Otp::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
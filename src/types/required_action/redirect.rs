@@ -1,25 +1,29 @@
 use crate::Error;
+use crate::types::Url;
 
 /// Data for a redirect action.
 ///
 /// Contains URLs for redirecting the customer to an external service
 /// and returning them after the action is completed.
+///
+/// # Validation
+/// * both URLs are validated as [`Url`] (`https` only, no fragments).
 #[derive(Clone, Debug)]
 pub struct Redirect {
-    url: String,
-    return_url: String,
+    url: Url,
+    return_url: Url,
 }
 
 impl Redirect {
     /// The URL to redirect the customer to.
     #[inline]
-    pub fn url(&self) -> &str {
+    pub fn url(&self) -> &Url {
         &self.url
     }
 
     /// The URL to return to after the customer completes the action.
     #[inline]
-    pub fn return_url(&self) -> &str {
+    pub fn return_url(&self) -> &Url {
         &self.return_url
     }
 }
@@ -30,10 +34,9 @@ impl<'a> TryFrom<crate::RequiredAction<'a>> for Redirect {
     fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
         match input {
             crate::RequiredAction::Redirect { url, return_url } => Ok(Self {
-                url: url.to_string(),
-                return_url: return_url.to_string(),
+                url: url.try_into()?,
+                return_url: return_url.try_into()?,
             }),
-            #[allow(unreachable_patterns)]
             _ => Err(Error::InvalidInput(String::from(
                 "invalid required action type",
             ))),
@@ -63,4 +66,15 @@ mod tests {
             "https://merchant.example.com/callback"
         );
     }
+
+    #[test]
+    fn rejects_insecure_url() {
+        let input = crate::RequiredAction::Redirect {
+            url: "http://gateway.example.com/auth/123",
+            return_url: "https://merchant.example.com/callback",
+        };
+
+        let result = Redirect::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
 }
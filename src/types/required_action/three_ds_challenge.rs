@@ -0,0 +1,121 @@
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::Url;
+
+/// Data for the 3DS challenge action (EMV 3DS).
+///
+/// The client posts the `creq` (and `threeDSSessionData`, if any) form fields
+/// to the ACS URL from an iframe or a redirected window, where the customer
+/// completes the challenge of the issuer.
+///
+/// # Validation
+/// * ACS URL: validated as [`Url`],
+/// * CReq: 1-8192 characters of Base64 (or Base64url) encoding,
+/// * session data: 1-1024 characters of Base64 (or Base64url) encoding.
+#[derive(Clone, Debug)]
+pub struct ThreeDSChallenge {
+    acs_url: Url,
+    creq: String,
+    session_data: Option<String>,
+}
+
+impl ThreeDSChallenge {
+    /// The URL of the ACS to post the challenge request to.
+    #[inline]
+    pub fn acs_url(&self) -> &Url {
+        &self.acs_url
+    }
+
+    /// The challenge request (CReq, Base64url-encoded).
+    #[inline]
+    pub fn creq(&self) -> &str {
+        &self.creq
+    }
+
+    /// The `threeDSSessionData` to post along with the CReq.
+    #[inline]
+    pub fn session_data(&self) -> Option<&str> {
+        self.session_data.as_deref()
+    }
+}
+
+impl<'a> TryFrom<crate::RequiredAction<'a>> for ThreeDSChallenge {
+    type Error = Error;
+
+    fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::RequiredAction::ThreeDSChallenge {
+                acs_url,
+                creq,
+                session_data,
+            } => Self {
+                acs_url: acs_url.try_into()?,
+                creq: creq.trim().to_string(),
+                session_data: session_data.map(|data| data.trim().to_string()),
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid required action type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for ThreeDSChallenge {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.creq, 1, 8192)?;
+        self._validate_alphanumeric(&self.creq, "+/-_=")?;
+        if let Some(data) = &self.session_data {
+            self._validate_length(data, 1, 1024)?;
+            self._validate_alphanumeric(data, "+/-_=")?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_input() -> crate::RequiredAction<'static> {
+        crate::RequiredAction::ThreeDSChallenge {
+            acs_url: "https://acs.example.com/challenge",
+            creq: "eyJtZXNzYWdlVHlwZSI6IkNSZXEifQ",
+            session_data: Some("c2Vzc2lvbi0xMjM="),
+        }
+    }
+
+    #[test]
+    fn constructs_from_required_action() {
+        let challenge = ThreeDSChallenge::try_from(valid_input()).unwrap();
+
+        assert_eq!(challenge.acs_url(), "https://acs.example.com/challenge");
+        assert_eq!(challenge.creq(), "eyJtZXNzYWdlVHlwZSI6IkNSZXEifQ");
+        assert_eq!(challenge.session_data(), Some("c2Vzc2lvbi0xMjM="));
+    }
+
+    #[test]
+    fn rejects_empty_creq() {
+        let input = crate::RequiredAction::ThreeDSChallenge {
+            acs_url: "https://acs.example.com/challenge",
+            creq: "",
+            session_data: None,
+        };
+
+        let result = ThreeDSChallenge::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_other_variants() {
+        let input = crate::RequiredAction::Redirect {
+            url: "https://gateway.example.com/auth/123",
+            return_url: "https://merchant.example.com/callback",
+        };
+
+        let result = ThreeDSChallenge::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::Error;
+use crate::internal::Validated;
+use crate::types::Url;
+
+/// Data for the 3DS method action (EMV 3DS device fingerprinting).
+///
+/// The client posts the `threeDSMethodData` form field to the method URL
+/// of the ACS from a hidden iframe, and waits for the notification
+/// (or a 10 seconds timeout) before continuing the authentication.
+///
+/// # Validation
+/// * url: validated as [`Url`],
+/// * data: 1-4096 characters of Base64 (or Base64url) encoding.
+#[derive(Clone, Debug)]
+pub struct ThreeDSMethod {
+    url: Url,
+    data: String,
+}
+
+impl ThreeDSMethod {
+    /// The 3DS method URL of the ACS.
+    #[inline]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The `threeDSMethodData` to post (Base64url-encoded).
+    #[inline]
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+}
+
+impl<'a> TryFrom<crate::RequiredAction<'a>> for ThreeDSMethod {
+    type Error = Error;
+
+    fn try_from(input: crate::RequiredAction<'a>) -> Result<Self, Self::Error> {
+        match input {
+            crate::RequiredAction::ThreeDSMethod { url, data } => Self {
+                url: url.try_into()?,
+                data: data.trim().to_string(),
+            }
+            .validate(),
+            _ => Err(Error::InvalidInput(String::from(
+                "invalid required action type",
+            ))),
+        }
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for ThreeDSMethod {
+    fn validate(self) -> Result<Self, Error> {
+        self._validate_length(&self.data, 1, 4096)?;
+        self._validate_alphanumeric(&self.data, "+/-_=")?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructs_from_required_action() {
+        let input = crate::RequiredAction::ThreeDSMethod {
+            url: "https://acs.example.com/method",
+            data: "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6IjEyMyJ9",
+        };
+
+        let method = ThreeDSMethod::try_from(input).unwrap();
+        assert_eq!(method.url(), "https://acs.example.com/method");
+        assert_eq!(method.data(), "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6IjEyMyJ9");
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let input = crate::RequiredAction::ThreeDSMethod {
+            url: "https://acs.example.com/method",
+            data: "{\"threeDSServerTransID\":\"123\"}",
+        };
+
+        let result = ThreeDSMethod::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_insecure_url() {
+        let input = crate::RequiredAction::ThreeDSMethod {
+            url: "http://acs.example.com/method",
+            data: "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6IjEyMyJ9",
+        };
+
+        let result = ThreeDSMethod::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
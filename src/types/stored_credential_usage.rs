@@ -1,5 +1,5 @@
 use crate::Error;
-use crate::types::TransactionId;
+use crate::types::NetworkTransactionId;
 
 /// Indicates whether this payment uses stored credentials for the first time or subsequently.
 ///
@@ -25,8 +25,8 @@ use crate::types::TransactionId;
 ///
 /// The gateway will:
 /// - Store the credentials in the vault
-/// - Return a network transaction ID
-/// - This transaction ID must be saved and used in later transactions
+/// - Return a network transaction ID (see `Transaction::network_transaction_id`)
+/// - This network transaction ID must be saved and used in later transactions
 ///
 /// ### Subsequent
 /// Subsequent use of stored credentials. Used when:
@@ -35,7 +35,8 @@ use crate::types::TransactionId;
 /// - No CVV is required (for cards)
 ///
 /// Must include:
-/// - `TransactionId` of the Initial transaction
+/// - `NetworkTransactionId` of the Initial transaction (assigned by the card network,
+///   not the gateway `TransactionId`)
 /// - This links the MIT to the original CIT for card network compliance
 ///
 /// ## Card Network Compliance
@@ -68,22 +69,23 @@ use crate::types::TransactionId;
 ///     stored_credential_usage: StoredCredentialUsage::Initial,
 /// };
 ///
-/// // After successful payment, save the transaction_id for future use
+/// // After successful payment, save the usage of the later payments
+/// // (Subsequent with the network transaction ID of this one)
 /// let transaction = gateway.charge(payment).await?;
-/// save_transaction_id_for_subscription(transaction.id);
+/// save_stored_credential_usage(transaction.subsequent_usage()?);
 /// ```
 ///
 /// ### Subscription Renewal (Subsequent)
 /// ```skip
 /// // Monthly renewal - merchant initiates without customer interaction
-/// let original_transaction_id = get_saved_transaction_id();
+/// let subsequent_usage = get_saved_stored_credential_usage();
 ///
 /// let payment = Payment {
 ///     method: stored_credential, // Previously obtained from CIT
 ///     amount: money,
 ///     idempotence_key: key,
 ///     merchant_initiated_type: Some(MerchantInitiatedType::Recurring),
-///     stored_credential_usage: StoredCredentialUsage::Subsequent(original_transaction_id),
+///     stored_credential_usage: subsequent_usage, // StoredCredentialUsage::Subsequent
 /// };
 ///
 /// gateway.charge(payment).await?;
@@ -93,8 +95,8 @@ pub enum StoredCredentialUsage {
     /// The first use of credentials for storage (Customer Initiated Transaction)
     Initial,
     /// A later use of stored credentials (Merchant Initiated Transaction)
-    /// Contains reference to the original Initial transaction
-    Subsequent(TransactionId),
+    /// Contains the network transaction ID of the original Initial transaction
+    Subsequent(NetworkTransactionId),
 }
 
 impl TryFrom<crate::StoredCredentialUsage<'_>> for StoredCredentialUsage {
@@ -124,12 +126,12 @@ mod tests {
 
     #[test]
     fn converts_subsequent_with_valid_id() {
-        let input = inputs::StoredCredentialUsage::Subsequent(" txn_12345 \n\t");
+        let input = inputs::StoredCredentialUsage::Subsequent(" 583112345678901 \n\t");
         let result = StoredCredentialUsage::try_from(input).unwrap();
 
         match result {
             StoredCredentialUsage::Subsequent(id) => unsafe {
-                assert_eq!(id.as_ref(), "txn_12345");
+                assert_eq!(id.as_ref(), "583112345678901");
             },
             _ => panic!("Expected Subsequent variant"),
         }
@@ -3,6 +3,7 @@ use std::convert::TryFrom;
 
 use crate::Error;
 use crate::internal::Validated;
+use crate::types::Date;
 
 /// Billing interval for recurring subscriptions
 ///
@@ -15,16 +16,22 @@ use crate::internal::Validated;
 ///   - `Day(7)` = exactly 7 * 24 hours from start (weekly)
 ///   - `Day(30)` = exactly 30 * 24 hours from start (not the same as monthly!)
 ///
+/// * `Week(n)`: Duration-based, the same as `Day(7 * n)`
+///   - `Week(2)` = biweekly billing
+///
 /// * `Month(n)`: Calendar-based, anchored to calendar dates
 ///   - `Month(1)` = monthly billing
 ///   - `Month(3)` = quarterly billing
 ///   - `Month(12)` = yearly billing
 ///   - Starting Jan 31 → next billing Feb 28/29 (last day of shorter month)
 ///
+/// * `Year(n)`: Calendar-based, the same as `Month(12 * n)`
+///   - Starting Feb 29 → next billing Feb 28 (in non-leap years)
+///
 /// # Ordering
 ///
-/// `Day` and `Month` intervals cannot be compared as they represent
-/// fundamentally different concepts (duration vs. calendar).
+/// Duration-based intervals (`Day`, `Week`) and calendar-based ones (`Month`, `Year`)
+/// cannot be compared as they represent fundamentally different concepts (duration vs. calendar).
 ///
 /// # Examples
 ///
@@ -35,7 +42,7 @@ use crate::internal::Validated;
 /// let daily = SubscriptionInterval::Day(1);
 ///
 /// // Weekly (every 7 days)
-/// let weekly = SubscriptionInterval::Day(7);
+/// let weekly = SubscriptionInterval::Week(1);
 ///
 /// // Every 30 days (different from monthly!)
 /// let thirty_days = SubscriptionInterval::Day(30);
@@ -47,25 +54,84 @@ use crate::internal::Validated;
 /// let quarterly = SubscriptionInterval::Month(3);
 ///
 /// // Yearly
-/// let yearly = SubscriptionInterval::Month(12);
+/// let yearly = SubscriptionInterval::Year(1);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SubscriptionInterval {
     /// Duration-based: exactly 24 hours * count from start_date
     Day(u32),
 
+    /// Duration-based: exactly 7 days * count from start_date
+    Week(u32),
+
     /// Calendar-based: calendar month * count
     /// Anchored to specific day of month, handles variable month lengths (28-31 days)
     Month(u32),
+
+    /// Calendar-based: calendar year * count
+    /// Anchored to specific day of year, handles leap years
+    Year(u32),
+}
+
+impl SubscriptionInterval {
+    /// The length of duration-based intervals in days (None for calendar-based ones).
+    #[inline]
+    pub fn days(&self) -> Option<u64> {
+        match self {
+            Self::Day(n) => Some(u64::from(*n)),
+            Self::Week(n) => Some(7 * u64::from(*n)),
+            Self::Month(_) | Self::Year(_) => None,
+        }
+    }
+
+    /// The length of calendar-based intervals in months (None for duration-based ones).
+    #[inline]
+    pub fn months(&self) -> Option<u64> {
+        match self {
+            Self::Month(n) => Some(u64::from(*n)),
+            Self::Year(n) => Some(12 * u64::from(*n)),
+            Self::Day(_) | Self::Week(_) => None,
+        }
+    }
+
+    /// The date shifted by the number of intervals (backwards for negative numbers).
+    ///
+    /// Calendar-based intervals clamp the day to the last day of shorter months,
+    /// and the result is clamped to the supported dates (1970-01-01 to 2199-12-31).
+    pub fn shift(&self, date: Date, count: i64) -> Date {
+        match self {
+            Self::Day(n) => date.add_days(count.saturating_mul(i64::from(*n))),
+            Self::Week(n) => date.add_days(count.saturating_mul(7 * i64::from(*n))),
+            Self::Month(n) => date.add_months(count.saturating_mul(i64::from(*n))),
+            Self::Year(n) => date.add_months(count.saturating_mul(12 * i64::from(*n))),
+        }
+    }
+
+    /// The date shifted by the number of intervals (backwards for negative numbers),
+    /// or None if the result is outside of the supported dates.
+    ///
+    /// Calendar-based intervals clamp the day to the last day of shorter months.
+    pub fn checked_shift(&self, date: Date, count: i64) -> Option<Date> {
+        match self {
+            Self::Day(n) => date.checked_add_days(count.checked_mul(i64::from(*n))?),
+            Self::Week(n) => date.checked_add_days(count.checked_mul(7 * i64::from(*n))?),
+            Self::Month(n) => date.checked_add_months(count.checked_mul(i64::from(*n))?),
+            Self::Year(n) => date.checked_add_months(count.checked_mul(12 * i64::from(*n))?),
+        }
+    }
 }
 
 impl PartialOrd for SubscriptionInterval {
+    /// Intervals of the same kind are ordered by their lengths.
+    /// Intervals of the same length but different units (like `Week(2)` and `Day(14)`)
+    /// are not equal, and therefore are not comparable.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Self::Day(a), Self::Day(b)) => Some(a.cmp(b)),
-            (Self::Month(a), Self::Month(b)) => Some(a.cmp(b)),
-            _ => None,
-        }
+        let ordering = match (self.days(), other.days()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (None, None) => self.months().cmp(&other.months()),
+            _ => return None,
+        };
+        (ordering.is_ne() || self == other).then_some(ordering)
     }
 }
 
@@ -75,7 +141,9 @@ impl TryFrom<crate::SubscriptionInterval> for SubscriptionInterval {
     fn try_from(input: crate::SubscriptionInterval) -> Result<Self, Self::Error> {
         match input {
             crate::SubscriptionInterval::Day(n) => Self::Day(n),
+            crate::SubscriptionInterval::Week(n) => Self::Week(n),
             crate::SubscriptionInterval::Month(n) => Self::Month(n),
+            crate::SubscriptionInterval::Year(n) => Self::Year(n),
         }
         .validate()
     }
@@ -86,9 +154,9 @@ impl TryFrom<crate::SubscriptionInterval> for SubscriptionInterval {
 impl Validated for SubscriptionInterval {
     fn validate(self) -> Result<Self, Error> {
         match self {
-            Self::Day(0) | Self::Month(0) => Err(Error::InvalidInput(
-                "Subscription interval must be positive".to_string(),
-            )),
+            Self::Day(0) | Self::Week(0) | Self::Month(0) | Self::Year(0) => Err(
+                Error::InvalidInput("Subscription interval must be positive".to_string()),
+            ),
             _ => Ok(self),
         }
     }
@@ -110,6 +178,16 @@ mod tests {
         assert!(SubscriptionInterval::Month(3) < SubscriptionInterval::Month(12));
     }
 
+    #[test]
+    fn compares_weeks_with_days_and_years_with_months() {
+        assert!(SubscriptionInterval::Day(6) < SubscriptionInterval::Week(1));
+        assert_eq!(
+            SubscriptionInterval::Week(2).partial_cmp(&SubscriptionInterval::Day(14)),
+            None
+        );
+        assert!(SubscriptionInterval::Month(13) > SubscriptionInterval::Year(1));
+    }
+
     #[test]
     fn cannot_compare_days_and_months() {
         assert_eq!(
@@ -120,6 +198,10 @@ mod tests {
             SubscriptionInterval::Day(365).partial_cmp(&SubscriptionInterval::Month(12)),
             None
         );
+        assert_eq!(
+            SubscriptionInterval::Week(52).partial_cmp(&SubscriptionInterval::Year(1)),
+            None
+        );
     }
 
     #[test]
@@ -149,6 +231,12 @@ mod tests {
         assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
+    #[test]
+    fn rejects_zero_week_and_year() {
+        assert!(SubscriptionInterval::Week(0).validate().is_err());
+        assert!(SubscriptionInterval::Year(0).validate().is_err());
+    }
+
     #[test]
     fn rejects_zero_month() {
         let result = SubscriptionInterval::Month(0).validate();
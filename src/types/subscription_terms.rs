@@ -0,0 +1,214 @@
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+use crate::internal::Validated;
+use crate::schedule::BillingSchedule;
+use crate::types::{Date, SubscriptionInterval};
+use crate::{Error, ProrationPolicy};
+
+/// Terms of a new subscription
+///
+/// Defines the billing interval, the optional free trial, the date of the first billing,
+/// the one-time setup fee, and the proration policy applied to the later changes
+/// of the amount or the interval.
+///
+/// # First Billing
+///
+/// The first billing happens on the billing anchor (if set), otherwise at the end
+/// of the trial (if any), otherwise at the start of the subscription.
+/// The period between the end of the trial and a later anchor is free of charge.
+///
+/// # Validation
+/// * interval and trial: must be positive,
+/// * setup fee: must be positive.
+///
+/// The billing anchor is validated against the start of the subscription
+/// by the [`SubscriptionTerms::first_billing_date`].
+///
+/// # Data Protection
+/// Terms of the subscription are shown to the customer, and are not masked.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SubscriptionTerms {
+    pub(crate) interval: SubscriptionInterval,
+    pub(crate) trial: Option<SubscriptionInterval>,
+    pub(crate) billing_anchor: Option<Date>,
+    pub(crate) setup_fee: Option<Decimal>,
+    pub(crate) proration: ProrationPolicy,
+}
+
+impl SubscriptionTerms {
+    /// Billing interval (how often the customer is charged)
+    #[inline]
+    pub fn interval(&self) -> SubscriptionInterval {
+        self.interval
+    }
+
+    /// The length of the free trial
+    #[inline]
+    pub fn trial(&self) -> Option<SubscriptionInterval> {
+        self.trial
+    }
+
+    /// The date of the first billing requested by the merchant
+    #[inline]
+    pub fn billing_anchor(&self) -> Option<Date> {
+        self.billing_anchor
+    }
+
+    /// One-time fee charged on creation of the subscription
+    #[inline]
+    pub fn setup_fee(&self) -> Option<Decimal> {
+        self.setup_fee
+    }
+
+    /// How the changes of the subscription are settled mid-cycle
+    #[inline]
+    pub fn proration(&self) -> ProrationPolicy {
+        self.proration
+    }
+
+    /// The end of the trial (the start date for subscriptions without a trial).
+    pub fn trial_end(&self, start: Date) -> Date {
+        self.trial.map_or(start, |trial| trial.shift(start, 1))
+    }
+
+    /// The date of the first billing of the subscription started on the date.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the billing anchor precedes the end of the trial.
+    pub fn first_billing_date(&self, start: Date) -> Result<Date, Error> {
+        let trial_end = self.trial_end(start);
+        match self.billing_anchor {
+            None => Ok(trial_end),
+            Some(anchor) if anchor >= trial_end => Ok(anchor),
+            Some(anchor) => Err(Error::InvalidInput(format!(
+                "Billing anchor {anchor:?} precedes the end of the trial {trial_end:?}"
+            ))),
+        }
+    }
+
+    /// The billing schedule of the subscription started on the date.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the billing anchor precedes the end of the trial.
+    pub fn schedule(&self, start: Date) -> Result<BillingSchedule, Error> {
+        let anchor = self.first_billing_date(start)?;
+        Ok(BillingSchedule::new(anchor, self.interval))
+    }
+}
+
+impl TryFrom<crate::SubscriptionTerms> for SubscriptionTerms {
+    type Error = Error;
+
+    fn try_from(input: crate::SubscriptionTerms) -> Result<Self, Self::Error> {
+        Self {
+            interval: input.interval.try_into()?,
+            trial: input.trial.map(TryFrom::try_from).transpose()?,
+            billing_anchor: input.billing_anchor.map(TryFrom::try_from).transpose()?,
+            setup_fee: input.setup_fee,
+            proration: input.proration,
+        }
+        .validate()
+    }
+}
+
+// --- Sealed traits (not parts of the public API) ---
+
+impl Validated for SubscriptionTerms {
+    fn validate(self) -> Result<Self, Error> {
+        match self.setup_fee {
+            Some(fee) if fee <= Decimal::ZERO => Err(Error::InvalidInput(format!(
+                "Setup fee must be positive, got {fee}"
+            ))),
+            _ => Ok(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::date;
+    use rust_decimal_macros::dec;
+
+    fn valid_input() -> crate::SubscriptionTerms {
+        crate::SubscriptionTerms {
+            interval: crate::SubscriptionInterval::Month(1),
+            trial: None,
+            billing_anchor: None,
+            setup_fee: None,
+            proration: ProrationPolicy::NextBilling,
+        }
+    }
+
+    #[test]
+    fn bills_at_start_without_trial() {
+        let terms = SubscriptionTerms::try_from(valid_input()).unwrap();
+
+        assert_eq!(terms.trial_end(date(2025, 1, 10)), date(2025, 1, 10));
+        assert_eq!(
+            terms.first_billing_date(date(2025, 1, 10)).unwrap(),
+            date(2025, 1, 10)
+        );
+    }
+
+    #[test]
+    fn bills_after_trial() {
+        let mut input = valid_input();
+        input.trial = Some(crate::SubscriptionInterval::Day(14));
+        let terms = SubscriptionTerms::try_from(input).unwrap();
+
+        let schedule = terms.schedule(date(2025, 1, 25)).unwrap();
+        assert_eq!(schedule.dates(2), [date(2025, 2, 8), date(2025, 3, 8)]);
+    }
+
+    #[test]
+    fn bills_on_anchor() {
+        let mut input = valid_input();
+        input.trial = Some(crate::SubscriptionInterval::Week(1));
+        input.billing_anchor = Some(crate::Date {
+            day: 1,
+            month: 2,
+            year: 2025,
+        });
+        let terms = SubscriptionTerms::try_from(input).unwrap();
+
+        assert_eq!(
+            terms.first_billing_date(date(2025, 1, 10)).unwrap(),
+            date(2025, 2, 1)
+        );
+    }
+
+    #[test]
+    fn rejects_anchor_before_trial_end() {
+        let mut input = valid_input();
+        input.trial = Some(crate::SubscriptionInterval::Month(1));
+        input.billing_anchor = Some(crate::Date {
+            day: 1,
+            month: 2,
+            year: 2025,
+        });
+        let terms = SubscriptionTerms::try_from(input).unwrap();
+
+        let result = terms.first_billing_date(date(2025, 1, 10));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_non_positive_setup_fee() {
+        let mut input = valid_input();
+        input.setup_fee = Some(dec!(0));
+
+        let result = SubscriptionTerms::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_zero_trial() {
+        let mut input = valid_input();
+        input.trial = Some(crate::SubscriptionInterval::Day(0));
+
+        let result = SubscriptionTerms::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
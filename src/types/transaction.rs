@@ -1,22 +1,28 @@
 use iso_currency::Currency;
 use std::convert::TryFrom;
 
-use crate::types::{Recipients, TransactionId, TransactionIdempotenceKey};
-use crate::{Error, MerchantInitiatedType, TransactionStatus};
+use crate::types::{
+    NetworkTransactionId, Recipients, StoredCredentialUsage, TransactionId,
+    TransactionIdempotenceKey,
+};
+use crate::{DeclineReason, Error, MerchantInitiatedType, TransactionStatus};
 
 /// Transaction result returned by payment gateway operations.
 ///
 /// Represents the outcome of a payment operation (charge, authorize, capture, refund, void).
 /// Contains the gateway-assigned transaction ID, idempotence key for duplicate detection,
-/// current transaction status, currency, payment recipients, and merchant-initiated transaction type if applicable.
+/// current transaction status (with a canonical decline reason if applicable), currency, payment recipients, and merchant-initiated transaction type if applicable.
+/// Card payments also carry the network transaction ID to chain the stored credential usages.
 #[derive(Debug, Clone)]
 pub struct Transaction {
     pub(crate) transaction_id: TransactionId,
     pub(crate) idempotence_key: TransactionIdempotenceKey,
     pub(crate) status: TransactionStatus,
+    pub(crate) decline_reason: Option<DeclineReason>,
     pub(crate) currency: Currency,
     pub(crate) recipients: Option<Recipients>,
     pub(crate) merchant_initiated_type: Option<MerchantInitiatedType>,
+    pub(crate) network_transaction_id: Option<NetworkTransactionId>,
 }
 
 impl Transaction {
@@ -38,6 +44,12 @@ impl Transaction {
         &self.status
     }
 
+    /// The canonical reason of the decline (for declined transactions only).
+    #[inline]
+    pub fn decline_reason(&self) -> Option<DeclineReason> {
+        self.decline_reason
+    }
+
     /// The currency of the transaction.
     #[inline]
     pub fn currency(&self) -> Currency {
@@ -55,19 +67,61 @@ impl Transaction {
     pub fn merchant_initiated_type(&self) -> Option<&MerchantInitiatedType> {
         self.merchant_initiated_type.as_ref()
     }
+
+    /// The network transaction ID assigned by the card scheme (if returned by the gateway).
+    #[inline]
+    pub fn network_transaction_id(&self) -> Option<&NetworkTransactionId> {
+        self.network_transaction_id.as_ref()
+    }
+
+    /// The stored credential usage of the later payments chained to this one
+    /// (the initial agreement of the cardholder).
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidInput` if the transaction was not approved,
+    /// or the gateway hasn't returned the network transaction ID.
+    pub fn subsequent_usage(&self) -> Result<StoredCredentialUsage, Error> {
+        if !matches!(
+            self.status,
+            TransactionStatus::Authorized | TransactionStatus::Captured
+        ) {
+            return Err(Error::InvalidInput(format!(
+                "{} transaction cannot start a stored credential agreement",
+                self.status
+            )));
+        }
+        self.network_transaction_id
+            .clone()
+            .map(StoredCredentialUsage::Subsequent)
+            .ok_or_else(|| {
+                Error::InvalidInput("transaction has no network transaction ID".to_owned())
+            })
+    }
 }
 
 impl<'a> TryFrom<crate::Transaction<'a>> for Transaction {
     type Error = Error;
 
     fn try_from(input: crate::Transaction<'a>) -> Result<Self, Self::Error> {
+        if input.decline_reason.is_some() && input.status != TransactionStatus::Declined {
+            return Err(Error::InvalidInput(format!(
+                "decline reason is not applicable to {} transaction",
+                input.status
+            )));
+        }
+
         Ok(Self {
             transaction_id: input.transaction_id.try_into()?,
             idempotence_key: input.idempotence_key.try_into()?,
             status: input.status,
+            decline_reason: input.decline_reason,
             currency: input.currency,
             recipients: input.recipients.map(TryFrom::try_from).transpose()?,
             merchant_initiated_type: input.merchant_initiated_type,
+            network_transaction_id: input
+                .network_transaction_id
+                .map(TryFrom::try_from)
+                .transpose()?,
         })
     }
 }
@@ -82,9 +136,11 @@ mod tests {
             transaction_id: " txn_12345678 \n\t",
             idempotence_key: " idempotence-key-123 \n\t",
             status: TransactionStatus::Captured,
+            decline_reason: None,
             currency: Currency::USD,
             recipients: None,
             merchant_initiated_type: Some(MerchantInitiatedType::Recurring),
+            network_transaction_id: Some(" 583112345678901 "),
         }
     }
 
@@ -106,6 +162,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn accepts_decline_reason_of_declined_transaction() {
+        let mut input = valid_input();
+        input.status = TransactionStatus::Declined;
+        input.decline_reason = Some(DeclineReason::InsufficientFunds);
+
+        let transaction = Transaction::try_from(input).unwrap();
+        assert_eq!(
+            transaction.decline_reason(),
+            Some(DeclineReason::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn rejects_decline_reason_of_not_declined_transaction() {
+        let mut input = valid_input();
+        input.decline_reason = Some(DeclineReason::InsufficientFunds);
+
+        let result = Transaction::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
     #[test]
     fn rejects_invalid_transaction_id() {
         let mut input = valid_input();
@@ -123,4 +201,44 @@ mod tests {
         let result = Transaction::try_from(input);
         assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
+
+    #[test]
+    fn rejects_invalid_network_transaction_id() {
+        let mut input = valid_input();
+        input.network_transaction_id = Some("txn_12345678");
+
+        let result = Transaction::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn chains_subsequent_usage_to_approved_transaction() {
+        let transaction = Transaction::try_from(valid_input()).unwrap();
+
+        match transaction.subsequent_usage().unwrap() {
+            StoredCredentialUsage::Subsequent(id) => unsafe {
+                assert_eq!(id.as_ref(), "583112345678901");
+            },
+            StoredCredentialUsage::Initial => panic!("Expected Subsequent variant"),
+        }
+    }
+
+    #[test]
+    fn refuses_subsequent_usage_without_agreement() {
+        let mut input = valid_input();
+        input.network_transaction_id = None;
+        let missing = Transaction::try_from(input).unwrap();
+        assert!(matches!(
+            missing.subsequent_usage(),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let mut input = valid_input();
+        input.status = TransactionStatus::Declined;
+        let declined = Transaction::try_from(input).unwrap();
+        assert!(matches!(
+            declined.subsequent_usage(),
+            Err(Error::InvalidInput(_))
+        ));
+    }
 }
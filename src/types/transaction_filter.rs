@@ -0,0 +1,144 @@
+use iso_currency::Currency;
+use std::convert::TryFrom;
+
+use crate::types::CustomerId;
+use crate::{Error, TransactionStatus};
+
+/// Criteria of transaction search
+///
+/// Transactions match the filter when they satisfy all criteria set.
+///
+/// # Validation
+/// * the date range must not be reversed
+///
+/// # Timestamps
+///
+/// All timestamps are Unix timestamps (seconds since epoch).
+/// The date range is half-open: `created_from <= created_at < created_to`.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub(crate) created_from: Option<i64>,
+    pub(crate) created_to: Option<i64>,
+    pub(crate) statuses: Vec<TransactionStatus>,
+    pub(crate) currency: Option<Currency>,
+    pub(crate) customer_id: Option<CustomerId>,
+}
+
+impl TransactionFilter {
+    /// The earliest creation time of transactions (inclusive).
+    #[inline]
+    pub fn created_from(&self) -> Option<i64> {
+        self.created_from
+    }
+
+    /// The latest creation time of transactions (exclusive).
+    #[inline]
+    pub fn created_to(&self) -> Option<i64> {
+        self.created_to
+    }
+
+    /// The statuses of transactions (empty = any status).
+    #[inline]
+    pub fn statuses(&self) -> &[TransactionStatus] {
+        &self.statuses
+    }
+
+    /// The currency of transactions.
+    #[inline]
+    pub fn currency(&self) -> Option<Currency> {
+        self.currency
+    }
+
+    /// The customer who made transactions.
+    #[inline]
+    pub fn customer_id(&self) -> Option<&CustomerId> {
+        self.customer_id.as_ref()
+    }
+}
+
+impl<'a> TryFrom<crate::TransactionFilter<'a>> for TransactionFilter {
+    type Error = Error;
+
+    fn try_from(input: crate::TransactionFilter<'a>) -> Result<Self, Self::Error> {
+        if let (Some(from), Some(to)) = (input.created_from, input.created_to)
+            && from > to
+        {
+            return Err(Error::InvalidInput(format!(
+                "date range is reversed ({from} > {to})"
+            )));
+        }
+
+        let mut statuses: Vec<TransactionStatus> = Vec::with_capacity(input.statuses.len());
+        for status in input.statuses {
+            if !statuses.contains(status) {
+                statuses.push(*status);
+            }
+        }
+
+        Ok(Self {
+            created_from: input.created_from,
+            created_to: input.created_to,
+            statuses,
+            currency: input.currency,
+            customer_id: input.customer_id.map(TryFrom::try_from).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsUnsafeRef;
+
+    fn valid_input() -> crate::TransactionFilter<'static> {
+        crate::TransactionFilter {
+            created_from: Some(1_700_000_000),
+            created_to: Some(1_700_086_400),
+            statuses: &[TransactionStatus::Captured, TransactionStatus::Refunded],
+            currency: Some(Currency::EUR),
+            customer_id: Some(" cus_12345678 "),
+        }
+    }
+
+    #[test]
+    fn constructed_from_valid_input() {
+        let filter = TransactionFilter::try_from(valid_input()).unwrap();
+
+        assert_eq!(filter.created_from(), Some(1_700_000_000));
+        assert_eq!(filter.created_to(), Some(1_700_086_400));
+        assert_eq!(
+            filter.statuses(),
+            [TransactionStatus::Captured, TransactionStatus::Refunded]
+        );
+        assert_eq!(filter.currency(), Some(Currency::EUR));
+        unsafe {
+            assert_eq!(filter.customer_id().unwrap().as_ref(), "cus_12345678");
+        }
+    }
+
+    #[test]
+    fn accepts_empty_criteria() {
+        let filter = TransactionFilter::try_from(crate::TransactionFilter::default()).unwrap();
+
+        assert!(filter.statuses().is_empty());
+        assert!(filter.customer_id().is_none());
+    }
+
+    #[test]
+    fn rejects_reversed_date_range() {
+        let mut input = valid_input();
+        input.created_to = Some(1_600_000_000);
+
+        let result = TransactionFilter::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_customer_id() {
+        let mut input = valid_input();
+        input.customer_id = Some(" ");
+
+        let result = TransactionFilter::try_from(input);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
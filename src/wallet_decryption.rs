@@ -0,0 +1,111 @@
+//! Local decryption of wallet payment tokens (requires the `wallet-decryption` feature).
+//!
+//! Merchants who decrypt Apple Pay and Google Pay tokens themselves (instead of passing
+//! the encrypted token to the gateway) verify the signature chain of the token,
+//! decrypt the payload with their private key, and pass the card data to the gateway
+//! as a [`NetworkToken`] (tokenized cards with a cryptogram) or a [`WalletCard`]
+//! (funding cards without a cryptogram):
+//!
+//! ```skip
+//! let decryptor = GooglePayDecryptor::new(&private_key_der, "merchant:12345678901234567890", &root_keys_json)?;
+//!
+//! match decryptor.decrypt(&token_json, &SystemClock)? {
+//!     DecryptedPaymentMethod::NetworkToken(token) => gateway.charge(payment_with(token)).await?,
+//!     DecryptedPaymentMethod::Card(card) => gateway.secure(request_with(card)).await?,
+//! };
+//! ```
+//!
+//! Keys and certificates are passed as DER. Convert PEM files with
+//! `openssl pkcs8 -topk8 -nocrypt -outform DER` and `openssl x509 -outform DER`.
+//!
+//! # Data Protection
+//! The private keys, the shared secrets and the decrypted payloads are zeroized on drop.
+//! Card numbers and cryptograms leave the module as the masked `PrimaryAccountNumber`
+//! and `Cryptogram` only.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use p256::pkcs8::DecodePrivateKey;
+use serde::de::DeserializeOwned;
+
+use crate::types::{NetworkToken, WalletCard};
+use crate::{CardExpiry, Error, Wallet};
+
+mod apple_pay;
+mod google_pay;
+
+pub use apple_pay::ApplePayDecryptor;
+pub use google_pay::GooglePayDecryptor;
+
+/// The payment method decrypted from a wallet token.
+#[derive(Clone, Debug)]
+pub enum DecryptedPaymentMethod {
+    /// Funding card without a cryptogram (to be authenticated via 3D Secure).
+    Card(WalletCard),
+    /// Tokenized card with the one-time cryptogram.
+    NetworkToken(NetworkToken),
+}
+
+/// Card data of the decrypted payload
+struct DecryptedCard<'a> {
+    number: &'a str,
+    month: u8,
+    year: u16,
+    cryptogram: Option<&'a str>,
+    eci: Option<&'a str>,
+}
+
+impl DecryptedCard<'_> {
+    fn into_payment_method(self, wallet: Wallet) -> Result<DecryptedPaymentMethod, Error> {
+        let card_expiry = CardExpiry {
+            month: self.month,
+            year: self.year,
+        };
+        let Some(cryptogram) = self.cryptogram else {
+            return Ok(DecryptedPaymentMethod::Card(
+                crate::WalletCard {
+                    number: self.number,
+                    card_expiry,
+                    wallet,
+                }
+                .try_into()?,
+            ));
+        };
+
+        // Wallets may drop the leading zero of the ECI (like "5" for "05")
+        let eci = self
+            .eci
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .map(|code| format!("{code:0>2}"));
+        Ok(DecryptedPaymentMethod::NetworkToken(
+            crate::NetworkToken {
+                number: self.number,
+                token_expiry: card_expiry,
+                cryptogram,
+                eci: eci.as_deref(),
+                wallet: Some(wallet),
+            }
+            .try_into()?,
+        ))
+    }
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidInput(format!("the wallet token {reason}"))
+}
+
+fn private_key(der: &[u8]) -> Result<p256::SecretKey, Error> {
+    p256::SecretKey::from_pkcs8_der(der)
+        .map_err(|_| Error::InvalidInput("invalid merchant private key".into()))
+}
+
+fn decode_base64(input: &str, field: &str) -> Result<Vec<u8>, Error> {
+    STANDARD
+        .decode(input)
+        .map_err(|_| invalid(&format!("has malformed {field}")))
+}
+
+fn parse_json<T: DeserializeOwned>(input: &[u8], what: &str) -> Result<T, Error> {
+    serde_json::from_slice(input).map_err(|_| invalid(&format!("has malformed {what}")))
+}
@@ -0,0 +1,738 @@
+//! Apple Pay payment tokens (`EC_v1`).
+//!
+//! See [Payment token format reference](https://developer.apple.com/documentation/passkit/payment-token-format-reference).
+
+use aes_gcm::aead::consts::U16;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::aes::Aes256;
+use aes_gcm::{AesGcm, Nonce};
+use cms::content_info::ContentInfo;
+use cms::signed_data::{SignedData, SignerInfo};
+use p256::pkcs8::{DecodePublicKey, EncodePublicKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256, Sha384};
+use std::time::Duration;
+use x509_cert::Certificate;
+use x509_cert::der::asn1::AnyRef;
+use x509_cert::der::oid::ObjectIdentifier;
+use x509_cert::der::{Decode, Encode};
+use x509_cert::spki::SubjectPublicKeyInfoOwned;
+use x509_cert::time::Time;
+use zeroize::Zeroizing;
+use zeroize_derive::{Zeroize, ZeroizeOnDrop};
+
+use super::{DecryptedCard, DecryptedPaymentMethod, decode_base64, invalid, parse_json};
+use crate::clock::Clock;
+use crate::internal::unhex;
+use crate::{Error, Wallet};
+
+/// Marker of the leaf certificate of Apple Pay token signatures
+const LEAF_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113635.100.6.29");
+/// Marker of the intermediate certificate (Apple Application Integration CA)
+const INTERMEDIATE_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113635.100.6.2.14");
+/// The merchant identifier field of the merchant certificate
+const MERCHANT_ID_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113635.100.6.32");
+
+const SIGNED_DATA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.2");
+const MESSAGE_DIGEST_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+const SIGNING_TIME_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.5");
+const SHA256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const ECDSA_SHA256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_SHA384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+const P256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const P384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// AES-256-GCM with the 16-byte IV used by Apple Pay
+type Aes256Gcm16 = AesGcm<Aes256, U16>;
+
+/// Decryptor of Apple Pay payment tokens.
+///
+/// Verifies the signature of the token against the Apple root certificate
+/// (Apple Root CA - G3), checks that the token was encrypted for the merchant certificate,
+/// and decrypts the payment data with the merchant private key
+/// (ECDH key agreement, NIST SP 800-56A key derivation and AES-256-GCM).
+///
+/// Only the elliptic curve tokens (`EC_v1`) with the `3DSecure` payment data are supported.
+///
+/// # Data Protection
+/// The private key is zeroized on drop and never shown in logs.
+pub struct ApplePayDecryptor {
+    private_key: p256::SecretKey,
+    merchant_id: Vec<u8>,
+    public_key_hash: [u8; 32],
+    root: Certificate,
+    max_age: Duration,
+}
+
+impl ApplePayDecryptor {
+    /// Build the decryptor with the merchant private key (PKCS#8 DER), the payment processing
+    /// certificate of the merchant (DER), and the Apple root certificate (DER).
+    ///
+    /// By default, tokens signed more than 5 minutes ago are rejected.
+    ///
+    /// # Validation
+    /// * private key: P-256 key matching the public key of the merchant certificate,
+    /// * merchant certificate: must contain the merchant identifier field.
+    pub fn new(
+        private_key: &[u8],
+        merchant_certificate: &[u8],
+        root_certificate: &[u8],
+    ) -> Result<Self, Error> {
+        let private_key = super::private_key(private_key)?;
+        let merchant_certificate = parse_certificate(merchant_certificate, "merchant")?;
+        let root = parse_certificate(root_certificate, "root")?;
+
+        let public_key = merchant_certificate
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()
+            .map_err(|_| Error::InvalidInput("invalid merchant certificate".into()))?;
+        let matches_private_key = private_key
+            .public_key()
+            .to_public_key_der()
+            .is_ok_and(|der| der.as_bytes() == public_key);
+        if !matches_private_key {
+            return Err(Error::InvalidInput(
+                "the private key doesn't match the merchant certificate".into(),
+            ));
+        }
+
+        let merchant_id = extension(&merchant_certificate, MERCHANT_ID_OID)
+            .and_then(|value| {
+                let value = AnyRef::from_der(value).map_or(value, |any| any.value());
+                unhex(std::str::from_utf8(value).ok()?)
+            })
+            .ok_or_else(|| {
+                Error::InvalidInput("the merchant certificate lacks the merchant identifier".into())
+            })?;
+
+        Ok(Self {
+            private_key,
+            merchant_id,
+            public_key_hash: Sha256::digest(&public_key).into(),
+            root,
+            max_age: Duration::from_secs(5 * 60),
+        })
+    }
+
+    /// Reject tokens signed earlier than the duration ago.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Verify and decrypt the token (the JSON of the `paymentData` field of the `PKPaymentToken`).
+    ///
+    /// Returns a network token (the device account number with the online payment cryptogram).
+    pub fn decrypt(&self, token: &str, clock: &dyn Clock) -> Result<DecryptedPaymentMethod, Error> {
+        let token: Token = parse_json(token.as_bytes(), "JSON")?;
+        if token.version != "EC_v1" {
+            return Err(Error::NotSupported(format!(
+                "Apple Pay tokens of version {:?}",
+                token.version
+            )));
+        }
+
+        let ephemeral_public_key =
+            decode_base64(&token.header.ephemeral_public_key, "ephemeral public key")?;
+        let data = decode_base64(&token.data, "data")?;
+        let mut signed_content = [ephemeral_public_key.as_slice(), &data].concat();
+        for (field, value) in [
+            ("transaction ID", Some(&token.header.transaction_id)),
+            ("application data", token.header.application_data.as_ref()),
+        ] {
+            if let Some(value) = value {
+                let value =
+                    unhex(value).ok_or_else(|| invalid(&format!("has malformed {field}")))?;
+                signed_content.extend(value);
+            }
+        }
+        self.verify_signature(
+            &decode_base64(&token.signature, "signature")?,
+            &signed_content,
+            clock.now(),
+        )?;
+
+        let public_key_hash = decode_base64(&token.header.public_key_hash, "public key hash")?;
+        if public_key_hash != self.public_key_hash {
+            return Err(invalid("is encrypted for another merchant certificate"));
+        }
+
+        let ephemeral_public_key = p256::PublicKey::from_public_key_der(&ephemeral_public_key)
+            .map_err(|_| invalid("has malformed ephemeral public key"))?;
+        let shared_secret = p256::ecdh::diffie_hellman(
+            self.private_key.to_nonzero_scalar(),
+            ephemeral_public_key.as_affine(),
+        );
+        let key = self.derive_key(shared_secret.raw_secret_bytes());
+        let payload = Zeroizing::new(
+            Aes256Gcm16::new_from_slice(key.as_slice())
+                .expect("the key is 32 bytes long")
+                .decrypt(&Nonce::from([0; 16]), data.as_slice())
+                .map_err(|_| invalid("can't be decrypted"))?,
+        );
+
+        let payload: Payload = parse_json(&payload, "payload")?;
+        if payload.payment_data_type != "3DSecure" {
+            return Err(Error::NotSupported(format!(
+                "Apple Pay payment data of type {:?}",
+                payload.payment_data_type
+            )));
+        }
+        let (month, year) = expiry(&payload.application_expiration_date)
+            .ok_or_else(|| invalid("has malformed expiration date"))?;
+        DecryptedCard {
+            number: &payload.application_primary_account_number,
+            month,
+            year,
+            cryptogram: Some(&payload.payment_data.online_payment_cryptogram),
+            eci: payload.payment_data.eci_indicator.as_deref(),
+        }
+        .into_payment_method(Wallet::ApplePay)
+    }
+
+    /// The symmetric key derived by the NIST SP 800-56A single-step KDF.
+    fn derive_key(&self, shared_secret: &[u8]) -> Zeroizing<[u8; 32]> {
+        let mut hasher = Sha256::new();
+        hasher.update(1u32.to_be_bytes());
+        hasher.update(shared_secret);
+        hasher.update(b"\x0did-aes256-GCM");
+        hasher.update(b"Apple");
+        hasher.update(&self.merchant_id);
+        Zeroizing::new(hasher.finalize().into())
+    }
+
+    /// Verify the detached CMS signature of the token and its certificate chain.
+    fn verify_signature(&self, signature: &[u8], content: &[u8], now: i64) -> Result<(), Error> {
+        let malformed = || invalid("has malformed signature");
+        let content_info = ContentInfo::from_der(signature).map_err(|_| malformed())?;
+        if content_info.content_type != SIGNED_DATA_OID {
+            return Err(malformed());
+        }
+        let signed_data: SignedData = content_info.content.decode_as().map_err(|_| malformed())?;
+
+        let certificates: Vec<&Certificate> = signed_data
+            .certificates
+            .iter()
+            .flat_map(|set| set.0.iter())
+            .filter_map(|choice| match choice {
+                cms::cert::CertificateChoices::Certificate(certificate) => Some(certificate),
+                _ => None,
+            })
+            .collect();
+        let find = |oid: ObjectIdentifier| {
+            certificates
+                .iter()
+                .copied()
+                .find(|certificate| extension(certificate, oid).is_some())
+        };
+        let leaf = find(LEAF_OID).ok_or_else(|| invalid("lacks the leaf certificate"))?;
+        let intermediate =
+            find(INTERMEDIATE_OID).ok_or_else(|| invalid("lacks the intermediate certificate"))?;
+        verify_certificate(leaf, intermediate, now)?;
+        verify_certificate(intermediate, &self.root, now)?;
+        verify_certificate(&self.root, &self.root, now)?;
+
+        let [signer_info]: [&SignerInfo; 1] = signed_data
+            .signer_infos
+            .0
+            .iter()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| malformed())?;
+        let attributes = signer_info.signed_attrs.as_ref().ok_or_else(malformed)?;
+        if signer_info.digest_alg.oid != SHA256_OID {
+            return Err(Error::NotSupported(format!(
+                "Apple Pay signatures with digest {}",
+                signer_info.digest_alg.oid
+            )));
+        }
+        let attribute = |oid: ObjectIdentifier| {
+            attributes
+                .iter()
+                .find(|attribute| attribute.oid == oid)
+                .and_then(|attribute| attribute.values.iter().next())
+                .ok_or_else(malformed)
+        };
+
+        let message_digest = attribute(MESSAGE_DIGEST_OID)?;
+        if message_digest.value() != &Sha256::digest(content)[..] {
+            return Err(invalid("doesn't match its signature"));
+        }
+        let signed_at = attribute(SIGNING_TIME_OID)?
+            .to_der()
+            .and_then(|der| Time::from_der(&der))
+            .map_err(|_| malformed())?
+            .to_unix_duration()
+            .as_secs();
+        let max_age = self.max_age.as_secs();
+        if u64::try_from(now).is_ok_and(|now| now.saturating_sub(signed_at) > max_age) {
+            return Err(invalid("has expired"));
+        }
+
+        let signed_attributes = attributes.to_der().map_err(|_| malformed())?;
+        verify_ecdsa(
+            &leaf.tbs_certificate.subject_public_key_info,
+            signer_info.signature_algorithm.oid,
+            &signed_attributes,
+            signer_info.signature.as_bytes(),
+        )
+        .map_err(|_| invalid("has invalid signature"))
+    }
+}
+
+#[derive(Deserialize)]
+struct Token {
+    version: String,
+    data: String,
+    signature: String,
+    header: Header,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Header {
+    ephemeral_public_key: String,
+    public_key_hash: String,
+    transaction_id: String,
+    application_data: Option<String>,
+}
+
+#[derive(Deserialize, Zeroize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+struct Payload {
+    application_primary_account_number: String,
+    application_expiration_date: String,
+    payment_data_type: String,
+    payment_data: PaymentData,
+}
+
+#[derive(Deserialize, Zeroize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+struct PaymentData {
+    online_payment_cryptogram: String,
+    eci_indicator: Option<String>,
+}
+
+fn parse_certificate(der: &[u8], name: &str) -> Result<Certificate, Error> {
+    Certificate::from_der(der)
+        .map_err(|_| Error::InvalidInput(format!("invalid {name} certificate")))
+}
+
+/// The value of the certificate extension.
+fn extension(certificate: &Certificate, oid: ObjectIdentifier) -> Option<&[u8]> {
+    certificate
+        .tbs_certificate
+        .extensions
+        .iter()
+        .flatten()
+        .find(|extension| extension.extn_id == oid)
+        .map(|extension| extension.extn_value.as_bytes())
+}
+
+/// Check that the certificate is valid at the moment, and is signed by the issuer.
+fn verify_certificate(
+    certificate: &Certificate,
+    issuer: &Certificate,
+    now: i64,
+) -> Result<(), Error> {
+    let tbs = &certificate.tbs_certificate;
+    let now = Duration::from_secs(u64::try_from(now).unwrap_or_default());
+    if now < tbs.validity.not_before.to_unix_duration()
+        || now > tbs.validity.not_after.to_unix_duration()
+    {
+        return Err(invalid(&format!(
+            "is signed by the certificate {} which is not valid now",
+            tbs.subject
+        )));
+    }
+
+    let verified = tbs.issuer == issuer.tbs_certificate.subject
+        && tbs.to_der().is_ok_and(|der| {
+            verify_ecdsa(
+                &issuer.tbs_certificate.subject_public_key_info,
+                certificate.signature_algorithm.oid,
+                &der,
+                certificate.signature.raw_bytes(),
+            )
+            .is_ok()
+        });
+    if verified {
+        Ok(())
+    } else {
+        Err(invalid(&format!(
+            "is signed by the certificate {} not issued by {}",
+            tbs.subject, issuer.tbs_certificate.subject
+        )))
+    }
+}
+
+/// Verify the DER-encoded ECDSA signature (P-256 or P-384 key, SHA-256 or SHA-384 digest).
+fn verify_ecdsa(
+    public_key: &SubjectPublicKeyInfoOwned,
+    algorithm: ObjectIdentifier,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), ()> {
+    use p256::ecdsa::signature::hazmat::PrehashVerifier;
+
+    let digest = match algorithm {
+        ECDSA_SHA256_OID => Sha256::digest(message).to_vec(),
+        ECDSA_SHA384_OID => Sha384::digest(message).to_vec(),
+        _ => return Err(()),
+    };
+    let curve = public_key
+        .algorithm
+        .parameters
+        .as_ref()
+        .and_then(|parameters| parameters.decode_as::<ObjectIdentifier>().ok());
+    let key = public_key.subject_public_key.raw_bytes();
+
+    match curve {
+        Some(P256_OID) => {
+            let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(key).map_err(|_| ())?;
+            let signature = p256::ecdsa::Signature::from_der(signature).map_err(|_| ())?;
+            key.verify_prehash(&digest, &signature).map_err(|_| ())
+        }
+        Some(P384_OID) => {
+            let key = p384::ecdsa::VerifyingKey::from_sec1_bytes(key).map_err(|_| ())?;
+            let signature = p384::ecdsa::Signature::from_der(signature).map_err(|_| ())?;
+            key.verify_prehash(&digest, &signature).map_err(|_| ())
+        }
+        _ => Err(()),
+    }
+}
+
+/// Parse the expiration date in the `YYMMDD` format.
+fn expiry(date: &str) -> Option<(u8, u16)> {
+    if date.len() != 6 || !date.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let year: u16 = date[0..2].parse().ok()?;
+    let month: u8 = date[2..4].parse().ok()?;
+    Some((month, 2000 + year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::internal::hex;
+    use crate::{AsUnsafeRef, CardNetwork, ECI};
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use cms::cert::{CertificateChoices, IssuerAndSerialNumber};
+    use cms::content_info::CmsVersion;
+    use cms::signed_data::{EncapsulatedContentInfo, SignerIdentifier, SignerInfos};
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::{Signature, SigningKey};
+    use p256::pkcs8::EncodePrivateKey;
+    use serde_json::json;
+    use std::str::FromStr;
+    use x509_cert::attr::Attribute;
+    use x509_cert::der::asn1::{Any, BitString, OctetString, SetOfVec, UtcTime};
+    use x509_cert::der::{Tag, Tagged};
+    use x509_cert::ext::Extension;
+    use x509_cert::name::Name;
+    use x509_cert::serial_number::SerialNumber;
+    use x509_cert::spki::AlgorithmIdentifierOwned;
+    use x509_cert::time::Validity;
+    use x509_cert::{TbsCertificate, Version};
+
+    const NOW: i64 = 1_700_000_000;
+    const DATA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+    const CONTENT_TYPE_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.3");
+
+    fn secret_key(byte: u8) -> p256::SecretKey {
+        p256::SecretKey::from_bytes(&[byte; 32].into()).unwrap()
+    }
+
+    fn ecdsa_sha256() -> AlgorithmIdentifierOwned {
+        AlgorithmIdentifierOwned {
+            oid: ECDSA_SHA256_OID,
+            parameters: None,
+        }
+    }
+
+    fn utc_time(timestamp: i64) -> UtcTime {
+        UtcTime::from_unix_duration(Duration::from_secs(timestamp as u64)).unwrap()
+    }
+
+    /// The certificate of the subject key (0x51 for the root) signed by the issuer key.
+    fn certificate(
+        subject: (&str, u8),
+        issuer: (&str, u8),
+        extension: Option<(ObjectIdentifier, Any)>,
+    ) -> Certificate {
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number: SerialNumber::new(&[subject.1]).unwrap(),
+            signature: ecdsa_sha256(),
+            issuer: Name::from_str(issuer.0).unwrap(),
+            validity: Validity {
+                not_before: Time::UtcTime(utc_time(NOW - 86_400)),
+                not_after: Time::UtcTime(utc_time(NOW + 86_400)),
+            },
+            subject: Name::from_str(subject.0).unwrap(),
+            subject_public_key_info: SubjectPublicKeyInfoOwned::from_key(
+                secret_key(subject.1).public_key(),
+            )
+            .unwrap(),
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: extension.map(|(oid, value)| {
+                vec![Extension {
+                    extn_id: oid,
+                    critical: false,
+                    extn_value: OctetString::new(value.to_der().unwrap()).unwrap(),
+                }]
+            }),
+        };
+        let signature: Signature =
+            SigningKey::from(secret_key(issuer.1)).sign(&tbs_certificate.to_der().unwrap());
+
+        Certificate {
+            tbs_certificate,
+            signature_algorithm: ecdsa_sha256(),
+            signature: BitString::from_bytes(signature.to_der().as_bytes()).unwrap(),
+        }
+    }
+
+    fn root(key: u8) -> Certificate {
+        certificate(
+            ("CN=Apple Root CA - G3", key),
+            ("CN=Apple Root CA - G3", key),
+            None,
+        )
+    }
+
+    fn merchant_certificate(key: u8) -> Certificate {
+        let merchant_id = hex(&Sha256::digest(b"merchant.com.example"));
+        certificate(
+            ("CN=merchant.com.example", key),
+            ("CN=Apple Worldwide Developer Relations CA - G2", 0x50),
+            Some((
+                MERCHANT_ID_OID,
+                Any::new(Tag::Utf8String, merchant_id.as_bytes()).unwrap(),
+            )),
+        )
+    }
+
+    fn decryptor(root_key: u8) -> ApplePayDecryptor {
+        ApplePayDecryptor::new(
+            secret_key(0x54).to_pkcs8_der().unwrap().as_bytes(),
+            &merchant_certificate(0x54).to_der().unwrap(),
+            &root(root_key).to_der().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn attribute(oid: ObjectIdentifier, value: Any) -> Attribute {
+        Attribute {
+            oid,
+            values: SetOfVec::try_from(vec![value]).unwrap(),
+        }
+    }
+
+    /// The detached CMS signature of the content by the leaf key (0x53).
+    fn signature(content: &[u8], signed_at: i64) -> Vec<u8> {
+        let intermediate = certificate(
+            ("CN=Apple Application Integration CA - G3", 0x52),
+            ("CN=Apple Root CA - G3", 0x51),
+            Some((INTERMEDIATE_OID, Any::null())),
+        );
+        let leaf = certificate(
+            ("CN=ecc-smp-broker-sign_UC4-PROD", 0x53),
+            ("CN=Apple Application Integration CA - G3", 0x52),
+            Some((LEAF_OID, Any::null())),
+        );
+
+        let attributes = SetOfVec::try_from(vec![
+            attribute(
+                CONTENT_TYPE_OID,
+                Any::new(DATA_OID.tag(), DATA_OID.as_bytes()).unwrap(),
+            ),
+            attribute(
+                MESSAGE_DIGEST_OID,
+                Any::new(Tag::OctetString, Sha256::digest(content).to_vec()).unwrap(),
+            ),
+            attribute(
+                SIGNING_TIME_OID,
+                Any::encode_from(&utc_time(signed_at)).unwrap(),
+            ),
+        ])
+        .unwrap();
+        let signature: Signature =
+            SigningKey::from(secret_key(0x53)).sign(&attributes.to_der().unwrap());
+
+        let signer_info = SignerInfo {
+            version: CmsVersion::V1,
+            sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+                issuer: leaf.tbs_certificate.issuer.clone(),
+                serial_number: leaf.tbs_certificate.serial_number.clone(),
+            }),
+            digest_alg: AlgorithmIdentifierOwned {
+                oid: SHA256_OID,
+                parameters: None,
+            },
+            signed_attrs: Some(attributes),
+            signature_algorithm: ecdsa_sha256(),
+            signature: OctetString::new(signature.to_der().as_bytes()).unwrap(),
+            unsigned_attrs: None,
+        };
+        let signed_data = SignedData {
+            version: CmsVersion::V1,
+            digest_algorithms: SetOfVec::try_from(vec![signer_info.digest_alg.clone()]).unwrap(),
+            encap_content_info: EncapsulatedContentInfo {
+                econtent_type: DATA_OID,
+                econtent: None,
+            },
+            certificates: Some(cms::signed_data::CertificateSet(
+                SetOfVec::try_from(vec![
+                    CertificateChoices::Certificate(leaf),
+                    CertificateChoices::Certificate(intermediate),
+                ])
+                .unwrap(),
+            )),
+            crls: None,
+            signer_infos: SignerInfos(SetOfVec::try_from(vec![signer_info]).unwrap()),
+        };
+
+        ContentInfo {
+            content_type: SIGNED_DATA_OID,
+            content: Any::encode_from(&signed_data).unwrap(),
+        }
+        .to_der()
+        .unwrap()
+    }
+
+    /// Encrypt the payload for the merchant key (0x54), and sign the token.
+    fn token(payload: serde_json::Value) -> serde_json::Value {
+        let ephemeral_key = secret_key(0x55);
+        let ephemeral_public_key = ephemeral_key.public_key().to_public_key_der().unwrap();
+        let shared_secret = p256::ecdh::diffie_hellman(
+            ephemeral_key.to_nonzero_scalar(),
+            secret_key(0x54).public_key().as_affine(),
+        );
+        let key = decryptor(0x51).derive_key(shared_secret.raw_secret_bytes());
+        let data = Aes256Gcm16::new_from_slice(key.as_slice())
+            .unwrap()
+            .encrypt(&Nonce::from([0; 16]), payload.to_string().as_bytes())
+            .unwrap();
+
+        let transaction_id = "2686f5297f123ec7fd9d31074d43d201953ca75f098890375f13aed2737d92f2";
+        let content = [
+            ephemeral_public_key.as_bytes(),
+            &data,
+            &unhex(transaction_id).unwrap(),
+        ]
+        .concat();
+        let public_key = merchant_certificate(0x54)
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()
+            .unwrap();
+
+        json!({
+            "version": "EC_v1",
+            "data": STANDARD.encode(&data),
+            "signature": STANDARD.encode(signature(&content, NOW - 60)),
+            "header": {
+                "ephemeralPublicKey": STANDARD.encode(ephemeral_public_key.as_bytes()),
+                "publicKeyHash": STANDARD.encode(Sha256::digest(&public_key)),
+                "transactionId": transaction_id,
+            },
+        })
+    }
+
+    fn payload() -> serde_json::Value {
+        json!({
+            "applicationPrimaryAccountNumber": "4895370012003478",
+            "applicationExpirationDate": "301231",
+            "currencyCode": "840",
+            "transactionAmount": 1999,
+            "deviceManufacturerIdentifier": "040010030273",
+            "paymentDataType": "3DSecure",
+            "paymentData": {
+                "onlinePaymentCryptogram": "AgAAAAAABk4DWZ4C28yUQAAAAAA=",
+                "eciIndicator": "5",
+            },
+        })
+    }
+
+    #[test]
+    fn decrypts_network_token() {
+        let token = token(payload()).to_string();
+
+        let result = decryptor(0x51).decrypt(&token, &FixedClock(NOW)).unwrap();
+        let DecryptedPaymentMethod::NetworkToken(token) = result else {
+            panic!("expected a network token");
+        };
+        assert_eq!(token.network(), CardNetwork::Visa);
+        assert_eq!(token.eci(), Some(ECI::FullyAuthenticated));
+        assert_eq!(token.wallet(), Some(Wallet::ApplePay));
+        unsafe {
+            assert_eq!(token.token_expiry().year(), 2030);
+            assert_eq!(token.number().as_ref(), "4895370012003478");
+            assert_eq!(token.cryptogram().as_ref(), "AgAAAAAABk4DWZ4C28yUQAAAAAA=");
+        }
+    }
+
+    #[test]
+    fn rejects_token_signed_by_another_root() {
+        let token = token(payload()).to_string();
+
+        let result = decryptor(0x61).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_tampered_data() {
+        let mut token = token(payload());
+        let mut data = STANDARD.decode(token["data"].as_str().unwrap()).unwrap();
+        data[0] ^= 1;
+        token["data"] = STANDARD.encode(&data).into();
+
+        let result = decryptor(0x51).decrypt(&token.to_string(), &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_token_for_another_merchant_certificate() {
+        let mut token = token(payload());
+        token["header"]["publicKeyHash"] = STANDARD.encode([0; 32]).into();
+
+        let result = decryptor(0x51).decrypt(&token.to_string(), &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_expired_signature() {
+        let token = token(payload()).to_string();
+        let decryptor = decryptor(0x51).with_max_age(Duration::from_secs(30));
+
+        let result = decryptor.decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_emv_payment_data() {
+        let mut payload = payload();
+        payload["paymentDataType"] = "EMV".into();
+        let token = token(payload).to_string();
+
+        let result = decryptor(0x51).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+    }
+
+    #[test]
+    fn rejects_private_key_of_another_certificate() {
+        let result = ApplePayDecryptor::new(
+            secret_key(0x56).to_pkcs8_der().unwrap().as_bytes(),
+            &merchant_certificate(0x54).to_der().unwrap(),
+            &root(0x51).to_der().unwrap(),
+        );
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,510 @@
+//! Google Pay payment tokens (`ECv2`).
+//!
+//! See [Payment data cryptography for merchants](https://developers.google.com/pay/api/web/guides/resources/payment-data-cryptography).
+
+use aes::Aes256;
+use ctr::Ctr128BE;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use serde::Deserialize;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+use zeroize_derive::{Zeroize, ZeroizeOnDrop};
+
+use super::{DecryptedCard, DecryptedPaymentMethod, decode_base64, invalid, parse_json};
+use crate::clock::Clock;
+use crate::{Error, Wallet};
+
+const SENDER_ID: &str = "Google";
+const PROTOCOL_VERSION: &str = "ECv2";
+
+/// Decryptor of Google Pay payment tokens.
+///
+/// Verifies the intermediate signing key against the Google root signing keys,
+/// the signature of the message against the intermediate key, and decrypts the message
+/// with the merchant private key (ECIES-KEM with HKDF-SHA256, AES-256-CTR and HMAC-SHA256).
+///
+/// Only the `ECv2` protocol and the `CARD` payment method are supported.
+///
+/// # Data Protection
+/// The private key is zeroized on drop and never shown in logs.
+pub struct GooglePayDecryptor {
+    private_key: p256::SecretKey,
+    recipient_id: String,
+    root_keys: Vec<RootKey>,
+}
+
+struct RootKey {
+    key: VerifyingKey,
+    expires_at: Option<i64>,
+}
+
+impl GooglePayDecryptor {
+    /// Build the decryptor with the merchant private key (PKCS#8 DER), the recipient ID
+    /// (`merchant:<merchantId>` or `gateway:<gatewayId>`), and the JSON of the Google root
+    /// signing keys (as published at `https://payments.developers.google.com/paymentmethodtoken/keys.json`).
+    ///
+    /// # Validation
+    /// * private key: P-256 key,
+    /// * root keys: at least one valid key for the `ECv2` protocol.
+    pub fn new(private_key: &[u8], recipient_id: &str, root_keys: &str) -> Result<Self, Error> {
+        let private_key = super::private_key(private_key)?;
+
+        let RootKeys { keys } = serde_json::from_str(root_keys)
+            .map_err(|_| Error::InvalidInput("malformed Google root signing keys".into()))?;
+        let root_keys: Vec<RootKey> = keys
+            .into_iter()
+            .filter(|key| key.protocol_version == PROTOCOL_VERSION)
+            .map(|key| {
+                Ok(RootKey {
+                    key: verifying_key(&key.key_value)?,
+                    expires_at: key.key_expiration.as_deref().map(timestamp).transpose()?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        if root_keys.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "no Google root signing keys for {PROTOCOL_VERSION}"
+            )));
+        }
+
+        Ok(Self {
+            private_key,
+            recipient_id: recipient_id.to_string(),
+            root_keys,
+        })
+    }
+
+    /// Verify and decrypt the token (the JSON of the `tokenizationData.token` field).
+    ///
+    /// Returns a network token for the `CRYPTOGRAM_3DS` authentication method,
+    /// and a wallet card for the `PAN_ONLY` one.
+    pub fn decrypt(&self, token: &str, clock: &dyn Clock) -> Result<DecryptedPaymentMethod, Error> {
+        let now = clock.now();
+        let token: Token = parse_json(token.as_bytes(), "JSON")?;
+        if token.protocol_version != PROTOCOL_VERSION {
+            return Err(Error::NotSupported(format!(
+                "Google Pay tokens of protocol {:?}",
+                token.protocol_version
+            )));
+        }
+
+        let intermediate_key =
+            self.verify_intermediate_key(&token.intermediate_signing_key, now)?;
+        let signature = signature(&token.signature)?;
+        let signed = signed_string(&[
+            SENDER_ID,
+            &self.recipient_id,
+            PROTOCOL_VERSION,
+            &token.signed_message,
+        ]);
+        intermediate_key
+            .verify(&signed, &signature)
+            .map_err(|_| invalid("has invalid signature"))?;
+
+        let message: SignedMessage = parse_json(token.signed_message.as_bytes(), "signed message")?;
+        let payload = self.decrypt_message(&message)?;
+        let payload: Payload = parse_json(&payload, "payload")?;
+
+        if timestamp(&payload.message_expiration)? < now {
+            return Err(invalid("has expired"));
+        }
+        if payload.payment_method != "CARD" {
+            return Err(Error::NotSupported(format!(
+                "Google Pay payment method {:?}",
+                payload.payment_method
+            )));
+        }
+        let details = &payload.payment_method_details;
+        let cryptogram = match details.auth_method.as_str() {
+            "PAN_ONLY" => None,
+            "CRYPTOGRAM_3DS" => Some(
+                details
+                    .cryptogram
+                    .as_deref()
+                    .ok_or_else(|| invalid("lacks the cryptogram"))?,
+            ),
+            method => {
+                return Err(Error::NotSupported(format!(
+                    "Google Pay authentication method {method:?}"
+                )));
+            }
+        };
+        DecryptedCard {
+            number: &details.pan,
+            month: details.expiration_month,
+            year: details.expiration_year,
+            cryptogram,
+            eci: details.eci_indicator.as_deref(),
+        }
+        .into_payment_method(Wallet::GooglePay)
+    }
+
+    /// Verify the intermediate signing key with any of the root keys, and check its expiration.
+    fn verify_intermediate_key(
+        &self,
+        intermediate: &IntermediateSigningKey,
+        now: i64,
+    ) -> Result<VerifyingKey, Error> {
+        let signed = signed_string(&[SENDER_ID, PROTOCOL_VERSION, &intermediate.signed_key]);
+        let signatures = intermediate
+            .signatures
+            .iter()
+            .map(|value| signature(value))
+            .collect::<Result<Vec<_>, _>>()?;
+        let verified = self
+            .root_keys
+            .iter()
+            .filter(|root| root.expires_at.is_none_or(|expires_at| expires_at >= now))
+            .any(|root| {
+                signatures
+                    .iter()
+                    .any(|signature| root.key.verify(&signed, signature).is_ok())
+            });
+        if !verified {
+            return Err(invalid("has intermediate signing key not signed by Google"));
+        }
+
+        let key: SignedKey = parse_json(intermediate.signed_key.as_bytes(), "signed key")?;
+        if timestamp(&key.key_expiration)? < now {
+            return Err(invalid("has expired intermediate signing key"));
+        }
+        verifying_key(&key.key_value)
+    }
+
+    /// Derive the keys from the ephemeral public key, check the tag, and decrypt the message.
+    fn decrypt_message(&self, message: &SignedMessage) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let ephemeral_public_key =
+            decode_base64(&message.ephemeral_public_key, "ephemeral public key")?;
+        let encrypted_message = decode_base64(&message.encrypted_message, "encrypted message")?;
+        let tag = decode_base64(&message.tag, "tag")?;
+
+        let public_key = p256::PublicKey::from_sec1_bytes(&ephemeral_public_key)
+            .map_err(|_| invalid("has malformed ephemeral public key"))?;
+        let shared_secret = p256::ecdh::diffie_hellman(
+            self.private_key.to_nonzero_scalar(),
+            public_key.as_affine(),
+        );
+        let input = Zeroizing::new(
+            [
+                ephemeral_public_key.as_slice(),
+                &shared_secret.raw_secret_bytes()[..],
+            ]
+            .concat(),
+        );
+        let mut keys = Zeroizing::new([0; 64]);
+        Hkdf::<Sha256>::new(None, &input)
+            .expand(SENDER_ID.as_bytes(), keys.as_mut_slice())
+            .expect("64 bytes is a valid length of HKDF-SHA256 output");
+        let (symmetric_key, mac_key) = keys.split_at(32);
+
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(mac_key).expect("HMAC accepts keys of any length");
+        mac.update(&encrypted_message);
+        mac.verify_slice(&tag)
+            .map_err(|_| invalid("has invalid tag"))?;
+
+        let mut payload = Zeroizing::new(encrypted_message);
+        Ctr128BE::<Aes256>::new(symmetric_key.into(), &[0; 16].into())
+            .apply_keystream(payload.as_mut_slice());
+        Ok(payload)
+    }
+}
+
+#[derive(Deserialize)]
+struct RootKeys {
+    keys: Vec<RootKeyValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RootKeyValue {
+    key_value: String,
+    protocol_version: String,
+    key_expiration: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Token {
+    protocol_version: String,
+    signature: String,
+    intermediate_signing_key: IntermediateSigningKey,
+    signed_message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntermediateSigningKey {
+    signed_key: String,
+    signatures: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedKey {
+    key_value: String,
+    key_expiration: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedMessage {
+    encrypted_message: String,
+    ephemeral_public_key: String,
+    tag: String,
+}
+
+#[derive(Deserialize, Zeroize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+struct Payload {
+    message_expiration: String,
+    payment_method: String,
+    payment_method_details: PaymentMethodDetails,
+}
+
+#[derive(Deserialize, Zeroize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+struct PaymentMethodDetails {
+    auth_method: String,
+    pan: String,
+    expiration_month: u8,
+    expiration_year: u16,
+    cryptogram: Option<String>,
+    eci_indicator: Option<String>,
+}
+
+/// Concatenate the values prefixed by their 4-byte little-endian lengths.
+fn signed_string(values: &[&str]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| {
+            let length = u32::try_from(value.len()).unwrap_or(u32::MAX);
+            length.to_le_bytes().into_iter().chain(value.bytes())
+        })
+        .collect()
+}
+
+fn verifying_key(value: &str) -> Result<VerifyingKey, Error> {
+    VerifyingKey::from_public_key_der(&decode_base64(value, "signing key")?)
+        .map_err(|_| invalid("has malformed signing key"))
+}
+
+fn signature(value: &str) -> Result<Signature, Error> {
+    Signature::from_der(&decode_base64(value, "signature")?)
+        .map_err(|_| invalid("has malformed signature"))
+}
+
+/// Convert the expiration in milliseconds to the Unix timestamp.
+fn timestamp(milliseconds: &str) -> Result<i64, Error> {
+    milliseconds
+        .parse::<i64>()
+        .map(|milliseconds| milliseconds / 1000)
+        .map_err(|_| invalid("has malformed expiration"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::{AsUnsafeRef, CardNetwork, ECI};
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use p256::ecdsa::SigningKey;
+    use p256::ecdsa::signature::Signer;
+    use p256::elliptic_curve::sec1::ToEncodedPoint;
+    use p256::pkcs8::{EncodePrivateKey, EncodePublicKey};
+    use serde_json::json;
+
+    const NOW: i64 = 1_700_000_000;
+    const RECIPIENT_ID: &str = "merchant:12345678901234567890";
+
+    fn secret_key(byte: u8) -> p256::SecretKey {
+        p256::SecretKey::from_bytes(&[byte; 32].into()).unwrap()
+    }
+
+    fn signing_key(byte: u8) -> SigningKey {
+        SigningKey::from(secret_key(byte))
+    }
+
+    fn public_key_value(key: &SigningKey) -> String {
+        let der = p256::PublicKey::from(key.verifying_key())
+            .to_public_key_der()
+            .unwrap();
+        STANDARD.encode(der.as_bytes())
+    }
+
+    fn sign(key: &SigningKey, values: &[&str]) -> String {
+        let signature: Signature = key.sign(&signed_string(values));
+        STANDARD.encode(signature.to_der().as_bytes())
+    }
+
+    fn root_keys() -> String {
+        json!({
+            "keys": [{
+                "keyValue": public_key_value(&signing_key(0x11)),
+                "protocolVersion": "ECv2",
+                "keyExpiration": ((NOW + 86_400) * 1000).to_string(),
+            }]
+        })
+        .to_string()
+    }
+
+    fn decryptor(merchant_key: u8) -> GooglePayDecryptor {
+        let private_key = secret_key(merchant_key).to_pkcs8_der().unwrap();
+        GooglePayDecryptor::new(private_key.as_bytes(), RECIPIENT_ID, &root_keys()).unwrap()
+    }
+
+    fn details(auth_method: &str) -> serde_json::Value {
+        match auth_method {
+            "CRYPTOGRAM_3DS" => json!({
+                "authMethod": "CRYPTOGRAM_3DS",
+                "pan": "4895370012003478",
+                "expirationMonth": 12,
+                "expirationYear": 2030,
+                "cryptogram": "AgAAAAAABk4DWZ4C28yUQAAAAAA=",
+                "eciIndicator": "05",
+            }),
+            _ => json!({
+                "authMethod": "PAN_ONLY",
+                "pan": "4111111111111111",
+                "expirationMonth": 12,
+                "expirationYear": 2030,
+            }),
+        }
+    }
+
+    /// Encrypt the payload for the merchant key 0x33, and sign it for the recipient.
+    fn token(details: serde_json::Value, recipient_id: &str, root_key: u8) -> String {
+        let payload = json!({
+            "messageExpiration": ((NOW + 600) * 1000).to_string(),
+            "messageId": "AH2EjtcW",
+            "paymentMethod": "CARD",
+            "paymentMethodDetails": details,
+        })
+        .to_string();
+
+        let ephemeral_key = secret_key(0x44);
+        let ephemeral_public_key = ephemeral_key.public_key().to_encoded_point(false);
+        let shared_secret = p256::ecdh::diffie_hellman(
+            ephemeral_key.to_nonzero_scalar(),
+            secret_key(0x33).public_key().as_affine(),
+        );
+        let input = [
+            ephemeral_public_key.as_bytes(),
+            &shared_secret.raw_secret_bytes()[..],
+        ]
+        .concat();
+        let mut keys = [0; 64];
+        Hkdf::<Sha256>::new(None, &input)
+            .expand(SENDER_ID.as_bytes(), &mut keys)
+            .unwrap();
+        let mut encrypted_message = payload.into_bytes();
+        Ctr128BE::<Aes256>::new(keys[..32].into(), &[0; 16].into())
+            .apply_keystream(&mut encrypted_message);
+        let mut mac = Hmac::<Sha256>::new_from_slice(&keys[32..]).unwrap();
+        mac.update(&encrypted_message);
+
+        let signed_message = json!({
+            "encryptedMessage": STANDARD.encode(&encrypted_message),
+            "ephemeralPublicKey": STANDARD.encode(ephemeral_public_key.as_bytes()),
+            "tag": STANDARD.encode(mac.finalize().into_bytes()),
+        })
+        .to_string();
+        let intermediate_key = signing_key(0x22);
+        let signed_key = json!({
+            "keyValue": public_key_value(&intermediate_key),
+            "keyExpiration": ((NOW + 3600) * 1000).to_string(),
+        })
+        .to_string();
+
+        json!({
+            "protocolVersion": "ECv2",
+            "signature": sign(
+                &intermediate_key,
+                &[SENDER_ID, recipient_id, PROTOCOL_VERSION, &signed_message],
+            ),
+            "intermediateSigningKey": {
+                "signatures": [sign(&signing_key(root_key), &[SENDER_ID, PROTOCOL_VERSION, &signed_key])],
+                "signedKey": signed_key,
+            },
+            "signedMessage": signed_message,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn decrypts_network_token() {
+        let token = token(details("CRYPTOGRAM_3DS"), RECIPIENT_ID, 0x11);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW)).unwrap();
+        let DecryptedPaymentMethod::NetworkToken(token) = result else {
+            panic!("expected a network token");
+        };
+        assert_eq!(token.network(), CardNetwork::Visa);
+        assert_eq!(token.eci(), Some(ECI::FullyAuthenticated));
+        assert_eq!(token.wallet(), Some(Wallet::GooglePay));
+        unsafe {
+            assert_eq!(token.number().as_ref(), "4895370012003478");
+            assert_eq!(token.cryptogram().as_ref(), "AgAAAAAABk4DWZ4C28yUQAAAAAA=");
+        }
+    }
+
+    #[test]
+    fn decrypts_card_without_cryptogram() {
+        let token = token(details("PAN_ONLY"), RECIPIENT_ID, 0x11);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW)).unwrap();
+        let DecryptedPaymentMethod::Card(card) = result else {
+            panic!("expected a card");
+        };
+        assert_eq!(card.wallet(), Wallet::GooglePay);
+        assert_eq!(card.display().last4(), "1111");
+    }
+
+    #[test]
+    fn rejects_token_for_another_recipient() {
+        let token = token(details("PAN_ONLY"), "merchant:another", 0x11);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_intermediate_key_not_signed_by_root() {
+        let token = token(details("PAN_ONLY"), RECIPIENT_ID, 0x12);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_token_encrypted_for_another_key() {
+        let token = token(details("PAN_ONLY"), RECIPIENT_ID, 0x11);
+
+        let result = decryptor(0x34).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_expired_message() {
+        let token = token(details("PAN_ONLY"), RECIPIENT_ID, 0x11);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW + 601));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn rejects_missing_cryptogram() {
+        let mut details = details("CRYPTOGRAM_3DS");
+        details.as_object_mut().unwrap().remove("cryptogram");
+        let token = token(details, RECIPIENT_ID, 0x11);
+
+        let result = decryptor(0x33).decrypt(&token, &FixedClock(NOW));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}